@@ -0,0 +1,241 @@
+use crate::model::character::gender::Gender;
+use crate::model::color::Color;
+use anyhow::{bail, Result};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+const SKIN_COLORS: [Color; 6] = [
+    Color::Chocolate,
+    Color::PeachPuff,
+    Color::SaddleBrown,
+    Color::Sienna,
+    Color::Tan,
+    Color::Wheat,
+];
+const HAIR_COLORS: [Color; 7] = [
+    Color::Black,
+    Color::Chocolate,
+    Color::Gold,
+    Color::Gray,
+    Color::Maroon,
+    Color::SaddleBrown,
+    Color::White,
+];
+const EYE_COLORS: [Color; 5] = [
+    Color::Blue,
+    Color::Gray,
+    Color::Green,
+    Color::SaddleBrown,
+    Color::Teal,
+];
+
+/// How a [`character`](crate::model::character::Character) looks like.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Appearance {
+    #[serde(default)]
+    pub head_shape: HeadShape,
+    pub skin_color: Color,
+    pub eye_color: Color,
+    #[serde(default)]
+    pub hair_style: HairStyle,
+    pub hair_color: Color,
+    #[serde(default)]
+    pub beard: Beard,
+}
+
+impl Appearance {
+    /// Generates a random appearance. Only male characters have beards.
+    pub fn random<R: Rng>(gender: Gender, rng: &mut R) -> Self {
+        let beard = if gender == Gender::Male {
+            *Beard::ALL.choose(rng).unwrap()
+        } else {
+            Beard::None
+        };
+
+        Self {
+            head_shape: *HeadShape::ALL.choose(rng).unwrap(),
+            skin_color: *SKIN_COLORS.choose(rng).unwrap(),
+            eye_color: *EYE_COLORS.choose(rng).unwrap(),
+            hair_style: *HairStyle::ALL.choose(rng).unwrap(),
+            hair_color: *HAIR_COLORS.choose(rng).unwrap(),
+            beard,
+        }
+    }
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            head_shape: HeadShape::default(),
+            skin_color: Color::PeachPuff,
+            eye_color: Color::Blue,
+            hair_style: HairStyle::default(),
+            hair_color: Color::SaddleBrown,
+            beard: Beard::default(),
+        }
+    }
+}
+
+/// The shape of the head.
+#[derive(Default, Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum HeadShape {
+    Long,
+    #[default]
+    Oval,
+    Round,
+    Square,
+}
+
+impl HeadShape {
+    pub const ALL: [Self; 4] = [Self::Long, Self::Oval, Self::Round, Self::Square];
+
+    /// Returns all the possible values.
+    pub fn get_all() -> [&'static str; 4] {
+        ["Long", "Oval", "Round", "Square"]
+    }
+
+    /// Parses a head shape & fails for unknown strings.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::character::appearance::HeadShape;
+    /// assert_eq!(HeadShape::parse("Round").unwrap(), HeadShape::Round);
+    /// assert!(HeadShape::parse("Unknown").is_err());
+    /// ```
+    pub fn parse(string: &str) -> Result<Self> {
+        match string {
+            "Long" => Ok(Self::Long),
+            "Oval" => Ok(Self::Oval),
+            "Round" => Ok(Self::Round),
+            "Square" => Ok(Self::Square),
+            _ => bail!("Unknown head shape '{}'!", string),
+        }
+    }
+}
+
+impl Display for HeadShape {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The style of the hair.
+#[derive(Default, Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum HairStyle {
+    Bald,
+    Bun,
+    Long,
+    #[default]
+    Short,
+}
+
+impl HairStyle {
+    pub const ALL: [Self; 4] = [Self::Bald, Self::Bun, Self::Long, Self::Short];
+
+    /// Returns all the possible values.
+    pub fn get_all() -> [&'static str; 4] {
+        ["Bald", "Bun", "Long", "Short"]
+    }
+
+    /// Parses a hair style & fails for unknown strings.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::character::appearance::HairStyle;
+    /// assert_eq!(HairStyle::parse("Bun").unwrap(), HairStyle::Bun);
+    /// assert!(HairStyle::parse("Unknown").is_err());
+    /// ```
+    pub fn parse(string: &str) -> Result<Self> {
+        match string {
+            "Bald" => Ok(Self::Bald),
+            "Bun" => Ok(Self::Bun),
+            "Long" => Ok(Self::Long),
+            "Short" => Ok(Self::Short),
+            _ => bail!("Unknown hair style '{}'!", string),
+        }
+    }
+}
+
+impl Display for HairStyle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The beard has the same color as the hair.
+#[derive(Default, Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Beard {
+    #[default]
+    None,
+    Full,
+    Goatee,
+    Moustache,
+}
+
+impl Beard {
+    pub const ALL: [Self; 4] = [Self::None, Self::Full, Self::Goatee, Self::Moustache];
+
+    /// Returns all the possible values.
+    pub fn get_all() -> [&'static str; 4] {
+        ["None", "Full", "Goatee", "Moustache"]
+    }
+
+    /// Parses a beard & fails for unknown strings.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::character::appearance::Beard;
+    /// assert_eq!(Beard::parse("Goatee").unwrap(), Beard::Goatee);
+    /// assert!(Beard::parse("Unknown").is_err());
+    /// ```
+    pub fn parse(string: &str) -> Result<Self> {
+        match string {
+            "None" => Ok(Self::None),
+            "Full" => Ok(Self::Full),
+            "Goatee" => Ok(Self::Goatee),
+            "Moustache" => Ok(Self::Moustache),
+            _ => bail!("Unknown beard '{}'!", string),
+        }
+    }
+}
+
+impl Display for Beard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn only_male_characters_have_random_beards() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+        for _ in 0..20 {
+            assert_eq!(
+                Appearance::random(Gender::Female, &mut rng).beard,
+                Beard::None
+            );
+            assert_eq!(
+                Appearance::random(Gender::Genderless, &mut rng).beard,
+                Beard::None
+            );
+        }
+
+        assert!((0..20).any(|_| Appearance::random(Gender::Male, &mut rng).beard != Beard::None));
+    }
+
+    #[test]
+    fn same_seed_generates_same_appearance() {
+        let mut rng0 = ChaCha8Rng::seed_from_u64(5);
+        let mut rng1 = ChaCha8Rng::seed_from_u64(5);
+
+        assert_eq!(
+            Appearance::random(Gender::Male, &mut rng0),
+            Appearance::random(Gender::Male, &mut rng1)
+        );
+    }
+}
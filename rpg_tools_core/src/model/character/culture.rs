@@ -1,4 +1,10 @@
+use crate::model::character::derivation::DerivationRule;
+use crate::model::character::gender::GenderMap;
+use crate::model::character::generator::NameGenerator;
+use crate::model::character::naming::NamingConvention;
+use crate::model::description::WithDescription;
 use crate::model::name::{Name, WithName};
+use crate::model::visibility::{Visibility, WithVisibility};
 use crate::utils::storage::{Element, Id};
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +27,25 @@ impl Id for CultureId {
 pub struct Culture {
     id: CultureId,
     name: Name,
+    /// How the patronymic is derived from the first name of the father.
+    #[serde(default)]
+    pub patronymic: GenderMap<DerivationRule>,
+    /// How the matronymic is derived from the first name of the mother.
+    #[serde(default)]
+    pub matronymic: GenderMap<DerivationRule>,
+    /// How the names of its characters are written.
+    #[serde(default)]
+    pub naming: NamingConvention,
+    /// Generates random first names for each gender.
+    #[serde(default)]
+    pub first_names: GenderMap<NameGenerator>,
+    /// Generates random family names.
+    #[serde(default)]
+    pub family_names: NameGenerator,
+    #[serde(default)]
+    visibility: Visibility,
+    #[serde(default)]
+    description: Option<String>,
 }
 
 impl Culture {
@@ -28,6 +53,13 @@ impl Culture {
         Culture {
             id,
             name: Name::new(format!("Culture {}", id.0)).unwrap(),
+            patronymic: GenderMap::default(),
+            matronymic: GenderMap::default(),
+            naming: NamingConvention::default(),
+            first_names: GenderMap::default(),
+            family_names: NameGenerator::default(),
+            visibility: Visibility::default(),
+            description: None,
         }
     }
 }
@@ -50,3 +82,23 @@ impl WithName for Culture {
         self.name = name;
     }
 }
+
+impl WithVisibility for Culture {
+    fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+}
+
+impl WithDescription for Culture {
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn set_description(&mut self, description: Option<String>) {
+        self.description = description;
+    }
+}
@@ -0,0 +1,76 @@
+use crate::model::name::Name;
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The placeholder for the name of the parent in a [`rule`](DerivationRule).
+pub const PLACEHOLDER: char = '*';
+
+/// Derives a last name from the first name of a parent by adding a prefix & a suffix.
+///
+/// It is defined by a pattern like *\*son*, *Mac\** or *ap \**.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DerivationRule {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    prefix: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    suffix: String,
+}
+
+impl DerivationRule {
+    pub fn new<S: Into<String>>(prefix: S, suffix: S) -> Self {
+        Self {
+            prefix: prefix.into(),
+            suffix: suffix.into(),
+        }
+    }
+
+    /// Parses a pattern with exactly one placeholder. An empty pattern keeps the name unchanged.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::character::derivation::DerivationRule;
+    /// assert_eq!(DerivationRule::parse("*son").unwrap(), DerivationRule::new("", "son"));
+    /// assert_eq!(DerivationRule::parse(" Mac* ").unwrap(), DerivationRule::new("Mac", ""));
+    /// assert_eq!(DerivationRule::parse("").unwrap(), DerivationRule::default());
+    /// assert!(DerivationRule::parse("son").is_err());
+    /// assert!(DerivationRule::parse("*s*").is_err());
+    /// ```
+    pub fn parse(pattern: &str) -> Result<Self> {
+        let pattern = pattern.trim();
+
+        if pattern.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let mut parts = pattern.split(PLACEHOLDER);
+
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(prefix), Some(suffix), None) => Ok(Self::new(prefix, suffix)),
+            _ => bail!(
+                "Pattern '{}' needs exactly one '{}' for the name of the parent!",
+                pattern,
+                PLACEHOLDER
+            ),
+        }
+    }
+
+    /// Derives the last name.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::character::derivation::DerivationRule;
+    ///# use rpg_tools_core::model::name::Name;
+    /// let rule = DerivationRule::new("ap ", "");
+    ///
+    /// assert_eq!(rule.derive(&Name::new("Rhys").unwrap()).str(), "ap Rhys");
+    /// ```
+    pub fn derive(&self, name: &Name) -> Name {
+        Name::new(format!("{}{}{}", self.prefix, name.str(), self.suffix))
+            .unwrap_or_else(|| name.clone())
+    }
+}
+
+impl Display for DerivationRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}{}", self.prefix, PLACEHOLDER, self.suffix)
+    }
+}
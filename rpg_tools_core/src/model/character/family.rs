@@ -0,0 +1,51 @@
+use crate::model::character::CharacterId;
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of parents of a [`character`](crate::model::character::Character).
+pub const MAX_PARENTS: usize = 2;
+
+/// The family relations of a [`character`](crate::model::character::Character).
+///
+/// The children are not stored, because they can be found via their parents.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Family {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    parents: Vec<CharacterId>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    spouses: Vec<CharacterId>,
+}
+
+impl Family {
+    pub fn parents(&self) -> &[CharacterId] {
+        &self.parents
+    }
+
+    pub fn spouses(&self) -> &[CharacterId] {
+        &self.spouses
+    }
+
+    pub fn is_parent(&self, id: CharacterId) -> bool {
+        self.parents.contains(&id)
+    }
+
+    pub fn is_spouse(&self, id: CharacterId) -> bool {
+        self.spouses.contains(&id)
+    }
+
+    pub(crate) fn parents_mut(&mut self) -> &mut Vec<CharacterId> {
+        &mut self.parents
+    }
+
+    pub(crate) fn spouses_mut(&mut self) -> &mut Vec<CharacterId> {
+        &mut self.spouses
+    }
+
+    /// Replaces a relative, if the id of the relative changed.
+    pub(crate) fn replace(&mut self, old: CharacterId, new: CharacterId) {
+        for id in self.parents.iter_mut().chain(self.spouses.iter_mut()) {
+            if *id == old {
+                *id = new;
+            }
+        }
+    }
+}
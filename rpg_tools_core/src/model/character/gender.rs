@@ -1,7 +1,10 @@
+use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fmt::{Display, Formatter};
 
-/// The gender of a [`character`](crate::model::character::Character).
+/// The base gender, which selects the gender specific names, honorifics & rules of a
+/// [`culture`](crate::model::character::culture::Culture).
 #[derive(Default, Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Gender {
     #[default]
@@ -16,13 +19,234 @@ impl Display for Gender {
     }
 }
 
-impl From<&str> for Gender {
-    fn from(string: &str) -> Self {
+impl Gender {
+    /// Returns all the possible values.
+    pub fn get_all() -> [&'static str; 3] {
+        ["Female", "Genderless", "Male"]
+    }
+
+    /// Parses a gender & fails for unknown strings.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::character::gender::Gender;
+    /// assert_eq!(Gender::parse("Male").unwrap(), Gender::Male);
+    /// assert!(Gender::parse("male").is_err());
+    /// assert!(Gender::parse("Unknown").is_err());
+    /// ```
+    pub fn parse(string: &str) -> Result<Self> {
         match string {
-            "Female" => Self::Female,
-            "Genderless" => Self::Genderless,
-            "Male" => Self::Male,
-            _ => Self::default(),
+            "Female" => Ok(Self::Female),
+            "Genderless" => Ok(Self::Genderless),
+            "Male" => Ok(Self::Male),
+            _ => bail!("Unknown gender '{}'!", string),
         }
     }
 }
+
+/// The pronouns used to refer to a [`character`](crate::model::character::Character).
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Pronouns {
+    /// E.g. "she" as in "she is here".
+    pub subject: String,
+    /// E.g. "her" as in "I see her".
+    pub object: String,
+    /// E.g. "her" as in "her house".
+    pub possessive: String,
+}
+
+impl Pronouns {
+    pub fn new(subject: &str, object: &str, possessive: &str) -> Self {
+        Self {
+            subject: subject.to_string(),
+            object: object.to_string(),
+            possessive: possessive.to_string(),
+        }
+    }
+}
+
+/// A gender defined by the setting.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GenderDefinition {
+    pub name: String,
+    pub base: Gender,
+    pub pronouns: Pronouns,
+}
+
+impl GenderDefinition {
+    pub fn new(name: &str, base: Gender, pronouns: Pronouns) -> Self {
+        Self {
+            name: name.to_string(),
+            base,
+            pronouns,
+        }
+    }
+}
+
+/// All the genders of a setting.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GenderSchema {
+    pub genders: Vec<GenderDefinition>,
+}
+
+impl Default for GenderSchema {
+    /// Returns a gender for each [`base gender`](Gender).
+    fn default() -> Self {
+        Self {
+            genders: vec![
+                GenderDefinition::new("Female", Gender::Female, Pronouns::new("she", "her", "her")),
+                GenderDefinition::new(
+                    "Genderless",
+                    Gender::Genderless,
+                    Pronouns::new("they", "them", "their"),
+                ),
+                GenderDefinition::new("Male", Gender::Male, Pronouns::new("he", "him", "his")),
+            ],
+        }
+    }
+}
+
+impl GenderSchema {
+    /// Checks, that there is at least one gender & that the names are unique & not empty.
+    pub fn check(&self) -> Result<()> {
+        if self.genders.is_empty() {
+            bail!("The setting needs at least one gender!");
+        }
+
+        let mut names = HashSet::new();
+
+        for gender in &self.genders {
+            if gender.name.trim().is_empty() {
+                bail!("The name of a gender is empty!");
+            } else if !names.insert(&gender.name) {
+                bail!("The gender '{}' is defined twice!", gender.name);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the names of all genders.
+    pub fn get_names(&self) -> Vec<&str> {
+        self.genders
+            .iter()
+            .map(|gender| gender.name.as_str())
+            .collect()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&GenderDefinition> {
+        self.genders.iter().find(|gender| gender.name.eq(name))
+    }
+
+    /// Returns a gender & fails for unknown names.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::character::gender::{Gender, GenderSchema};
+    /// let schema = GenderSchema::default();
+    ///
+    /// assert_eq!(schema.parse("Male").unwrap().base, Gender::Male);
+    /// assert!(schema.parse("Unknown").is_err());
+    /// ```
+    pub fn parse(&self, name: &str) -> Result<&GenderDefinition> {
+        self.get(name)
+            .context(format!("Unknown gender '{}'!", name))
+    }
+
+    /// Returns the base gender of a gender & fails for unknown names.
+    pub fn get_base(&self, name: &str) -> Result<Gender> {
+        self.parse(name).map(|gender| gender.base)
+    }
+
+    /// Returns the first gender with the base gender.
+    pub fn get_by_base(&self, base: Gender) -> Option<&GenderDefinition> {
+        self.genders.iter().find(|gender| gender.base == base)
+    }
+}
+
+/// Stores a value for each [`gender`](Gender).
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GenderMap<T> {
+    #[serde(default)]
+    pub female: T,
+    #[serde(default)]
+    pub genderless: T,
+    #[serde(default)]
+    pub male: T,
+}
+
+impl<T> GenderMap<T> {
+    pub fn new(female: T, genderless: T, male: T) -> Self {
+        Self {
+            female,
+            genderless,
+            male,
+        }
+    }
+
+    pub fn get(&self, gender: Gender) -> &T {
+        match gender {
+            Gender::Female => &self.female,
+            Gender::Genderless => &self.genderless,
+            Gender::Male => &self.male,
+        }
+    }
+
+    pub fn get_mut(&mut self, gender: Gender) -> &mut T {
+        match gender {
+            Gender::Female => &mut self.female,
+            Gender::Genderless => &mut self.genderless,
+            Gender::Male => &mut self.male,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_default() {
+        assert!(GenderSchema::default().check().is_ok());
+    }
+
+    #[test]
+    fn check_empty() {
+        assert!(GenderSchema { genders: vec![] }.check().is_err());
+    }
+
+    #[test]
+    fn check_duplicate() {
+        let mut schema = GenderSchema::default();
+        schema.genders.push(GenderDefinition::new(
+            "Male",
+            Gender::Male,
+            Pronouns::new("he", "him", "his"),
+        ));
+
+        assert!(schema.check().is_err());
+    }
+
+    #[test]
+    fn check_empty_name() {
+        let mut schema = GenderSchema::default();
+        schema.genders[0].name = " ".to_string();
+
+        assert!(schema.check().is_err());
+    }
+
+    #[test]
+    fn get_base() {
+        let mut schema = GenderSchema::default();
+        schema.genders.push(GenderDefinition::new(
+            "Nonbinary",
+            Gender::Genderless,
+            Pronouns::new("xe", "xem", "xyr"),
+        ));
+
+        assert_eq!(schema.get_base("Nonbinary").unwrap(), Gender::Genderless);
+        assert!(schema.get_base("Unknown").is_err());
+        assert_eq!(
+            schema.get_by_base(Gender::Genderless).unwrap().name,
+            "Genderless"
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use crate::model::name::Name;
+use crate::utils::markov::MarkovChain;
+use anyhow::{bail, Result};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The order of the [`markov chain`](MarkovChain) used by [`NameGenerator::Markov`].
+pub const MARKOV_ORDER: usize = 2;
+
+/// Generates random names for a [`culture`](crate::model::character::culture::Culture).
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum NameGenerator {
+    /// Generates no names.
+    #[default]
+    None,
+    /// Picks a name from a list.
+    List { names: Vec<String> },
+    /// Generates new names with a markov chain trained on the examples.
+    Markov { examples: Vec<String> },
+}
+
+impl NameGenerator {
+    pub fn get_all() -> Vec<&'static str> {
+        vec!["None", "List", "Markov"]
+    }
+
+    /// Parses the type & the names, one per line.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::character::generator::NameGenerator;
+    /// assert_eq!(NameGenerator::parse("List", "Anna\n\n Bob \r\n").unwrap(),
+    ///     NameGenerator::List { names: vec!["Anna".to_string(), "Bob".to_string()] });
+    /// assert_eq!(NameGenerator::parse("List", "  ").unwrap(), NameGenerator::None);
+    /// assert_eq!(NameGenerator::parse("None", "Anna").unwrap(), NameGenerator::None);
+    /// assert!(NameGenerator::parse("Unknown", "Anna").is_err());
+    /// ```
+    pub fn parse(generator_type: &str, names: &str) -> Result<Self> {
+        let names: Vec<String> = names
+            .lines()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        if names.is_empty() {
+            return match generator_type {
+                "None" | "List" | "Markov" => Ok(Self::None),
+                _ => bail!("Unknown name generator '{}'!", generator_type),
+            };
+        }
+
+        match generator_type {
+            "None" => Ok(Self::None),
+            "List" => Ok(Self::List { names }),
+            "Markov" => Ok(Self::Markov { examples: names }),
+            _ => bail!("Unknown name generator '{}'!", generator_type),
+        }
+    }
+
+    /// Returns the names of the list or the examples of the markov chain.
+    pub fn names(&self) -> &[String] {
+        match self {
+            Self::None => &[],
+            Self::List { names } => names,
+            Self::Markov { examples } => examples,
+        }
+    }
+
+    /// Generates a random name, if possible.
+    ///
+    /// Use a [`sampler`](NameGenerator::sampler) to generate many names.
+    pub fn generate<R: Rng>(&self, rng: &mut R) -> Option<Name> {
+        self.sampler().generate(rng)
+    }
+
+    /// Prepares the generator to generate many names, which trains the markov chain only once.
+    pub fn sampler(&self) -> NameSampler<'_> {
+        match self {
+            Self::None => NameSampler::None,
+            Self::List { names } => NameSampler::List(names),
+            Self::Markov { examples } => {
+                NameSampler::Markov(MarkovChain::train(examples, MARKOV_ORDER))
+            }
+        }
+    }
+}
+
+/// A [`name generator`](NameGenerator) prepared to generate many names.
+#[derive(Clone, Debug)]
+pub enum NameSampler<'a> {
+    None,
+    List(&'a [String]),
+    Markov(MarkovChain),
+}
+
+impl<'a> NameSampler<'a> {
+    pub fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+
+    /// Generates a random name, if possible.
+    pub fn generate<R: Rng>(&self, rng: &mut R) -> Option<Name> {
+        match self {
+            Self::None => None,
+            Self::List(names) => names.choose(rng).and_then(|name| Name::new(name.as_str())),
+            Self::Markov(chain) => chain.generate(rng).and_then(Name::new),
+        }
+    }
+}
+
+impl Display for NameGenerator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::List { .. } => write!(f, "List"),
+            Self::Markov { .. } => write!(f, "Markov"),
+        }
+    }
+}
@@ -1,13 +1,25 @@
+use crate::model::character::appearance::Appearance;
 use crate::model::character::culture::CultureId;
+use crate::model::character::family::Family;
 use crate::model::character::gender::Gender;
 use crate::model::character::name::CharacterName;
+use crate::model::description::WithDescription;
 use crate::model::name::Name;
+use crate::model::stats::StatBlock;
+use crate::model::time::date::Date;
+use crate::model::visibility::{Visibility, WithVisibility};
+use crate::model::world::building::BuildingId;
 use crate::utils::storage::{Element, Id};
 use serde::{Deserialize, Serialize};
 
+pub mod appearance;
 pub mod culture;
+pub mod derivation;
+pub mod family;
 pub mod gender;
+pub mod generator;
 pub mod name;
+pub mod naming;
 
 /// The unique identifier of a [`character`](Character).
 #[derive(Default, Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
@@ -28,8 +40,29 @@ impl Id for CharacterId {
 pub struct Character {
     id: CharacterId,
     pub name: CharacterName,
-    pub gender: Gender,
+    /// The name of a [`gender`](gender::GenderDefinition) of the setting.
+    pub gender: String,
     pub culture: CultureId,
+    #[serde(default)]
+    pub appearance: Appearance,
+    #[serde(default, skip_serializing_if = "StatBlock::is_empty")]
+    pub stats: StatBlock,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub birth_date: Option<Date>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub death_date: Option<Date>,
+    #[serde(default)]
+    pub family: Family,
+    /// The building the character lives in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub home: Option<BuildingId>,
+    /// The building the character works in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workplace: Option<BuildingId>,
+    #[serde(default)]
+    visibility: Visibility,
+    #[serde(default)]
+    description: Option<String>,
 }
 
 impl Character {
@@ -37,8 +70,17 @@ impl Character {
         Character {
             id,
             name: CharacterName::only_first(Name::new(format!("Character {}", id.0)).unwrap()),
-            gender: Gender::default(),
+            gender: Gender::default().to_string(),
             culture: Default::default(),
+            appearance: Appearance::default(),
+            stats: StatBlock::default(),
+            birth_date: None,
+            death_date: None,
+            family: Family::default(),
+            home: None,
+            workplace: None,
+            visibility: Visibility::default(),
+            description: None,
         }
     }
 }
@@ -52,3 +94,23 @@ impl Element<CharacterId> for Character {
         Character { id, ..self }
     }
 }
+
+impl WithVisibility for Character {
+    fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+}
+
+impl WithDescription for Character {
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn set_description(&mut self, description: Option<String>) {
+        self.description = description;
+    }
+}
@@ -51,10 +51,10 @@ impl CharacterName {
                     "Family Name" => Lastname::Family { name },
                     "Patronymic" => Lastname::Patronymic { name },
                     "Matronymic" => Lastname::Matronymic { name },
-                    _ => return bail!("Unknown type of last name"),
+                    _ => bail!("Unknown type of last name"),
                 }
             } else {
-                return bail!("Last name is invalid");
+                bail!("Last name is invalid");
             };
 
             Ok(Self {
@@ -81,6 +81,10 @@ impl CharacterName {
     pub fn last(&self) -> &Lastname {
         &self.last
     }
+
+    pub fn set_last(&mut self, last: Lastname) {
+        self.last = last;
+    }
 }
 
 impl Display for CharacterName {
@@ -141,6 +145,50 @@ impl Lastname {
     }
 }
 
+/// The type of a [`last name`](Lastname).
+#[derive(Default, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LastnameType {
+    #[default]
+    None,
+    Family,
+    Patronymic,
+    Matronymic,
+}
+
+impl LastnameType {
+    pub fn get_all() -> Vec<&'static str> {
+        vec!["None", "Family Name", "Patronymic", "Matronymic"]
+    }
+
+    /// Parses the type like it is returned by [`Lastname::get_type()`].
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::character::name::LastnameType;
+    /// assert_eq!(LastnameType::parse("Family Name").unwrap(), LastnameType::Family);
+    /// assert!(LastnameType::parse("Unknown").is_err());
+    /// ```
+    pub fn parse(string: &str) -> Result<Self> {
+        match string {
+            "None" => Ok(Self::None),
+            "Family Name" => Ok(Self::Family),
+            "Patronymic" => Ok(Self::Patronymic),
+            "Matronymic" => Ok(Self::Matronymic),
+            _ => bail!("Unknown type of last name '{}'!", string),
+        }
+    }
+}
+
+impl Display for LastnameType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::Family => write!(f, "Family Name"),
+            Self::Patronymic => write!(f, "Patronymic"),
+            Self::Matronymic => write!(f, "Matronymic"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,177 @@
+use crate::model::character::gender::{Gender, GenderMap};
+use crate::model::character::name::CharacterName;
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The order of the parts of a [`name`](CharacterName).
+#[derive(Default, Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum NameOrder {
+    /// First, middle & then last name.
+    #[default]
+    GivenFirst,
+    /// Last, first & then middle name.
+    FamilyFirst,
+}
+
+impl NameOrder {
+    /// Returns all the possible values.
+    pub fn get_all() -> [&'static str; 2] {
+        ["GivenFirst", "FamilyFirst"]
+    }
+
+    /// Parses a name order & fails for unknown strings.
+    pub fn parse(string: &str) -> Result<Self> {
+        match string {
+            "GivenFirst" => Ok(Self::GivenFirst),
+            "FamilyFirst" => Ok(Self::FamilyFirst),
+            _ => bail!("Unknown name order '{}'!", string),
+        }
+    }
+}
+
+impl Display for NameOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Where is the honorific placed?
+#[derive(Default, Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum HonorificPlacement {
+    /// Before the name & separated by a space, like *Mr. Smith*.
+    #[default]
+    Before,
+    /// Directly after the name, like *Tanaka-san*.
+    After,
+}
+
+impl HonorificPlacement {
+    /// Returns all the possible values.
+    pub fn get_all() -> [&'static str; 2] {
+        ["Before", "After"]
+    }
+
+    /// Parses a placement & fails for unknown strings.
+    pub fn parse(string: &str) -> Result<Self> {
+        match string {
+            "Before" => Ok(Self::Before),
+            "After" => Ok(Self::After),
+            _ => bail!("Unknown honorific placement '{}'!", string),
+        }
+    }
+}
+
+impl Display for HonorificPlacement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The different ways to write a [`name`](CharacterName).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NameFormat {
+    /// All parts of the name in the order of the culture.
+    Full,
+    /// The last name with the honorific.
+    Formal,
+    /// Only the first name.
+    Short,
+}
+
+/// How the [`names`](CharacterName) of a [`culture`](crate::model::character::culture::Culture)
+/// are written.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct NamingConvention {
+    #[serde(default)]
+    pub order: NameOrder,
+    #[serde(default = "use_middle_names")]
+    pub use_middle_names: bool,
+    #[serde(default)]
+    pub honorifics: GenderMap<String>,
+    #[serde(default)]
+    pub honorific_placement: HonorificPlacement,
+}
+
+fn use_middle_names() -> bool {
+    true
+}
+
+impl Default for NamingConvention {
+    fn default() -> Self {
+        Self {
+            order: NameOrder::default(),
+            use_middle_names: use_middle_names(),
+            honorifics: GenderMap::default(),
+            honorific_placement: HonorificPlacement::default(),
+        }
+    }
+}
+
+impl NamingConvention {
+    /// Writes the name of a character.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::character::gender::Gender;
+    ///# use rpg_tools_core::model::character::name::{CharacterName, Lastname};
+    ///# use rpg_tools_core::model::character::naming::{HonorificPlacement, NameFormat, NameOrder, NamingConvention};
+    ///# use rpg_tools_core::model::name::Name;
+    /// let name = CharacterName::full(
+    ///     Name::new("Taro").unwrap(),
+    ///     Name::new("Jiro").unwrap(),
+    ///     Lastname::Family { name: Name::new("Tanaka").unwrap() },
+    /// );
+    /// let mut convention = NamingConvention::default();
+    ///
+    /// assert_eq!(convention.format(&name, Gender::Male, NameFormat::Full), "Taro Jiro Tanaka");
+    ///
+    /// convention.order = NameOrder::FamilyFirst;
+    /// convention.use_middle_names = false;
+    /// convention.honorifics.male = "-san".to_string();
+    /// convention.honorific_placement = HonorificPlacement::After;
+    ///
+    /// assert_eq!(convention.format(&name, Gender::Male, NameFormat::Full), "Tanaka Taro");
+    /// assert_eq!(convention.format(&name, Gender::Male, NameFormat::Formal), "Tanaka-san");
+    /// assert_eq!(convention.format(&name, Gender::Female, NameFormat::Formal), "Tanaka");
+    /// assert_eq!(convention.format(&name, Gender::Male, NameFormat::Short), "Taro");
+    /// ```
+    pub fn format(&self, name: &CharacterName, gender: Gender, format: NameFormat) -> String {
+        match format {
+            NameFormat::Full => self.format_full(name),
+            NameFormat::Formal => self.format_formal(name, gender),
+            NameFormat::Short => name.first().to_string(),
+        }
+    }
+
+    fn format_full(&self, name: &CharacterName) -> String {
+        let first = Some(name.first());
+        let middle = name.middle().filter(|_| self.use_middle_names);
+        let last = name.last().name();
+
+        let parts = match self.order {
+            NameOrder::GivenFirst => [first, middle, last],
+            NameOrder::FamilyFirst => [last, first, middle],
+        };
+
+        parts
+            .iter()
+            .flatten()
+            .map(|name| name.str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn format_formal(&self, name: &CharacterName, gender: Gender) -> String {
+        let name = name.last().name().unwrap_or(name.first()).str();
+        let honorific = self.honorifics.get(gender).trim();
+
+        if honorific.is_empty() {
+            name.to_string()
+        } else {
+            match self.honorific_placement {
+                HonorificPlacement::Before => format!("{} {}", honorific, name),
+                HonorificPlacement::After => format!("{}{}", name, honorific),
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
@@ -8,7 +9,9 @@ pub enum Color {
     Aqua,
     Black,
     Blue,
+    Chocolate,
     Fuchsia,
+    Gold,
     Gray,
     Green,
     Lime,
@@ -16,16 +19,91 @@ pub enum Color {
     Navy,
     Olive,
     Orange,
+    PeachPuff,
     #[default]
     Purple,
     Red,
     SaddleBrown,
+    Sienna,
     Silver,
+    Tan,
     Teal,
+    Wheat,
     White,
     Yellow,
 }
 
+impl Color {
+    /// Returns all the possible values.
+    pub fn get_all() -> [&'static str; 24] {
+        [
+            "Aqua",
+            "Black",
+            "Blue",
+            "Chocolate",
+            "Fuchsia",
+            "Gold",
+            "Gray",
+            "Green",
+            "Lime",
+            "Maroon",
+            "Navy",
+            "Olive",
+            "Orange",
+            "PeachPuff",
+            "Purple",
+            "Red",
+            "SaddleBrown",
+            "Sienna",
+            "Silver",
+            "Tan",
+            "Teal",
+            "Wheat",
+            "White",
+            "Yellow",
+        ]
+    }
+
+    /// Parses a color & fails for unknown strings.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::color::Color;
+    /// for color in Color::get_all() {
+    ///     assert_eq!(Color::parse(color).unwrap().to_string(), color);
+    /// }
+    /// assert!(Color::parse("Unknown").is_err());
+    /// ```
+    pub fn parse(string: &str) -> Result<Self> {
+        match string {
+            "Aqua" => Ok(Self::Aqua),
+            "Black" => Ok(Self::Black),
+            "Blue" => Ok(Self::Blue),
+            "Chocolate" => Ok(Self::Chocolate),
+            "Fuchsia" => Ok(Self::Fuchsia),
+            "Gold" => Ok(Self::Gold),
+            "Gray" => Ok(Self::Gray),
+            "Green" => Ok(Self::Green),
+            "Lime" => Ok(Self::Lime),
+            "Maroon" => Ok(Self::Maroon),
+            "Navy" => Ok(Self::Navy),
+            "Olive" => Ok(Self::Olive),
+            "Orange" => Ok(Self::Orange),
+            "PeachPuff" => Ok(Self::PeachPuff),
+            "Purple" => Ok(Self::Purple),
+            "Red" => Ok(Self::Red),
+            "SaddleBrown" => Ok(Self::SaddleBrown),
+            "Sienna" => Ok(Self::Sienna),
+            "Silver" => Ok(Self::Silver),
+            "Tan" => Ok(Self::Tan),
+            "Teal" => Ok(Self::Teal),
+            "Wheat" => Ok(Self::Wheat),
+            "White" => Ok(Self::White),
+            "Yellow" => Ok(Self::Yellow),
+            _ => bail!("Unknown color '{}'!", string),
+        }
+    }
+}
+
 impl Display for Color {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}", self)
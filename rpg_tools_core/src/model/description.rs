@@ -0,0 +1,8 @@
+/// A trait for elements with an optional description in [Markdown](https://commonmark.org/).
+///
+/// Other elements can be linked with *[[Name]]* or *[[storage:Name]]*,
+/// e.g. *[[town:Arkham]]*.
+pub trait WithDescription {
+    fn description(&self) -> Option<&str>;
+    fn set_description(&mut self, description: Option<String>);
+}
@@ -0,0 +1,97 @@
+use crate::model::character::culture::CultureId;
+use crate::model::character::CharacterId;
+use crate::model::event::EventId;
+use crate::model::organization::OrganizationId;
+use crate::model::world::building::BuildingId;
+use crate::model::world::mountain::MountainId;
+use crate::model::world::river::RiverId;
+use crate::model::world::street::StreetId;
+use crate::model::world::town::TownId;
+use crate::utils::storage::Id;
+use anyhow::{bail, Result};
+use std::fmt::{Display, Formatter};
+
+/// A reference to any element of the [`rpg data`](crate::model::RpgData).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ElementRef {
+    Building(BuildingId),
+    Character(CharacterId),
+    Culture(CultureId),
+    Event(EventId),
+    Mountain(MountainId),
+    Organization(OrganizationId),
+    River(RiverId),
+    Street(StreetId),
+    Town(TownId),
+}
+
+impl ElementRef {
+    /// Creates a reference from the name of a [`storage`](crate::utils::storage::Storage)
+    /// & the id inside it.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::element::ElementRef;
+    ///# use rpg_tools_core::model::world::town::TownId;
+    ///# use rpg_tools_core::utils::storage::Id;
+    /// assert_eq!(ElementRef::parse("town", 3).unwrap(), ElementRef::Town(TownId::new(3)));
+    /// assert!(ElementRef::parse("unknown", 3).is_err());
+    /// ```
+    pub fn parse(storage: &str, id: usize) -> Result<Self> {
+        Ok(match storage {
+            "building" => ElementRef::Building(BuildingId::new(id)),
+            "character" => ElementRef::Character(CharacterId::new(id)),
+            "culture" => ElementRef::Culture(CultureId::new(id)),
+            "event" => ElementRef::Event(EventId::new(id)),
+            "mountain" => ElementRef::Mountain(MountainId::new(id)),
+            "organization" => ElementRef::Organization(OrganizationId::new(id)),
+            "river" => ElementRef::River(RiverId::new(id)),
+            "street" => ElementRef::Street(StreetId::new(id)),
+            "town" => ElementRef::Town(TownId::new(id)),
+            _ => bail!("Unknown type '{}'!", storage),
+        })
+    }
+
+    /// Returns the name of the [`storage`](crate::utils::storage::Storage) of the element.
+    pub fn storage(&self) -> &'static str {
+        match self {
+            ElementRef::Building(_) => "building",
+            ElementRef::Character(_) => "character",
+            ElementRef::Culture(_) => "culture",
+            ElementRef::Event(_) => "event",
+            ElementRef::Mountain(_) => "mountain",
+            ElementRef::Organization(_) => "organization",
+            ElementRef::River(_) => "river",
+            ElementRef::Street(_) => "street",
+            ElementRef::Town(_) => "town",
+        }
+    }
+
+    /// Returns the id of the element inside its [`storage`](crate::utils::storage::Storage).
+    pub fn id(&self) -> usize {
+        match self {
+            ElementRef::Building(id) => id.id(),
+            ElementRef::Character(id) => id.id(),
+            ElementRef::Culture(id) => id.id(),
+            ElementRef::Event(id) => id.id(),
+            ElementRef::Mountain(id) => id.id(),
+            ElementRef::Organization(id) => id.id(),
+            ElementRef::River(id) => id.id(),
+            ElementRef::Street(id) => id.id(),
+            ElementRef::Town(id) => id.id(),
+        }
+    }
+}
+
+impl Display for ElementRef {
+    /// Displays the reference.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::element::ElementRef;
+    ///# use rpg_tools_core::model::world::town::TownId;
+    ///# use rpg_tools_core::utils::storage::Id;
+    /// assert_eq!(ElementRef::Town(TownId::new(3)).to_string(), "town 3");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.storage(), self.id())
+    }
+}
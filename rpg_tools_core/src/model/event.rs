@@ -0,0 +1,209 @@
+use crate::model::character::CharacterId;
+use crate::model::description::WithDescription;
+use crate::model::element::ElementRef;
+use crate::model::name::{Name, WithName};
+use crate::model::time::date::Date;
+use crate::model::visibility::{Visibility, WithVisibility};
+use crate::model::world::building::BuildingId;
+use crate::model::world::street::StreetId;
+use crate::model::world::town::TownId;
+use crate::utils::storage::{Element, Id};
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// The unique identifier of an [`event`](Event).
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct EventId(usize);
+
+impl Id for EventId {
+    fn new(id: usize) -> Self {
+        Self(id)
+    }
+
+    fn id(&self) -> usize {
+        self.0
+    }
+}
+
+/// Something, that happened at a date of the campaign's calendar.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Event {
+    id: EventId,
+    name: Name,
+    pub date: Date,
+    #[serde(default)]
+    visibility: Visibility,
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    characters: HashSet<CharacterId>,
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    buildings: HashSet<BuildingId>,
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    streets: HashSet<StreetId>,
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    towns: HashSet<TownId>,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+impl Event {
+    pub fn new(id: EventId) -> Self {
+        Event {
+            id,
+            name: Name::new(format!("Event {}", id.0)).unwrap(),
+            date: Date::new(1, 1, 1),
+            visibility: Visibility::default(),
+            characters: HashSet::new(),
+            buildings: HashSet::new(),
+            streets: HashSet::new(),
+            towns: HashSet::new(),
+            description: None,
+        }
+    }
+
+    /// Returns all participating elements ordered by type & id.
+    pub fn participants(&self) -> Vec<ElementRef> {
+        let mut participants: Vec<ElementRef> = self
+            .characters
+            .iter()
+            .map(|id| ElementRef::Character(*id))
+            .chain(self.buildings.iter().map(|id| ElementRef::Building(*id)))
+            .chain(self.streets.iter().map(|id| ElementRef::Street(*id)))
+            .chain(self.towns.iter().map(|id| ElementRef::Town(*id)))
+            .collect();
+
+        participants.sort_by_key(|element| (element.storage(), element.id()));
+        participants
+    }
+
+    pub fn has_participant(&self, element: ElementRef) -> bool {
+        match element {
+            ElementRef::Building(id) => self.buildings.contains(&id),
+            ElementRef::Character(id) => self.characters.contains(&id),
+            ElementRef::Street(id) => self.streets.contains(&id),
+            ElementRef::Town(id) => self.towns.contains(&id),
+            _ => false,
+        }
+    }
+
+    /// Adds a character, building, street or town & fails for other elements.
+    pub fn add_participant(&mut self, element: ElementRef) -> Result<()> {
+        match element {
+            ElementRef::Building(id) => self.buildings.insert(id),
+            ElementRef::Character(id) => self.characters.insert(id),
+            ElementRef::Street(id) => self.streets.insert(id),
+            ElementRef::Town(id) => self.towns.insert(id),
+            _ => bail!("A {} can't participate in an event!", element.storage()),
+        };
+
+        Ok(())
+    }
+
+    /// Removes a participant & returns true, if it was removed.
+    pub fn remove_participant(&mut self, element: ElementRef) -> bool {
+        match element {
+            ElementRef::Building(id) => self.buildings.remove(&id),
+            ElementRef::Character(id) => self.characters.remove(&id),
+            ElementRef::Street(id) => self.streets.remove(&id),
+            ElementRef::Town(id) => self.towns.remove(&id),
+            _ => false,
+        }
+    }
+
+    /// Replaces a participant, which changed its id.
+    pub fn replace_participant(&mut self, old: ElementRef, new: ElementRef) {
+        if self.remove_participant(old) {
+            // only fails for elements, which couldn't be removed
+            let _ = self.add_participant(new);
+        }
+    }
+}
+
+impl Element<EventId> for Event {
+    fn id(&self) -> EventId {
+        self.id
+    }
+
+    fn with_id(self, id: EventId) -> Self {
+        Event { id, ..self }
+    }
+}
+
+impl WithName for Event {
+    fn name(&self) -> &Name {
+        &self.name
+    }
+
+    fn set_name(&mut self, name: Name) {
+        self.name = name;
+    }
+}
+
+impl WithDescription for Event {
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn set_description(&mut self, description: Option<String>) {
+        self.description = description;
+    }
+}
+
+impl WithVisibility for Event {
+    fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::culture::CultureId;
+
+    #[test]
+    fn add_and_remove_participants() {
+        let mut event = Event::new(EventId::default());
+        let character = ElementRef::Character(CharacterId::new(2));
+        let town = ElementRef::Town(TownId::new(0));
+
+        assert!(event.add_participant(town).is_ok());
+        assert!(event.add_participant(character).is_ok());
+        assert!(event.add_participant(character).is_ok());
+
+        assert_eq!(event.participants(), vec![character, town]);
+        assert!(event.has_participant(character));
+
+        assert!(event.remove_participant(character));
+        assert!(!event.remove_participant(character));
+
+        assert_eq!(event.participants(), vec![town]);
+        assert!(!event.has_participant(character));
+    }
+
+    #[test]
+    fn unsupported_participant() {
+        let mut event = Event::new(EventId::default());
+        let culture = ElementRef::Culture(CultureId::default());
+
+        assert!(event.add_participant(culture).is_err());
+        assert!(event.participants().is_empty());
+    }
+
+    #[test]
+    fn replace_participant() {
+        let mut event = Event::new(EventId::default());
+        let old = ElementRef::Building(BuildingId::new(3));
+        let new = ElementRef::Building(BuildingId::new(1));
+        let other = ElementRef::Building(BuildingId::new(2));
+        event.add_participant(old).unwrap();
+
+        event.replace_participant(old, new);
+        event.replace_participant(other, old);
+
+        assert_eq!(event.participants(), vec![new]);
+    }
+}
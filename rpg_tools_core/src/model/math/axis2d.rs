@@ -0,0 +1,41 @@
+use anyhow::{bail, Result};
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// The 2 axes of a 2d space.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Axis2d {
+    /// Along the x-axis from left to right.
+    Horizontal,
+    /// Along the y-axis from top to bottom.
+    Vertical,
+}
+
+impl Axis2d {
+    pub fn get_all() -> Vec<Self> {
+        vec![Self::Horizontal, Self::Vertical]
+    }
+
+    /// Parses an axis from a string.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::math::axis2d::Axis2d;
+    /// for axis in Axis2d::get_all() {
+    ///     assert_eq!(Axis2d::parse(&axis.to_string()).unwrap(), axis);
+    /// }
+    /// assert!(Axis2d::parse("Diagonal").is_err());
+    /// ```
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "Horizontal" => Ok(Self::Horizontal),
+            "Vertical" => Ok(Self::Vertical),
+            _ => bail!("Unknown axis '{}'!", value),
+        }
+    }
+}
+
+impl fmt::Display for Axis2d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
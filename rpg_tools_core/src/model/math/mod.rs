@@ -1,4 +1,5 @@
 pub mod aabb2d;
+pub mod axis2d;
 pub mod point2d;
 pub mod side2d;
 pub mod size2d;
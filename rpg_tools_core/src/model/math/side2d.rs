@@ -1,3 +1,4 @@
+use anyhow::{bail, Result};
 use core::fmt;
 use serde::{Deserialize, Serialize};
 
@@ -10,6 +11,67 @@ pub enum Side2d {
     Right,
 }
 
+impl Side2d {
+    pub fn get_all() -> Vec<Self> {
+        vec![Self::Top, Self::Left, Self::Bottom, Self::Right]
+    }
+
+    /// Parses a side from a string.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::math::side2d::Side2d;
+    /// for side in Side2d::get_all() {
+    ///     assert_eq!(Side2d::parse(&side.to_string()).unwrap(), side);
+    /// }
+    /// assert!(Side2d::parse("Center").is_err());
+    /// ```
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "Top" => Ok(Self::Top),
+            "Left" => Ok(Self::Left),
+            "Bottom" => Ok(Self::Bottom),
+            "Right" => Ok(Self::Right),
+            _ => bail!("Unknown side '{}'!", value),
+        }
+    }
+
+    /// Returns the offset to the neighbouring tile on this side.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::math::side2d::Side2d;
+    /// assert_eq!(Side2d::Top.get_offset(), (0, -1));
+    /// assert_eq!(Side2d::Left.get_offset(), (-1, 0));
+    /// assert_eq!(Side2d::Bottom.get_offset(), (0, 1));
+    /// assert_eq!(Side2d::Right.get_offset(), (1, 0));
+    /// ```
+    pub fn get_offset(&self) -> (i32, i32) {
+        match self {
+            Self::Top => (0, -1),
+            Self::Left => (-1, 0),
+            Self::Bottom => (0, 1),
+            Self::Right => (1, 0),
+        }
+    }
+
+    /// Returns the side after a clockwise rotation by 90°.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::math::side2d::Side2d;
+    /// assert_eq!(Side2d::Top.rotate_clockwise(), Side2d::Right);
+    /// assert_eq!(Side2d::Left.rotate_clockwise(), Side2d::Top);
+    /// assert_eq!(Side2d::Bottom.rotate_clockwise(), Side2d::Left);
+    /// assert_eq!(Side2d::Right.rotate_clockwise(), Side2d::Bottom);
+    /// ```
+    pub fn rotate_clockwise(&self) -> Self {
+        match self {
+            Self::Top => Self::Right,
+            Self::Left => Self::Top,
+            Self::Bottom => Self::Left,
+            Self::Right => Self::Bottom,
+        }
+    }
+}
+
 impl fmt::Display for Side2d {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{:?}", self)
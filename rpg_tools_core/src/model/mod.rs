@@ -1,20 +1,37 @@
 use crate::model::character::culture::{Culture, CultureId};
+use crate::model::character::gender::GenderSchema;
 use crate::model::character::{Character, CharacterId};
+use crate::model::event::{Event, EventId};
+use crate::model::organization::{Organization, OrganizationId};
+use crate::model::stats::StatSchema;
+use crate::model::time::CampaignTime;
+use crate::model::world::building::types::BuildingTypeCatalog;
 use crate::model::world::building::{Building, BuildingId};
 use crate::model::world::mountain::{Mountain, MountainId};
 use crate::model::world::river::{River, RiverId};
 use crate::model::world::street::{Street, StreetId};
 use crate::model::world::town::{Town, TownId};
-use crate::utils::io::{load_storage, save_storage};
-use crate::utils::storage::Storage;
+use crate::utils::io::{load_storage, read, save_storage, write};
+use crate::utils::storage::{Element, Id, Storage};
+use anyhow::Context;
+use serde::de::DeserializeOwned;
 use std::path::PathBuf;
 
 pub mod character;
 pub mod color;
+pub mod description;
+pub mod element;
+pub mod event;
 pub mod math;
 pub mod name;
+pub mod organization;
+pub mod stats;
+pub mod time;
+pub mod visibility;
 pub mod world;
 
+const TIME_FILE: &str = "time.yaml";
+
 /// Contains all the data of the rpg.
 #[derive(Debug, Default)]
 pub struct RpgData {
@@ -22,37 +39,110 @@ pub struct RpgData {
     pub building_manager: Storage<BuildingId, Building>,
     pub characters: Storage<CharacterId, Character>,
     pub cultures: Storage<CultureId, Culture>,
+    pub events: Storage<EventId, Event>,
     pub mountain_manager: Storage<MountainId, Mountain>,
+    pub organizations: Storage<OrganizationId, Organization>,
     pub river_manager: Storage<RiverId, River>,
     pub street_manager: Storage<StreetId, Street>,
     pub town_manager: Storage<TownId, Town>,
+    /// The building types of the setting, which are read-only.
+    pub building_types: BuildingTypeCatalog,
+    /// The genders of the setting, which are read-only.
+    pub genders: GenderSchema,
+    /// The statistics of the game system, which are read-only.
+    pub stats: StatSchema,
+    pub time: CampaignTime,
 }
 
 impl RpgData {
     pub fn load(setting: &str) -> anyhow::Result<Self> {
-        Ok(Self {
+        let data = Self {
             setting: setting.to_string(),
             building_manager: load_storage(setting, "building")?,
             characters: load_storage(setting, "character")?,
             cultures: load_storage(setting, "culture")?,
+            events: load_storage(setting, "event")?,
             mountain_manager: load_storage(setting, "mountain")?,
+            organizations: load_storage(setting, "organization")?,
             river_manager: load_storage(setting, "river")?,
             street_manager: load_storage(setting, "street")?,
             town_manager: load_storage(setting, "town")?,
-        })
+            building_types: load_schema(
+                setting,
+                "building_types.yaml",
+                BuildingTypeCatalog::check,
+            )?,
+            genders: load_schema(setting, "genders.yaml", GenderSchema::check)?,
+            stats: load_schema(setting, "stats.yaml", StatSchema::check)?,
+            time: load_schema(setting, TIME_FILE, CampaignTime::check)?,
+        };
+
+        data.check_genders()?;
+
+        Ok(data)
+    }
+
+    /// Checks, that the setting defines the gender of every [`character`](Character).
+    pub fn check_genders(&self) -> anyhow::Result<()> {
+        for character in self.characters.get_all() {
+            self.genders
+                .parse(&character.gender)
+                .context(format!("Invalid character {}", character.id().id()))?;
+        }
+
+        Ok(())
     }
 
     pub fn save(&self) -> anyhow::Result<()> {
         save_storage(&self.building_manager, &self.setting)?;
         save_storage(&self.characters, &self.setting)?;
         save_storage(&self.cultures, &self.setting)?;
+        save_storage(&self.events, &self.setting)?;
         save_storage(&self.mountain_manager, &self.setting)?;
+        save_storage(&self.organizations, &self.setting)?;
         save_storage(&self.river_manager, &self.setting)?;
         save_storage(&self.street_manager, &self.setting)?;
-        save_storage(&self.town_manager, &self.setting)
+        save_storage(&self.town_manager, &self.setting)?;
+        write(&self.time, &get_setting_path(&self.setting, TIME_FILE))
+            .context("Failed to save the time")
     }
 }
 
+/// Loads an optional & read-only schema of the setting, or returns its default.
+fn load_schema<T: Default + DeserializeOwned>(
+    setting: &str,
+    file: &str,
+    check: fn(&T) -> anyhow::Result<()>,
+) -> anyhow::Result<T> {
+    let path = get_setting_path(setting, file);
+
+    if !path.exists() {
+        return Ok(T::default());
+    }
+
+    let schema: T = read(&path)?;
+    check(&schema).context(format!("Invalid schema {:?}", path))?;
+
+    Ok(schema)
+}
+
 pub fn get_setting_path(setting: &str, file: &str) -> PathBuf {
     ["resources", "settings", setting, file].iter().collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_genders() {
+        let mut data = RpgData::default();
+        let id = data.characters.create(Character::new);
+
+        assert!(data.check_genders().is_ok());
+
+        data.characters.get_mut(id).unwrap().gender = "Unknown".to_string();
+
+        assert!(data.check_genders().is_err());
+    }
+}
@@ -0,0 +1,305 @@
+use crate::model::character::CharacterId;
+use crate::model::description::WithDescription;
+use crate::model::element::ElementRef;
+use crate::model::name::{Name, WithName};
+use crate::model::visibility::{Visibility, WithVisibility};
+use crate::model::world::building::BuildingId;
+use crate::utils::storage::{Element, Id};
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The unique identifier of an [`organization`](Organization).
+#[derive(
+    Default, Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+pub struct OrganizationId(usize);
+
+impl Id for OrganizationId {
+    fn new(id: usize) -> Self {
+        Self(id)
+    }
+
+    fn id(&self) -> usize {
+        self.0
+    }
+}
+
+/// A character, who is a member of an [`organization`](Organization).
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Member {
+    pub character: CharacterId,
+    /// The role or rank of the character inside the organization.
+    #[serde(default)]
+    pub role: String,
+}
+
+impl Member {
+    pub fn new<S: Into<String>>(character: CharacterId, role: S) -> Self {
+        Self {
+            character,
+            role: role.into(),
+        }
+    }
+}
+
+/// How an [`organization`](Organization) relates to another one.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Relation {
+    Allied,
+    Hostile,
+}
+
+impl Relation {
+    pub fn get_all() -> Vec<Self> {
+        vec![Self::Allied, Self::Hostile]
+    }
+
+    /// Parses a relation from a string.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::organization::Relation;
+    /// assert_eq!(Relation::parse("Allied").unwrap(), Relation::Allied);
+    /// assert_eq!(Relation::parse("Hostile").unwrap(), Relation::Hostile);
+    /// assert!(Relation::parse("Neutral").is_err());
+    /// ```
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "Allied" => Ok(Self::Allied),
+            "Hostile" => Ok(Self::Hostile),
+            _ => bail!("Unknown relation '{}'!", value),
+        }
+    }
+}
+
+impl fmt::Display for Relation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// An organization or faction of the setting.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Organization {
+    id: OrganizationId,
+    name: Name,
+    #[serde(default)]
+    visibility: Visibility,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<Member>,
+    #[serde(default)]
+    pub headquarters: Option<BuildingId>,
+    /// The buildings owned by the organization.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub buildings: Vec<BuildingId>,
+    #[serde(default)]
+    pub parent: Option<OrganizationId>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub relations: BTreeMap<OrganizationId, Relation>,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+impl Organization {
+    pub fn new(id: OrganizationId) -> Self {
+        Organization {
+            id,
+            name: Name::new(format!("Organization {}", id.0)).unwrap(),
+            visibility: Visibility::default(),
+            members: Vec::new(),
+            headquarters: None,
+            buildings: Vec::new(),
+            parent: None,
+            relations: BTreeMap::new(),
+            description: None,
+        }
+    }
+
+    pub fn get_member(&self, character: CharacterId) -> Option<&Member> {
+        self.members
+            .iter()
+            .find(|member| member.character == character)
+    }
+
+    /// Returns the headquarters & all owned buildings without duplicates.
+    pub fn get_all_buildings(&self) -> Vec<BuildingId> {
+        let mut buildings: Vec<BuildingId> = self.headquarters.iter().copied().collect();
+
+        for building in &self.buildings {
+            if !buildings.contains(building) {
+                buildings.push(*building);
+            }
+        }
+
+        buildings
+    }
+
+    /// Returns all elements referenced by the organization.
+    pub fn references(&self) -> Vec<ElementRef> {
+        self.members
+            .iter()
+            .map(|member| ElementRef::Character(member.character))
+            .chain(
+                self.get_all_buildings()
+                    .into_iter()
+                    .map(ElementRef::Building),
+            )
+            .chain(self.parent.map(ElementRef::Organization))
+            .chain(
+                self.relations
+                    .keys()
+                    .map(|id| ElementRef::Organization(*id)),
+            )
+            .collect()
+    }
+
+    /// Removes all references to an element, which gets deleted.
+    pub fn remove_references(&mut self, element: ElementRef) {
+        match element {
+            ElementRef::Building(id) => {
+                if self.headquarters == Some(id) {
+                    self.headquarters = None;
+                }
+                self.buildings.retain(|building| *building != id);
+            }
+            ElementRef::Character(id) => self.members.retain(|member| member.character != id),
+            ElementRef::Organization(id) => {
+                if self.parent == Some(id) {
+                    self.parent = None;
+                }
+                self.relations.remove(&id);
+            }
+            _ => {}
+        }
+    }
+
+    /// Replaces all references to an element, which changed its id.
+    pub fn replace_references(&mut self, old: ElementRef, new: ElementRef) {
+        match (old, new) {
+            (ElementRef::Building(old), ElementRef::Building(new)) => {
+                if self.headquarters == Some(old) {
+                    self.headquarters = Some(new);
+                }
+                for building in self.buildings.iter_mut().filter(|id| **id == old) {
+                    *building = new;
+                }
+            }
+            (ElementRef::Character(old), ElementRef::Character(new)) => {
+                for member in self.members.iter_mut().filter(|m| m.character == old) {
+                    member.character = new;
+                }
+            }
+            (ElementRef::Organization(old), ElementRef::Organization(new)) => {
+                if self.parent == Some(old) {
+                    self.parent = Some(new);
+                }
+                if let Some(relation) = self.relations.remove(&old) {
+                    self.relations.insert(new, relation);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Element<OrganizationId> for Organization {
+    fn id(&self) -> OrganizationId {
+        self.id
+    }
+
+    fn with_id(self, id: OrganizationId) -> Self {
+        Organization { id, ..self }
+    }
+}
+
+impl WithName for Organization {
+    fn name(&self) -> &Name {
+        &self.name
+    }
+
+    fn set_name(&mut self, name: Name) {
+        self.name = name;
+    }
+}
+
+impl WithDescription for Organization {
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn set_description(&mut self, description: Option<String>) {
+        self.description = description;
+    }
+}
+
+impl WithVisibility for Organization {
+    fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_all_buildings_without_duplicates() {
+        let mut organization = Organization::new(OrganizationId::default());
+        organization.headquarters = Some(BuildingId::new(2));
+        organization.buildings = vec![BuildingId::new(1), BuildingId::new(2)];
+
+        assert_eq!(
+            organization.get_all_buildings(),
+            vec![BuildingId::new(2), BuildingId::new(1)]
+        );
+    }
+
+    #[test]
+    fn remove_references() {
+        let mut organization = Organization::new(OrganizationId::default());
+        let building = BuildingId::new(1);
+        let other = OrganizationId::new(1);
+        organization.headquarters = Some(building);
+        organization.buildings.push(building);
+        organization
+            .members
+            .push(Member::new(CharacterId::new(3), "Boss"));
+        organization.parent = Some(other);
+        organization.relations.insert(other, Relation::Hostile);
+
+        organization.remove_references(ElementRef::Building(building));
+        organization.remove_references(ElementRef::Character(CharacterId::new(3)));
+        organization.remove_references(ElementRef::Organization(other));
+
+        assert_eq!(organization, Organization::new(OrganizationId::default()));
+    }
+
+    #[test]
+    fn replace_references() {
+        let mut organization = Organization::new(OrganizationId::default());
+        let old = OrganizationId::new(3);
+        let new = OrganizationId::new(1);
+        organization
+            .members
+            .push(Member::new(CharacterId::new(3), ""));
+        organization.parent = Some(old);
+        organization.relations.insert(old, Relation::Allied);
+
+        organization.replace_references(
+            ElementRef::Character(CharacterId::new(3)),
+            ElementRef::Character(CharacterId::new(0)),
+        );
+        organization
+            .replace_references(ElementRef::Organization(old), ElementRef::Organization(new));
+
+        assert!(organization.get_member(CharacterId::new(0)).is_some());
+        assert_eq!(organization.parent, Some(new));
+        assert_eq!(organization.relations.get(&new), Some(&Relation::Allied));
+        assert!(!organization.relations.contains_key(&old));
+    }
+}
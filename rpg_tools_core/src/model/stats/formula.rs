@@ -0,0 +1,333 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// A formula to calculate a value from other values, like *(CON + SIZ) / 10*.
+///
+/// It supports integers, variables, parentheses & the operators *+*, *-*, *\** & */*.
+/// The division rounds down.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Formula {
+    text: String,
+    expression: Expression,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Expression {
+    Number(i32),
+    Variable(String),
+    Negate(Box<Expression>),
+    Binary(Box<Expression>, Operator, Box<Expression>),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Formula {
+    /// Parses a formula.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::stats::formula::Formula;
+    /// assert!(Formula::parse("(CON + SIZ) / 10").is_ok());
+    /// assert!(Formula::parse("-2 * (STR").is_err());
+    /// assert!(Formula::parse("STR +").is_err());
+    /// assert!(Formula::parse("").is_err());
+    /// ```
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut parser = Parser {
+            chars: text.chars().peekable(),
+        };
+        let expression = parser
+            .parse_sum()
+            .context(format!("Failed to parse formula '{}'", text))?;
+
+        parser.skip_whitespace();
+
+        if let Some(c) = parser.chars.next() {
+            bail!("Unexpected '{}' in formula '{}'!", c, text);
+        }
+
+        Ok(Self {
+            text: text.trim().to_string(),
+            expression,
+        })
+    }
+
+    /// Returns the names of all variables used by the formula.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::stats::formula::Formula;
+    /// let formula = Formula::parse("(CON + SIZ) / 10 + CON").unwrap();
+    ///
+    /// assert_eq!(formula.get_variables(), vec!["CON", "SIZ"]);
+    /// ```
+    pub fn get_variables(&self) -> Vec<&str> {
+        let mut variables = Vec::new();
+        self.expression.collect_variables(&mut variables);
+        variables
+    }
+
+    /// Calculates the value of the formula with the values of the variables from *get*.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::stats::formula::Formula;
+    /// let formula = Formula::parse("(CON + SIZ) / 10 - -1").unwrap();
+    /// let get = |name: &str| match name {
+    ///     "CON" => Some(45),
+    ///     "SIZ" => Some(60),
+    ///     _ => None,
+    /// };
+    ///
+    /// assert_eq!(formula.calculate(get).unwrap(), 11);
+    /// assert!(Formula::parse("POW").unwrap().calculate(get).is_err());
+    /// assert!(Formula::parse("CON / 0").unwrap().calculate(get).is_err());
+    /// ```
+    pub fn calculate<F: Fn(&str) -> Option<i32>>(&self, get: F) -> Result<i32> {
+        self.expression.calculate(&get)
+    }
+}
+
+impl Display for Formula {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+impl TryFrom<String> for Formula {
+    type Error = anyhow::Error;
+
+    fn try_from(text: String) -> Result<Self> {
+        Self::parse(&text)
+    }
+}
+
+impl From<Formula> for String {
+    fn from(formula: Formula) -> Self {
+        formula.text
+    }
+}
+
+impl Expression {
+    fn collect_variables<'a>(&'a self, variables: &mut Vec<&'a str>) {
+        match self {
+            Expression::Number(_) => {}
+            Expression::Variable(name) => {
+                if !variables.contains(&name.as_str()) {
+                    variables.push(name);
+                }
+            }
+            Expression::Negate(expression) => expression.collect_variables(variables),
+            Expression::Binary(left, _, right) => {
+                left.collect_variables(variables);
+                right.collect_variables(variables);
+            }
+        }
+    }
+
+    fn calculate<F: Fn(&str) -> Option<i32>>(&self, get: &F) -> Result<i32> {
+        Ok(match self {
+            Expression::Number(number) => *number,
+            Expression::Variable(name) => {
+                get(name).context(format!("Unknown value '{}'!", name))?
+            }
+            Expression::Negate(expression) => expression
+                .calculate(get)?
+                .checked_neg()
+                .context("Overflow!")?,
+            Expression::Binary(left, operator, right) => {
+                let left = left.calculate(get)?;
+                let right = right.calculate(get)?;
+
+                match operator {
+                    Operator::Add => left.checked_add(right),
+                    Operator::Subtract => left.checked_sub(right),
+                    Operator::Multiply => left.checked_mul(right),
+                    Operator::Divide => {
+                        if right == 0 {
+                            bail!("Division by zero!");
+                        }
+                        div_floor(left, right)
+                    }
+                }
+                .context("Overflow!")?
+            }
+        })
+    }
+}
+
+/// Divides & rounds toward negative infinity.
+///
+/// ```
+///# use rpg_tools_core::model::stats::formula::div_floor;
+/// assert_eq!(div_floor(7, 2), Some(3));
+/// assert_eq!(div_floor(-7, 2), Some(-4));
+/// assert_eq!(div_floor(7, -2), Some(-4));
+/// assert_eq!(div_floor(-7, -2), Some(3));
+/// assert_eq!(div_floor(i32::MIN, -1), None);
+/// ```
+pub fn div_floor(left: i32, right: i32) -> Option<i32> {
+    let quotient = left.checked_div(right)?;
+
+    if left % right != 0 && (left < 0) != (right < 0) {
+        Some(quotient - 1)
+    } else {
+        Some(quotient)
+    }
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+
+    fn next_operator(&mut self, operators: &[(char, Operator)]) -> Option<Operator> {
+        self.skip_whitespace();
+
+        let c = *self.chars.peek()?;
+        let operator = operators
+            .iter()
+            .find(|(symbol, _)| *symbol == c)
+            .map(|(_, operator)| *operator)?;
+        self.chars.next();
+
+        Some(operator)
+    }
+
+    fn parse_sum(&mut self) -> Result<Expression> {
+        let mut expression = self.parse_product()?;
+
+        while let Some(operator) =
+            self.next_operator(&[('+', Operator::Add), ('-', Operator::Subtract)])
+        {
+            let right = self.parse_product()?;
+            expression = Expression::Binary(Box::new(expression), operator, Box::new(right));
+        }
+
+        Ok(expression)
+    }
+
+    fn parse_product(&mut self) -> Result<Expression> {
+        let mut expression = self.parse_factor()?;
+
+        while let Some(operator) =
+            self.next_operator(&[('*', Operator::Multiply), ('/', Operator::Divide)])
+        {
+            let right = self.parse_factor()?;
+            expression = Expression::Binary(Box::new(expression), operator, Box::new(right));
+        }
+
+        Ok(expression)
+    }
+
+    fn parse_factor(&mut self) -> Result<Expression> {
+        self.skip_whitespace();
+
+        match self.chars.next() {
+            Some('-') => Ok(Expression::Negate(Box::new(self.parse_factor()?))),
+            Some('(') => {
+                let expression = self.parse_sum()?;
+                self.skip_whitespace();
+
+                match self.chars.next() {
+                    Some(')') => Ok(expression),
+                    _ => bail!("Missing ')'!"),
+                }
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let mut number = c.to_string();
+
+                while let Some(c) = self.chars.next_if(|c| c.is_ascii_digit()) {
+                    number.push(c);
+                }
+
+                Ok(Expression::Number(number.parse()?))
+            }
+            Some(c) if is_variable_char(c) => {
+                let mut name = c.to_string();
+
+                while let Some(c) = self
+                    .chars
+                    .next_if(|&c| is_variable_char(c) || c.is_ascii_digit())
+                {
+                    name.push(c);
+                }
+
+                Ok(Expression::Variable(name))
+            }
+            Some(c) => bail!("Unexpected '{}'!", c),
+            None => bail!("Unexpected end!"),
+        }
+    }
+}
+
+/// Can the character be part of a variable name?
+pub fn is_variable_char(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calculate(text: &str) -> i32 {
+        Formula::parse(text).unwrap().calculate(|_| None).unwrap()
+    }
+
+    #[test]
+    fn operator_precedence() {
+        assert_eq!(calculate("2 + 3 * 4"), 14);
+        assert_eq!(calculate("(2 + 3) * 4"), 20);
+        assert_eq!(calculate("10 - 4 - 3"), 3);
+        assert_eq!(calculate("100 / 10 / 5"), 2);
+    }
+
+    #[test]
+    fn division_rounds_down() {
+        assert_eq!(calculate("7 / 2"), 3);
+        assert_eq!(calculate("-7 / 2"), -4);
+        assert_eq!(calculate("7 / -2"), -4);
+        assert_eq!(calculate("-7 / -2"), 3);
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let get = |_: &str| Some(i32::MIN);
+
+        assert!(Formula::parse("2147483647 + 1")
+            .unwrap()
+            .calculate(get)
+            .is_err());
+        assert!(Formula::parse("0 - 2 - 2147483647")
+            .unwrap()
+            .calculate(get)
+            .is_err());
+        assert!(Formula::parse("65536 * 65536")
+            .unwrap()
+            .calculate(get)
+            .is_err());
+        assert!(Formula::parse("-X").unwrap().calculate(get).is_err());
+        assert!(Formula::parse("X / -1").unwrap().calculate(get).is_err());
+    }
+
+    #[test]
+    fn serde_uses_the_text() {
+        let formula = Formula::parse(" POW / 5 ").unwrap();
+        let yaml = serde_yaml::to_string(&formula).unwrap();
+
+        assert_eq!(yaml, "POW / 5\n");
+        assert_eq!(serde_yaml::from_str::<Formula>(&yaml).unwrap(), formula);
+        assert!(serde_yaml::from_str::<Formula>("POW /").is_err());
+    }
+}
@@ -0,0 +1,361 @@
+use crate::model::stats::formula::{is_variable_char, Formula};
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+pub mod formula;
+
+/// Defines the statistics of characters for the game system of a setting.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StatSchema {
+    /// The values, which are set directly.
+    #[serde(default)]
+    pub attributes: Vec<AttributeDefinition>,
+    /// The values, which are calculated from the attributes & previous derived values.
+    #[serde(default)]
+    pub derived: Vec<DerivedDefinition>,
+    #[serde(default)]
+    pub skills: Vec<SkillDefinition>,
+}
+
+/// An attribute like *STR*.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AttributeDefinition {
+    pub name: String,
+    pub min: i32,
+    pub max: i32,
+    pub default: i32,
+}
+
+/// A value like *HP*, which is calculated from other values.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DerivedDefinition {
+    pub name: String,
+    pub formula: Formula,
+}
+
+/// A skill, whose base value is calculated from the attributes & derived values.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SkillDefinition {
+    pub name: String,
+    pub base: Formula,
+    #[serde(default)]
+    pub max: Option<i32>,
+}
+
+/// The statistics of a [`character`](crate::model::character::Character).
+///
+/// Missing attributes use their default & missing skills their base value.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StatBlock {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub attributes: BTreeMap<String, i32>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub skills: BTreeMap<String, i32>,
+}
+
+impl StatBlock {
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty() && self.skills.is_empty()
+    }
+}
+
+/// The calculated statistics of a [`character`](crate::model::character::Character)
+/// in the order of the [`schema`](StatSchema).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StatSheet {
+    pub attributes: Vec<(String, i32)>,
+    pub derived: Vec<(String, i32)>,
+    pub skills: Vec<(String, i32)>,
+}
+
+impl StatSchema {
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty() && self.derived.is_empty() && self.skills.is_empty()
+    }
+
+    /// Checks, that all names are unique & valid and that formulas only use attributes
+    /// & previous derived values.
+    pub fn check(&self) -> Result<()> {
+        let mut names: Vec<&str> = Vec::new();
+
+        for attribute in &self.attributes {
+            check_name(&names, &attribute.name)?;
+
+            if attribute.min > attribute.max
+                || attribute.default < attribute.min
+                || attribute.default > attribute.max
+            {
+                bail!("Attribute '{}' has an invalid range!", attribute.name);
+            }
+
+            names.push(&attribute.name);
+        }
+
+        for derived in &self.derived {
+            check_name(&names, &derived.name)?;
+            check_formula(&names, &derived.name, &derived.formula)?;
+            names.push(&derived.name);
+        }
+
+        let mut skills: Vec<&str> = Vec::new();
+
+        for skill in &self.skills {
+            if skills.contains(&skill.name.as_str()) {
+                bail!("Skill '{}' is defined twice!", skill.name);
+            }
+
+            check_formula(&names, &skill.name, &skill.base)?;
+            skills.push(&skill.name);
+        }
+
+        Ok(())
+    }
+
+    /// Checks, that the [`stat block`](StatBlock) only contains known attributes & skills
+    /// with valid values.
+    pub fn validate(&self, block: &StatBlock) -> Result<()> {
+        for (name, &value) in &block.attributes {
+            let attribute = self
+                .get_attribute(name)
+                .context(format!("Unknown attribute '{}'!", name))?;
+
+            if value < attribute.min || value > attribute.max {
+                bail!(
+                    "Attribute '{}' must be between {} & {}!",
+                    name,
+                    attribute.min,
+                    attribute.max
+                );
+            }
+        }
+
+        for (name, &value) in &block.skills {
+            let skill = self
+                .get_skill(name)
+                .context(format!("Unknown skill '{}'!", name))?;
+
+            if value < 0 || skill.max.map(|max| value > max).unwrap_or(false) {
+                bail!("Skill '{}' has the invalid value {}!", name, value);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get_attribute(&self, name: &str) -> Option<&AttributeDefinition> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name.eq(name))
+    }
+
+    pub fn get_skill(&self, name: &str) -> Option<&SkillDefinition> {
+        self.skills.iter().find(|skill| skill.name.eq(name))
+    }
+
+    /// Calculates all the values of a [`stat block`](StatBlock).
+    pub fn calculate(&self, block: &StatBlock) -> Result<StatSheet> {
+        let mut sheet = StatSheet::default();
+
+        for attribute in &self.attributes {
+            let value = block
+                .attributes
+                .get(&attribute.name)
+                .copied()
+                .unwrap_or(attribute.default);
+            sheet.attributes.push((attribute.name.clone(), value));
+        }
+
+        for derived in &self.derived {
+            let value = derived.formula.calculate(|name| sheet.get(name))?;
+            sheet.derived.push((derived.name.clone(), value));
+        }
+
+        for skill in &self.skills {
+            let value = match block.skills.get(&skill.name) {
+                Some(value) => *value,
+                None => skill.base.calculate(|name| sheet.get(name))?,
+            };
+            sheet.skills.push((skill.name.clone(), value));
+        }
+
+        Ok(sheet)
+    }
+
+    /// Calculates the base value of a skill.
+    pub fn calculate_base(&self, block: &StatBlock, skill: &str) -> Result<i32> {
+        let sheet = self.calculate(&StatBlock {
+            attributes: block.attributes.clone(),
+            skills: BTreeMap::new(),
+        })?;
+
+        sheet
+            .skills
+            .into_iter()
+            .find(|(name, _)| name.eq(skill))
+            .map(|(_, value)| value)
+            .context(format!("Unknown skill '{}'!", skill))
+    }
+}
+
+impl StatSheet {
+    /// Returns the value of an attribute or derived value.
+    pub fn get(&self, name: &str) -> Option<i32> {
+        self.attributes
+            .iter()
+            .chain(self.derived.iter())
+            .find(|(n, _)| n.eq(name))
+            .map(|(_, value)| *value)
+    }
+}
+
+fn check_name(names: &[&str], name: &str) -> Result<()> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| is_variable_char(c) || c.is_ascii_digit())
+        || name.starts_with(|c: char| c.is_ascii_digit())
+    {
+        bail!("'{}' is not a valid name for a value!", name);
+    } else if names.contains(&name) {
+        bail!("'{}' is defined twice!", name);
+    }
+
+    Ok(())
+}
+
+fn check_formula(names: &[&str], name: &str, formula: &Formula) -> Result<()> {
+    for variable in formula.get_variables() {
+        if !names.contains(&variable) {
+            bail!("The formula of '{}' uses unknown '{}'!", name, variable);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attribute(name: &str) -> AttributeDefinition {
+        AttributeDefinition {
+            name: name.to_string(),
+            min: 15,
+            max: 90,
+            default: 50,
+        }
+    }
+
+    fn derived(name: &str, formula: &str) -> DerivedDefinition {
+        DerivedDefinition {
+            name: name.to_string(),
+            formula: Formula::parse(formula).unwrap(),
+        }
+    }
+
+    fn skill(name: &str, base: &str) -> SkillDefinition {
+        SkillDefinition {
+            name: name.to_string(),
+            base: Formula::parse(base).unwrap(),
+            max: Some(99),
+        }
+    }
+
+    fn init() -> StatSchema {
+        StatSchema {
+            attributes: vec![attribute("CON"), attribute("SIZ"), attribute("DEX")],
+            derived: vec![derived("HP", "(CON + SIZ) / 10"), derived("HP2", "HP * 2")],
+            skills: vec![skill("Dodge", "DEX / 2"), skill("Spot Hidden", "25")],
+        }
+    }
+
+    fn block(attributes: &[(&str, i32)], skills: &[(&str, i32)]) -> StatBlock {
+        StatBlock {
+            attributes: attributes
+                .iter()
+                .map(|(name, value)| (name.to_string(), *value))
+                .collect(),
+            skills: skills
+                .iter()
+                .map(|(name, value)| (name.to_string(), *value))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn check_valid_schema() {
+        assert!(init().check().is_ok());
+        assert!(StatSchema::default().check().is_ok());
+    }
+
+    #[test]
+    fn check_invalid_schemas() {
+        let mut schema = init();
+        schema.attributes.push(attribute("CON"));
+        assert!(schema.check().is_err());
+
+        let mut schema = init();
+        schema.attributes.push(attribute("Credit Rating"));
+        assert!(schema.check().is_err());
+
+        let mut schema = init();
+        schema.derived.insert(0, derived("HP0", "HP"));
+        assert!(schema.check().is_err());
+
+        let mut schema = init();
+        schema.skills.push(skill("Dodge", "10"));
+        assert!(schema.check().is_err());
+
+        let mut schema = init();
+        schema.attributes[0].default = 10;
+        assert!(schema.check().is_err());
+    }
+
+    #[test]
+    fn validate_block() {
+        let schema = init();
+
+        assert!(schema
+            .validate(&block(&[("CON", 60)], &[("Dodge", 40)]))
+            .is_ok());
+        assert!(schema.validate(&block(&[("POW", 60)], &[])).is_err());
+        assert!(schema.validate(&block(&[("CON", 91)], &[])).is_err());
+        assert!(schema.validate(&block(&[], &[("Drive", 40)])).is_err());
+        assert!(schema.validate(&block(&[], &[("Dodge", 100)])).is_err());
+    }
+
+    #[test]
+    fn calculate_sheet() {
+        let schema = init();
+        let sheet = schema
+            .calculate(&block(&[("CON", 60), ("DEX", 41)], &[("Spot Hidden", 70)]))
+            .unwrap();
+
+        assert_eq!(
+            sheet.attributes,
+            vec![
+                ("CON".to_string(), 60),
+                ("SIZ".to_string(), 50),
+                ("DEX".to_string(), 41)
+            ]
+        );
+        assert_eq!(
+            sheet.derived,
+            vec![("HP".to_string(), 11), ("HP2".to_string(), 22)]
+        );
+        assert_eq!(
+            sheet.skills,
+            vec![("Dodge".to_string(), 20), ("Spot Hidden".to_string(), 70)]
+        );
+    }
+
+    #[test]
+    fn calculate_base() {
+        let schema = init();
+        let block = block(&[("DEX", 60)], &[("Dodge", 70)]);
+
+        assert_eq!(schema.calculate_base(&block, "Dodge").unwrap(), 30);
+        assert!(schema.calculate_base(&block, "Drive").is_err());
+    }
+}
@@ -0,0 +1,349 @@
+use crate::model::time::date::Date;
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+const GREGORIAN_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const GREGORIAN_DAYS: [u32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+const GREGORIAN_WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+/// The 1st January of the year 0 was a Saturday.
+const GREGORIAN_FIRST_WEEKDAY: i64 = 5;
+
+/// A month of a [`custom calendar`](Calendar::Custom).
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MonthDefinition {
+    pub name: String,
+    pub days: u32,
+}
+
+impl MonthDefinition {
+    pub fn new(name: &str, days: u32) -> Self {
+        Self {
+            name: name.to_string(),
+            days,
+        }
+    }
+}
+
+/// Defines the months & weekdays of a setting.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Calendar {
+    /// The calendar of the real world with leap years.
+    #[default]
+    Gregorian,
+    /// A calendar without leap years, e.g. for a fantasy setting.
+    Custom {
+        months: Vec<MonthDefinition>,
+        #[serde(default)]
+        weekdays: Vec<String>,
+    },
+}
+
+impl Calendar {
+    /// Returns all the possible types.
+    pub fn get_all() -> [&'static str; 2] {
+        ["Gregorian", "Custom"]
+    }
+
+    /// Checks, that the months & weekdays have unique & non-empty names
+    /// & that each month has days.
+    pub fn check(&self) -> Result<()> {
+        if let Calendar::Custom { months, weekdays } = self {
+            if months.is_empty() {
+                bail!("The calendar needs at least one month!");
+            }
+
+            check_names(months.iter().map(|month| month.name.as_str()), "month")?;
+            check_names(weekdays.iter().map(|weekday| weekday.as_str()), "weekday")?;
+
+            if let Some(month) = months.iter().find(|month| month.days == 0) {
+                bail!("The month '{}' has no days!", month.name);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of months per year.
+    pub fn get_months(&self) -> u32 {
+        match self {
+            Calendar::Gregorian => GREGORIAN_MONTHS.len() as u32,
+            Calendar::Custom { months, .. } => months.len() as u32,
+        }
+    }
+
+    /// Returns the name of a month starting at 1.
+    pub fn get_month_name(&self, month: u32) -> Option<&str> {
+        let index = month.checked_sub(1)? as usize;
+
+        match self {
+            Calendar::Gregorian => GREGORIAN_MONTHS.get(index).copied(),
+            Calendar::Custom { months, .. } => months.get(index).map(|m| m.name.as_str()),
+        }
+    }
+
+    /// Returns the number of days of a month starting at 1.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::time::calendar::Calendar;
+    /// let calendar = Calendar::Gregorian;
+    ///
+    /// assert_eq!(calendar.get_days(1925, 2), Some(28));
+    /// assert_eq!(calendar.get_days(1924, 2), Some(29));
+    /// assert_eq!(calendar.get_days(1900, 2), Some(28));
+    /// assert_eq!(calendar.get_days(2000, 2), Some(29));
+    /// assert_eq!(calendar.get_days(1925, 13), None);
+    /// ```
+    pub fn get_days(&self, year: i32, month: u32) -> Option<u32> {
+        let index = month.checked_sub(1)? as usize;
+
+        match self {
+            Calendar::Gregorian => GREGORIAN_DAYS.get(index).map(|&days| {
+                if month == 2 && is_leap_year(year) {
+                    29
+                } else {
+                    days
+                }
+            }),
+            Calendar::Custom { months, .. } => months.get(index).map(|month| month.days),
+        }
+    }
+
+    /// Checks, that the date exists in this calendar.
+    pub fn validate(&self, date: &Date) -> Result<()> {
+        let days = self
+            .get_days(date.year(), date.month())
+            .context(format!("The date {} has an unknown month!", date))?;
+
+        if date.day() > days {
+            bail!("The date {} has an invalid day!", date);
+        }
+
+        Ok(())
+    }
+
+    /// Parses a date in the format *year-month-day* & checks, that it exists in this calendar.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::time::calendar::Calendar;
+    ///# use rpg_tools_core::model::time::date::Date;
+    /// let calendar = Calendar::Gregorian;
+    ///
+    /// assert_eq!(calendar.parse_date("1924-02-29").unwrap(), Date::new(1924, 2, 29));
+    /// assert!(calendar.parse_date("1925-02-29").is_err());
+    /// assert!(calendar.parse_date("1925-13-01").is_err());
+    /// ```
+    pub fn parse_date(&self, text: &str) -> Result<Date> {
+        let date = Date::parse(text)?;
+        self.validate(&date)?;
+        Ok(date)
+    }
+
+    /// Parses an optional date, where an empty text is no date.
+    pub fn parse_optional_date(&self, text: &str) -> Result<Option<Date>> {
+        let date = Date::parse_optional(text)?;
+
+        if let Some(date) = &date {
+            self.validate(date)?;
+        }
+
+        Ok(date)
+    }
+
+    /// Returns the number of days since the 1st day of the year 0.
+    pub fn get_day_number(&self, date: &Date) -> i64 {
+        let year = date.year() as i64;
+        let days_of_previous_months: i64 = (1..date.month())
+            .map(|month| self.get_days(date.year(), month).unwrap_or_default() as i64)
+            .sum();
+        let days_of_previous_years = match self {
+            Calendar::Gregorian => {
+                let previous = year - 1;
+                365 * year + previous.div_euclid(4) - previous.div_euclid(100)
+                    + previous.div_euclid(400)
+                    + 1
+            }
+            Calendar::Custom { months, .. } => {
+                year * months.iter().map(|month| month.days as i64).sum::<i64>()
+            }
+        };
+
+        days_of_previous_years + days_of_previous_months + date.day() as i64 - 1
+    }
+
+    /// Returns the name of the weekday of a date, if the calendar has weekdays.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::time::calendar::Calendar;
+    ///# use rpg_tools_core::model::time::date::Date;
+    /// let calendar = Calendar::Gregorian;
+    ///
+    /// assert_eq!(calendar.get_weekday(&Date::new(1925, 3, 14)), Some("Saturday"));
+    /// assert_eq!(calendar.get_weekday(&Date::new(2000, 1, 1)), Some("Saturday"));
+    /// assert_eq!(calendar.get_weekday(&Date::new(1970, 1, 1)), Some("Thursday"));
+    /// ```
+    pub fn get_weekday(&self, date: &Date) -> Option<&str> {
+        let day = self.get_day_number(date);
+
+        match self {
+            Calendar::Gregorian => {
+                let index = (day + GREGORIAN_FIRST_WEEKDAY).rem_euclid(7) as usize;
+                Some(GREGORIAN_WEEKDAYS[index])
+            }
+            Calendar::Custom { weekdays, .. } => {
+                if weekdays.is_empty() {
+                    return None;
+                }
+
+                let index = day.rem_euclid(weekdays.len() as i64) as usize;
+                Some(weekdays[index].as_str())
+            }
+        }
+    }
+
+    /// Formats a date with the name of its month, e.g. *14 March 1925*.
+    pub fn format(&self, date: &Date) -> String {
+        match self.get_month_name(date.month()) {
+            Some(month) => format!("{} {} {}", date.day(), month, date.year()),
+            None => date.to_string(),
+        }
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    year.rem_euclid(4) == 0 && (year.rem_euclid(100) != 0 || year.rem_euclid(400) == 0)
+}
+
+fn check_names<'a, I: Iterator<Item = &'a str>>(names: I, kind: &str) -> Result<()> {
+    let mut unique = HashSet::new();
+
+    for name in names {
+        if name.trim().is_empty() {
+            bail!("The name of a {} is empty!", kind);
+        } else if !unique.insert(name) {
+            bail!("The {} '{}' is defined twice!", kind, name);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_custom() -> Calendar {
+        Calendar::Custom {
+            months: vec![
+                MonthDefinition::new("Frost", 30),
+                MonthDefinition::new("Thaw", 20),
+            ],
+            weekdays: vec!["Moonday".to_string(), "Sunday".to_string()],
+        }
+    }
+
+    #[test]
+    fn check() {
+        assert!(Calendar::Gregorian.check().is_ok());
+        assert!(create_custom().check().is_ok());
+    }
+
+    #[test]
+    fn check_invalid_custom() {
+        let empty = Calendar::Custom {
+            months: vec![],
+            weekdays: vec![],
+        };
+        let no_days = Calendar::Custom {
+            months: vec![MonthDefinition::new("Frost", 0)],
+            weekdays: vec![],
+        };
+        let duplicate = Calendar::Custom {
+            months: vec![
+                MonthDefinition::new("Frost", 30),
+                MonthDefinition::new("Frost", 30),
+            ],
+            weekdays: vec![],
+        };
+        let empty_weekday = Calendar::Custom {
+            months: vec![MonthDefinition::new("Frost", 30)],
+            weekdays: vec![" ".to_string()],
+        };
+
+        assert!(empty.check().is_err());
+        assert!(no_days.check().is_err());
+        assert!(duplicate.check().is_err());
+        assert!(empty_weekday.check().is_err());
+    }
+
+    #[test]
+    fn validate_custom() {
+        let calendar = create_custom();
+
+        assert!(calendar.validate(&Date::new(5, 1, 30)).is_ok());
+        assert!(calendar.validate(&Date::new(5, 2, 20)).is_ok());
+        assert!(calendar.validate(&Date::new(5, 2, 21)).is_err());
+        assert!(calendar.validate(&Date::new(5, 3, 1)).is_err());
+    }
+
+    #[test]
+    fn gregorian_day_numbers() {
+        let calendar = Calendar::Gregorian;
+
+        assert_eq!(calendar.get_day_number(&Date::new(0, 1, 1)), 0);
+        assert_eq!(calendar.get_day_number(&Date::new(1, 1, 1)), 366);
+        assert_eq!(calendar.get_day_number(&Date::new(-1, 12, 31)), -1);
+        assert_eq!(
+            calendar.get_day_number(&Date::new(1925, 3, 1))
+                - calendar.get_day_number(&Date::new(1925, 2, 28)),
+            1
+        );
+        assert_eq!(
+            calendar.get_day_number(&Date::new(1924, 3, 1))
+                - calendar.get_day_number(&Date::new(1924, 2, 28)),
+            2
+        );
+    }
+
+    #[test]
+    fn custom_weekdays() {
+        let calendar = create_custom();
+
+        assert_eq!(calendar.get_day_number(&Date::new(1, 2, 1)), 80);
+        assert_eq!(calendar.get_weekday(&Date::new(0, 1, 1)), Some("Moonday"));
+        assert_eq!(calendar.get_weekday(&Date::new(0, 1, 2)), Some("Sunday"));
+        assert_eq!(calendar.get_weekday(&Date::new(-1, 2, 20)), Some("Sunday"));
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(
+            Calendar::Gregorian.format(&Date::new(1925, 3, 14)),
+            "14 March 1925"
+        );
+        assert_eq!(create_custom().format(&Date::new(5, 2, 3)), "3 Thaw 5");
+        assert_eq!(create_custom().format(&Date::new(5, 3, 3)), "5-03-03");
+    }
+}
@@ -0,0 +1,160 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// A date of a [`calendar`](crate::model::time::calendar::Calendar).
+///
+/// The month & the day start at 1 & the dates are ordered chronologically.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Parses a date in the format *year-month-day*, which doesn't check the calendar.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::time::date::Date;
+    /// assert_eq!(Date::parse("1925-03-14").unwrap(), Date::new(1925, 3, 14));
+    /// assert_eq!(Date::parse("-44-3-15").unwrap(), Date::new(-44, 3, 15));
+    /// assert!(Date::parse("1925-03").is_err());
+    /// assert!(Date::parse("1925-0-14").is_err());
+    /// assert!(Date::parse("1925-March-14").is_err());
+    /// ```
+    pub fn parse(text: &str) -> Result<Self> {
+        let text = text.trim();
+        let (sign, rest) = match text.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, text),
+        };
+        let parts: Vec<&str> = rest.split('-').collect();
+
+        if parts.len() != 3 {
+            bail!("The date '{}' is not in the format year-month-day!", text);
+        }
+
+        let year: i32 = parts[0]
+            .parse()
+            .context(format!("Invalid year in date '{}'", text))?;
+        let month: u32 = parts[1]
+            .parse()
+            .context(format!("Invalid month in date '{}'", text))?;
+        let day: u32 = parts[2]
+            .parse()
+            .context(format!("Invalid day in date '{}'", text))?;
+
+        if month == 0 || day == 0 {
+            bail!("The month & day of date '{}' start at 1!", text);
+        }
+
+        Ok(Self::new(sign * year, month, day))
+    }
+
+    /// Parses an optional date, where an empty text is no date.
+    pub fn parse_optional(text: &str) -> Result<Option<Self>> {
+        if text.trim().is_empty() {
+            Ok(None)
+        } else {
+            Self::parse(text).map(Some)
+        }
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns the number of full years from this date until another one,
+    /// or nothing, if the other one is earlier.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::time::date::Date;
+    /// let birth = Date::new(1900, 5, 10);
+    ///
+    /// assert_eq!(birth.get_years_until(&Date::new(1925, 5, 9)), Some(24));
+    /// assert_eq!(birth.get_years_until(&Date::new(1925, 5, 10)), Some(25));
+    /// assert_eq!(birth.get_years_until(&Date::new(1900, 5, 10)), Some(0));
+    /// assert_eq!(birth.get_years_until(&Date::new(1899, 12, 31)), None);
+    /// ```
+    pub fn get_years_until(&self, end: &Date) -> Option<u32> {
+        if end < self {
+            return None;
+        }
+
+        let years = end.year - self.year;
+
+        if (end.month, end.day) < (self.month, self.day) {
+            Some((years - 1) as u32)
+        } else {
+            Some(years as u32)
+        }
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl TryFrom<String> for Date {
+    type Error = anyhow::Error;
+
+    fn try_from(text: String) -> Result<Self> {
+        Self::parse(&text)
+    }
+}
+
+impl From<Date> for String {
+    fn from(date: Date) -> Self {
+        date.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_and_parse() {
+        for date in [
+            Date::new(1925, 3, 14),
+            Date::new(-44, 3, 15),
+            Date::new(0, 12, 1),
+        ] {
+            assert_eq!(Date::parse(&date.to_string()).unwrap(), date);
+        }
+    }
+
+    #[test]
+    fn parse_optional() {
+        assert_eq!(Date::parse_optional(" ").unwrap(), None);
+        assert_eq!(
+            Date::parse_optional("1925-3-14").unwrap(),
+            Some(Date::new(1925, 3, 14))
+        );
+        assert!(Date::parse_optional("invalid").is_err());
+    }
+
+    #[test]
+    fn chronological_order() {
+        assert!(Date::new(1925, 3, 14) < Date::new(1925, 3, 15));
+        assert!(Date::new(1925, 3, 14) < Date::new(1925, 4, 1));
+        assert!(Date::new(1924, 12, 31) < Date::new(1925, 1, 1));
+        assert!(Date::new(-1, 12, 31) < Date::new(0, 1, 1));
+    }
+}
@@ -0,0 +1,31 @@
+use crate::model::time::calendar::Calendar;
+use crate::model::time::date::Date;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+pub mod calendar;
+pub mod date;
+
+/// The calendar of the setting & the current date of the campaign.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CampaignTime {
+    #[serde(default)]
+    pub calendar: Calendar,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub now: Option<Date>,
+}
+
+impl CampaignTime {
+    /// Checks the calendar & that the current date exists in it.
+    pub fn check(&self) -> Result<()> {
+        self.calendar.check()?;
+
+        if let Some(now) = &self.now {
+            self.calendar
+                .validate(now)
+                .context("Invalid current date")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Who can see an element of the setting?
+#[derive(Default, Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Visibility {
+    /// Everybody knows about it.
+    #[default]
+    Public,
+    /// Only the game master knows about it.
+    GmOnly,
+    /// The players don't know about it until it is revealed.
+    Hidden,
+}
+
+/// Who is looking at the setting?
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Audience {
+    GameMaster,
+    Players,
+}
+
+impl Visibility {
+    /// Returns all the possible values.
+    pub fn get_all() -> [&'static str; 3] {
+        ["Public", "GmOnly", "Hidden"]
+    }
+
+    /// Parses a visibility & fails for unknown strings.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::visibility::Visibility;
+    /// assert_eq!(Visibility::parse("GmOnly").unwrap(), Visibility::GmOnly);
+    /// assert!(Visibility::parse("Unknown").is_err());
+    /// ```
+    pub fn parse(string: &str) -> Result<Self> {
+        match string {
+            "Public" => Ok(Self::Public),
+            "GmOnly" => Ok(Self::GmOnly),
+            "Hidden" => Ok(Self::Hidden),
+            _ => bail!("Unknown visibility '{}'!", string),
+        }
+    }
+
+    pub fn is_public(&self) -> bool {
+        *self == Self::Public
+    }
+
+    /// Can the [`audience`](Audience) see it?
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::visibility::{Audience, Visibility};
+    /// assert!(Visibility::Hidden.is_visible_to(Audience::GameMaster));
+    /// assert!(!Visibility::Hidden.is_visible_to(Audience::Players));
+    /// assert!(Visibility::Public.is_visible_to(Audience::Players));
+    /// ```
+    pub fn is_visible_to(&self, audience: Audience) -> bool {
+        match audience {
+            Audience::GameMaster => true,
+            Audience::Players => self.is_public(),
+        }
+    }
+}
+
+impl Display for Visibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// A trait for elements, which can be hidden from the players.
+pub trait WithVisibility {
+    fn visibility(&self) -> Visibility;
+    fn set_visibility(&mut self, visibility: Visibility);
+
+    fn is_visible_to(&self, audience: Audience) -> bool {
+        self.visibility().is_visible_to(audience)
+    }
+}
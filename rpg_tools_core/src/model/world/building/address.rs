@@ -0,0 +1,16 @@
+use crate::model::world::street::StreetId;
+use serde::{Deserialize, Serialize};
+
+/// The address of a [`building`](crate::model::world::building::Building).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Address {
+    pub street: StreetId,
+    /// The house number.
+    pub number: u32,
+}
+
+impl Address {
+    pub fn new(street: StreetId, number: u32) -> Self {
+        Self { street, number }
+    }
+}
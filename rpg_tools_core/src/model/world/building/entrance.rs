@@ -0,0 +1,58 @@
+use crate::model::math::side2d::Side2d;
+use crate::model::math::size2d::Size2d;
+use serde::{Deserialize, Serialize};
+
+/// An entrance of a [`building`](crate::model::world::building::Building)
+/// on a side of a tile of its [`lot`](crate::model::world::building::lot::BuildingLot).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Entrance {
+    /// The x position of the tile relative to the bounding box of the lot.
+    pub x: i32,
+    /// The y position of the tile relative to the bounding box of the lot.
+    pub y: i32,
+    pub side: Side2d,
+}
+
+impl Entrance {
+    pub fn new(x: i32, y: i32, side: Side2d) -> Self {
+        Self { x, y, side }
+    }
+
+    /// Returns the position of the tile in front of the entrance relative to the bounding box of the lot.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::math::side2d::Side2d;
+    ///# use rpg_tools_core::model::world::building::entrance::Entrance;
+    /// assert_eq!(Entrance::new(0, 0, Side2d::Top).get_front(), (0, -1));
+    /// assert_eq!(Entrance::new(2, 1, Side2d::Right).get_front(), (3, 1));
+    /// ```
+    pub fn get_front(&self) -> (i32, i32) {
+        let (x, y) = self.side.get_offset();
+        (self.x + x, self.y + y)
+    }
+
+    /// Moves the entrance by the offset, if the bounding box of the lot changes.
+    pub fn shift(&mut self, offset_x: i32, offset_y: i32) {
+        self.x += offset_x;
+        self.y += offset_y;
+    }
+
+    /// Rotates the entrance clockwise by 90° like a lot of the given size.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::math::side2d::Side2d;
+    ///# use rpg_tools_core::model::math::size2d::Size2d;
+    ///# use rpg_tools_core::model::world::building::entrance::Entrance;
+    /// let mut entrance = Entrance::new(2, 0, Side2d::Right);
+    /// entrance.rotate(Size2d::new(3, 1));
+    ///
+    /// assert_eq!(entrance, Entrance::new(0, 2, Side2d::Bottom));
+    /// ```
+    pub fn rotate(&mut self, lot_size: Size2d) {
+        *self = Self {
+            x: lot_size.height() - 1 - self.y,
+            y: self.x,
+            side: self.side.rotate_clockwise(),
+        };
+    }
+}
@@ -0,0 +1,190 @@
+use crate::model::color::Color;
+use crate::model::math::size2d::Size2d;
+use crate::utils::map::tile::TileMap;
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How many interior tiles cover a tile of the [`lot`](crate::model::world::building::lot::BuildingLot)
+/// in each direction.
+pub const INTERIOR_RESOLUTION: u32 = 4;
+
+/// A tile of the floor plan of a [`building`](crate::model::world::building::Building).
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum InteriorTile {
+    Door,
+    #[default]
+    Floor,
+    Stairs,
+    Wall,
+    Window,
+}
+
+impl InteriorTile {
+    pub fn get_all() -> Vec<Self> {
+        vec![
+            Self::Door,
+            Self::Floor,
+            Self::Stairs,
+            Self::Wall,
+            Self::Window,
+        ]
+    }
+
+    /// Parses an interior tile from a string.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::world::building::interior::InteriorTile;
+    /// for tile in InteriorTile::get_all() {
+    ///     assert_eq!(InteriorTile::parse(&tile.to_string()).unwrap(), tile);
+    /// }
+    /// assert!(InteriorTile::parse("Roof").is_err());
+    /// ```
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "Door" => Ok(Self::Door),
+            "Floor" => Ok(Self::Floor),
+            "Stairs" => Ok(Self::Stairs),
+            "Wall" => Ok(Self::Wall),
+            "Window" => Ok(Self::Window),
+            _ => bail!("Unknown interior tile '{}'!", value),
+        }
+    }
+
+    pub fn get_color(&self) -> Color {
+        match self {
+            Self::Door => Color::SaddleBrown,
+            Self::Floor => Color::Wheat,
+            Self::Stairs => Color::Silver,
+            Self::Wall => Color::Black,
+            Self::Window => Color::Aqua,
+        }
+    }
+}
+
+impl fmt::Display for InteriorTile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// A floor of a [`building`](crate::model::world::building::Building).
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Floor {
+    pub name: String,
+    pub map: TileMap<InteriorTile>,
+}
+
+impl Floor {
+    /// Creates a floor surrounded by walls, which fits a lot of the given size.
+    pub fn new<S: Into<String>>(name: S, lot_size: Size2d) -> Self {
+        let size = get_interior_size(lot_size);
+        let mut map = TileMap::simple(size, InteriorTile::Floor);
+
+        for y in 0..size.height() {
+            for x in 0..size.width() {
+                let is_border = x == 0 || y == 0 || x == size.width() - 1 || y == size.height() - 1;
+
+                if is_border {
+                    if let Some(tile) = map.get_tile_mut(size.to_index_risky(x, y)) {
+                        *tile = InteriorTile::Wall;
+                    }
+                }
+            }
+        }
+
+        Self {
+            name: name.into(),
+            map,
+        }
+    }
+
+    /// Resizes the floor to fit a lot of the given size & keeps the existing tiles.
+    pub fn resize(&mut self, lot_size: Size2d) {
+        self.map = self
+            .map
+            .resize(get_interior_size(lot_size), InteriorTile::Floor);
+    }
+
+    /// Rotates the floor clockwise by 90° like its lot.
+    pub fn rotate(&mut self) {
+        self.map = self.map.rotate_clockwise();
+    }
+
+    /// Fits the floor to a lot of the given size, whose old bounding box started
+    /// at the offset (in tiles of the lot) relative to the new one.
+    /// The existing tiles keep their position in the town.
+    pub fn reshape(&mut self, offset_x: i32, offset_y: i32, lot_size: Size2d) {
+        let size = get_interior_size(lot_size);
+        let offset_x = offset_x * INTERIOR_RESOLUTION as i32;
+        let offset_y = offset_y * INTERIOR_RESOLUTION as i32;
+        let mut map = TileMap::simple(size, InteriorTile::Floor);
+
+        for y in 0..size.height() {
+            for x in 0..size.width() {
+                if let (Some(old), Some(tile)) = (
+                    self.map.get_tile_xy(x - offset_x, y - offset_y),
+                    map.get_tile_mut(size.to_index_risky(x, y)),
+                ) {
+                    *tile = *old;
+                }
+            }
+        }
+
+        self.map = map;
+    }
+}
+
+/// Returns the size of the floors of a lot.
+///
+/// ```
+///# use rpg_tools_core::model::math::size2d::Size2d;
+///# use rpg_tools_core::model::world::building::interior::get_interior_size;
+/// assert_eq!(get_interior_size(Size2d::new(2, 1)), Size2d::new(8, 4));
+/// ```
+pub fn get_interior_size(lot_size: Size2d) -> Size2d {
+    Size2d::new(
+        lot_size.width() as u32 * INTERIOR_RESOLUTION,
+        lot_size.height() as u32 * INTERIOR_RESOLUTION,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use InteriorTile::{Floor as F, Wall as W};
+
+    #[test]
+    fn new_floor_is_surrounded_by_walls() {
+        let floor = Floor::new("Ground", Size2d::square(1));
+
+        assert_eq!(floor.name, "Ground");
+        assert_eq!(
+            floor.map.get_tiles(),
+            &vec![W, W, W, W, W, F, F, W, W, F, F, W, W, W, W, W]
+        );
+    }
+
+    #[test]
+    fn resize_keeps_tiles() {
+        let mut floor = Floor::new("Ground", Size2d::square(1));
+
+        floor.resize(Size2d::new(2, 1));
+
+        assert_eq!(floor.map.get_size(), Size2d::new(8, 4));
+        assert_eq!(floor.map.get_tile(0), Some(&W));
+        assert_eq!(floor.map.get_tile(4), Some(&F));
+    }
+
+    #[test]
+    fn reshape_keeps_tile_positions() {
+        let mut floor = Floor::new("Ground", Size2d::square(1));
+
+        floor.reshape(1, 0, Size2d::new(2, 1));
+
+        assert_eq!(floor.map.get_size(), Size2d::new(8, 4));
+        assert_eq!(floor.map.get_tile(0), Some(&F));
+        assert_eq!(floor.map.get_tile(4), Some(&W));
+        assert_eq!(floor.map.get_tile(13), Some(&F));
+    }
+}
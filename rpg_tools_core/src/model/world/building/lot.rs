@@ -1,13 +1,23 @@
 use crate::model::math::size2d::Size2d;
+use crate::model::world::building::entrance::Entrance;
 use crate::model::world::town::TownId;
+use crate::utils::map::tile::TileMap;
+use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
 
 /// The lot, plot or parcel of a [`building`](crate::model::world::building::Building).
+///
+/// It is a connected set of tiles inside a bounding box.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct BuildingLot {
     pub town: TownId,
+    /// The top left tile of the bounding box.
     pub tile: usize,
+    /// The size of the bounding box.
     pub size: Size2d,
+    /// Which tiles of the bounding box are part of the lot. All of them, if empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mask: Vec<bool>,
 }
 
 impl BuildingLot {
@@ -16,14 +26,302 @@ impl BuildingLot {
             town,
             tile,
             size: Size2d::square(1),
+            mask: Vec::new(),
         }
     }
 
     pub fn big(town: TownId, tile: usize, size: Size2d) -> Self {
-        BuildingLot { town, tile, size }
+        BuildingLot {
+            town,
+            tile,
+            size,
+            mask: Vec::new(),
+        }
     }
 
     pub fn tile(tile: usize) -> BuildingLot {
         Self::new(TownId::default(), tile)
     }
+
+    /// Creates a lot from the tiles of a town of the given size.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::math::size2d::Size2d;
+    ///# use rpg_tools_core::model::world::building::lot::BuildingLot;
+    ///# use rpg_tools_core::model::world::town::TownId;
+    /// let town = TownId::default();
+    /// let size = Size2d::new(3, 3);
+    ///
+    /// let l_shape = BuildingLot::from_tiles(town, size, &[1, 4, 5]).unwrap();
+    /// assert_eq!(l_shape.tile, 1);
+    /// assert_eq!(l_shape.size, Size2d::square(2));
+    /// assert_eq!(l_shape.mask, vec![true, false, true, true]);
+    ///
+    /// let rectangle = BuildingLot::from_tiles(town, size, &[4, 1]).unwrap();
+    /// assert_eq!(rectangle, BuildingLot::big(town, 1, Size2d::new(1, 2)));
+    ///
+    /// assert!(BuildingLot::from_tiles(town, size, &[]).is_err());
+    /// assert!(BuildingLot::from_tiles(town, size, &[0, 2]).is_err());
+    /// assert!(BuildingLot::from_tiles(town, size, &[8, 9]).is_err());
+    /// ```
+    pub fn from_tiles(town: TownId, town_size: Size2d, tiles: &[usize]) -> Result<Self> {
+        if tiles.is_empty() {
+            bail!("A lot needs at least 1 tile!");
+        } else if let Some(tile) = tiles.iter().find(|&&tile| tile >= town_size.tiles()) {
+            bail!("Tile {} is outside the town!", tile);
+        }
+
+        let xs = tiles.iter().map(|&tile| town_size.to_x(tile));
+        let ys = tiles.iter().map(|&tile| town_size.to_y(tile));
+        let (min_x, max_x) = (xs.clone().min().unwrap(), xs.max().unwrap());
+        let (min_y, max_y) = (ys.clone().min().unwrap(), ys.max().unwrap());
+        let size = Size2d::new((max_x - min_x + 1) as u32, (max_y - min_y + 1) as u32);
+        let mut mask = vec![false; size.tiles()];
+
+        for &tile in tiles {
+            let x = town_size.to_x(tile) - min_x;
+            let y = town_size.to_y(tile) - min_y;
+            mask[size.to_index_risky(x, y)] = true;
+        }
+
+        let lot = BuildingLot {
+            town,
+            tile: town_size.to_index_risky(min_x, min_y),
+            size,
+            mask,
+        }
+        .simplify();
+
+        if !lot.is_connected() {
+            bail!("The tiles of the lot are not connected!");
+        }
+
+        Ok(lot)
+    }
+
+    /// Is the lot the whole bounding box?
+    pub fn is_rectangle(&self) -> bool {
+        self.mask.iter().all(|&part| part)
+    }
+
+    /// Is the tile at the position relative to the bounding box part of the lot?
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        self.size
+            .to_index(x, y)
+            .map(|index| self.mask.get(index).copied().unwrap_or(true))
+            .unwrap_or(false)
+    }
+
+    /// Is the [`entrance`](Entrance) on a tile of the lot & does it face a tile outside of it?
+    pub fn is_valid_entrance(&self, entrance: &Entrance) -> bool {
+        let (x, y) = entrance.get_front();
+        self.contains(entrance.x, entrance.y) && !self.contains(x, y)
+    }
+
+    /// Returns the positions of all tiles of the lot relative to the bounding box.
+    pub fn get_local_positions(&self) -> Vec<(i32, i32)> {
+        let mut positions = Vec::new();
+
+        for y in 0..self.size.height() {
+            for x in 0..self.size.width() {
+                if self.contains(x, y) {
+                    positions.push((x, y));
+                }
+            }
+        }
+
+        positions
+    }
+
+    /// Returns the positions of all tiles of the lot in a town of the given size.
+    /// They can be outside the town.
+    pub fn get_positions(&self, town_size: Size2d) -> Vec<(i32, i32)> {
+        let start_x = town_size.to_x(self.tile);
+        let start_y = town_size.to_y(self.tile);
+
+        self.get_local_positions()
+            .into_iter()
+            .map(|(x, y)| (start_x + x, start_y + y))
+            .collect()
+    }
+
+    /// Returns the indices of all tiles of the lot in a town of the given size
+    /// or nothing, if part of the lot is outside the town.
+    pub fn get_tiles(&self, town_size: Size2d) -> Option<Vec<usize>> {
+        self.get_positions(town_size)
+            .into_iter()
+            .map(|(x, y)| town_size.to_index(x, y))
+            .collect()
+    }
+
+    /// Are all tiles of the lot connected horizontally or vertically?
+    /// An empty lot isn't connected.
+    pub fn is_connected(&self) -> bool {
+        let positions = self.get_local_positions();
+        let mut visited = vec![false; self.size.tiles()];
+        let mut stack: Vec<(i32, i32)> = positions.first().copied().into_iter().collect();
+        let mut count = 0;
+
+        while let Some((x, y)) = stack.pop() {
+            let index = self.size.to_index_risky(x, y);
+
+            if visited[index] {
+                continue;
+            }
+
+            visited[index] = true;
+            count += 1;
+
+            for (nx, ny) in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+                if self.contains(nx, ny) {
+                    stack.push((nx, ny));
+                }
+            }
+        }
+
+        count > 0 && count == positions.len()
+    }
+
+    /// Changes the size of the bounding box. New tiles are part of the lot.
+    pub fn resize(&self, size: Size2d) -> Self {
+        let mask = if self.is_rectangle() {
+            Vec::new()
+        } else {
+            TileMap::new(self.size, self.mask.clone())
+                .map(|map| map.resize(size, true).get_tiles().clone())
+                .unwrap_or_default()
+        };
+
+        BuildingLot {
+            town: self.town,
+            tile: self.tile,
+            size,
+            mask,
+        }
+        .simplify()
+    }
+
+    /// Rotates the lot clockwise by 90° around the top left tile of its bounding box.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::math::size2d::Size2d;
+    ///# use rpg_tools_core::model::world::building::lot::BuildingLot;
+    ///# use rpg_tools_core::model::world::town::TownId;
+    /// let lot = BuildingLot::big(TownId::default(), 4, Size2d::new(3, 1));
+    ///
+    /// assert_eq!(lot.rotate(), BuildingLot::big(TownId::default(), 4, Size2d::new(1, 3)));
+    /// ```
+    pub fn rotate(&self) -> Self {
+        let mask = if self.is_rectangle() {
+            Vec::new()
+        } else {
+            TileMap::new(self.size, self.mask.clone())
+                .map(|map| map.rotate_clockwise().get_tiles().clone())
+                .unwrap_or_default()
+        };
+
+        BuildingLot {
+            town: self.town,
+            tile: self.tile,
+            size: Size2d::new(self.size.height() as u32, self.size.width() as u32),
+            mask,
+        }
+    }
+
+    /// Returns the same lot at another tile of another town.
+    pub fn relocate(&self, town: TownId, tile: usize) -> Self {
+        BuildingLot {
+            town,
+            tile,
+            ..self.clone()
+        }
+    }
+
+    /// Removes the mask, if the lot is a rectangle.
+    fn simplify(mut self) -> Self {
+        if self.is_rectangle() {
+            self.mask.clear();
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::math::side2d::Side2d;
+
+    fn l_shape() -> BuildingLot {
+        BuildingLot::from_tiles(TownId::default(), Size2d::new(3, 3), &[0, 3, 4]).unwrap()
+    }
+
+    #[test]
+    fn contains() {
+        let lot = l_shape();
+
+        assert!(lot.contains(0, 0));
+        assert!(!lot.contains(1, 0));
+        assert!(lot.contains(0, 1));
+        assert!(lot.contains(1, 1));
+        assert!(!lot.contains(2, 1));
+        assert!(!lot.contains(-1, 0));
+    }
+
+    #[test]
+    fn get_tiles() {
+        let lot = l_shape();
+
+        assert_eq!(lot.get_tiles(Size2d::new(3, 3)), Some(vec![0, 3, 4]));
+        assert_eq!(lot.get_tiles(Size2d::new(4, 2)), Some(vec![0, 4, 5]));
+        assert_eq!(lot.get_tiles(Size2d::new(1, 3)), None);
+    }
+
+    #[test]
+    fn rectangle_is_connected() {
+        assert!(BuildingLot::big(TownId::default(), 0, Size2d::new(3, 2)).is_connected());
+    }
+
+    #[test]
+    fn diagonal_tiles_are_not_connected() {
+        let mut lot = BuildingLot::big(TownId::default(), 0, Size2d::square(2));
+        lot.mask = vec![true, false, false, true];
+
+        assert!(!lot.is_connected());
+    }
+
+    #[test]
+    fn valid_entrances() {
+        let lot = l_shape();
+
+        assert!(lot.is_valid_entrance(&Entrance::new(0, 0, Side2d::Top)));
+        assert!(lot.is_valid_entrance(&Entrance::new(0, 0, Side2d::Right)));
+        assert!(lot.is_valid_entrance(&Entrance::new(1, 1, Side2d::Top)));
+        assert!(!lot.is_valid_entrance(&Entrance::new(0, 0, Side2d::Bottom)));
+        assert!(!lot.is_valid_entrance(&Entrance::new(1, 0, Side2d::Top)));
+    }
+
+    #[test]
+    fn resize_keeps_shape() {
+        let lot = l_shape().resize(Size2d::new(3, 2));
+
+        assert_eq!(lot.mask, vec![true, false, true, true, true, true]);
+    }
+
+    #[test]
+    fn rotate_l_shape() {
+        let lot = l_shape().rotate();
+
+        assert_eq!(lot.mask, vec![true, true, true, false]);
+    }
+
+    #[test]
+    fn resize_to_rectangle() {
+        let lot = l_shape().resize(Size2d::new(1, 2));
+
+        assert_eq!(
+            lot,
+            BuildingLot::big(TownId::default(), 0, Size2d::new(1, 2))
+        );
+    }
 }
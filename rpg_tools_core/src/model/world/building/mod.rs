@@ -1,6 +1,15 @@
+pub mod address;
+pub mod entrance;
+pub mod interior;
 pub mod lot;
+pub mod types;
 
+use crate::model::description::WithDescription;
 use crate::model::name::{Name, WithName};
+use crate::model::visibility::{Visibility, WithVisibility};
+use crate::model::world::building::address::Address;
+use crate::model::world::building::entrance::Entrance;
+use crate::model::world::building::interior::Floor;
 use crate::model::world::building::lot::BuildingLot;
 use crate::utils::storage::{Element, Id};
 use serde::{Deserialize, Serialize};
@@ -25,6 +34,20 @@ pub struct Building {
     id: BuildingId,
     name: Name,
     pub lot: BuildingLot,
+    /// The name of a [`building type`](types::BuildingType) of the setting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub building_type: Option<String>,
+    /// The floor plans from the lowest to the highest floor.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub floors: Vec<Floor>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entrances: Vec<Entrance>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(default)]
+    visibility: Visibility,
+    #[serde(default)]
+    description: Option<String>,
 }
 
 impl Building {
@@ -33,6 +56,12 @@ impl Building {
             id,
             name: Name::new(format!("Building {}", id.0)).unwrap(),
             lot,
+            building_type: None,
+            floors: Vec::new(),
+            entrances: Vec::new(),
+            address: None,
+            visibility: Visibility::default(),
+            description: None,
         }
     }
 }
@@ -56,3 +85,23 @@ impl WithName for Building {
         self.name = name;
     }
 }
+
+impl WithVisibility for Building {
+    fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+}
+
+impl WithDescription for Building {
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn set_description(&mut self, description: Option<String>) {
+        self.description = description;
+    }
+}
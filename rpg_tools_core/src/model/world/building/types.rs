@@ -0,0 +1,172 @@
+use crate::model::color::Color;
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// The color of buildings without a (known) type.
+pub const UNTYPED_COLOR: Color = Color::Black;
+
+/// A setting-defined usage of a [`building`](crate::model::world::building::Building),
+/// like a residence or a church.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BuildingType {
+    pub name: String,
+    pub color: Color,
+    /// A short symbol shown on the map.
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// Characters can live in buildings of this type.
+    #[serde(default)]
+    pub residence: bool,
+}
+
+impl BuildingType {
+    pub fn new<S: Into<String>>(name: S, color: Color, icon: Option<&str>) -> Self {
+        Self {
+            name: name.into(),
+            color,
+            icon: icon.map(|icon| icon.to_string()),
+            residence: false,
+        }
+    }
+}
+
+/// All [`building types`](BuildingType) of the setting.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BuildingTypeCatalog {
+    pub types: Vec<BuildingType>,
+}
+
+impl Default for BuildingTypeCatalog {
+    fn default() -> Self {
+        Self {
+            types: vec![
+                BuildingType {
+                    residence: true,
+                    ..BuildingType::new("Residence", Color::Tan, None)
+                },
+                BuildingType::new("Shop", Color::Gold, Some("$")),
+                BuildingType::new("Tavern", Color::Orange, Some("T")),
+                BuildingType::new("Church", Color::Silver, Some("+")),
+                BuildingType::new("Warehouse", Color::Wheat, Some("W")),
+            ],
+        }
+    }
+}
+
+impl BuildingTypeCatalog {
+    /// Checks, that the names are unique & not empty.
+    pub fn check(&self) -> Result<()> {
+        let mut names = HashSet::new();
+
+        for building_type in &self.types {
+            if building_type.name.trim().is_empty() {
+                bail!("The name of a building type is empty!");
+            } else if !names.insert(&building_type.name) {
+                bail!(
+                    "The building type '{}' is defined twice!",
+                    building_type.name
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the names of all building types.
+    pub fn get_names(&self) -> Vec<&str> {
+        self.types.iter().map(|t| t.name.as_str()).collect()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&BuildingType> {
+        self.types.iter().find(|t| t.name.eq(name))
+    }
+
+    /// Returns the index of a building type in the catalog.
+    pub fn get_index(&self, name: &str) -> Option<usize> {
+        self.types.iter().position(|t| t.name.eq(name))
+    }
+
+    /// Returns a building type & fails for unknown names.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::world::building::types::BuildingTypeCatalog;
+    /// let catalog = BuildingTypeCatalog::default();
+    ///
+    /// assert_eq!(catalog.parse("Church").unwrap().name, "Church");
+    /// assert!(catalog.parse("Spaceport").is_err());
+    /// ```
+    pub fn parse(&self, name: &str) -> Result<&BuildingType> {
+        self.get(name)
+            .context(format!("Unknown building type '{}'!", name))
+    }
+
+    /// Returns the color of an optional building type, or the default for unknown ones.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::color::Color;
+    ///# use rpg_tools_core::model::world::building::types::BuildingTypeCatalog;
+    /// let catalog = BuildingTypeCatalog::default();
+    ///
+    /// assert_eq!(catalog.get_color(Some("Shop")), Color::Gold);
+    /// assert_eq!(catalog.get_color(Some("Unknown")), Color::Black);
+    /// assert_eq!(catalog.get_color(None), Color::Black);
+    /// ```
+    pub fn get_color(&self, name: Option<&str>) -> Color {
+        name.and_then(|name| self.get(name))
+            .map(|t| t.color)
+            .unwrap_or(UNTYPED_COLOR)
+    }
+
+    /// Can characters live in a building of an optional building type?
+    /// Buildings without a type can be anything, so they are treated as residences.
+    ///
+    /// ```
+    ///# use rpg_tools_core::model::world::building::types::BuildingTypeCatalog;
+    /// let catalog = BuildingTypeCatalog::default();
+    ///
+    /// assert!(catalog.is_residence(Some("Residence")));
+    /// assert!(!catalog.is_residence(Some("Shop")));
+    /// assert!(!catalog.is_residence(Some("Unknown")));
+    /// assert!(catalog.is_residence(None));
+    /// ```
+    pub fn is_residence(&self, name: Option<&str>) -> bool {
+        match name {
+            Some(name) => self.get(name).map(|t| t.residence).unwrap_or(false),
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_default() {
+        assert!(BuildingTypeCatalog::default().check().is_ok());
+    }
+
+    #[test]
+    fn check_empty_catalog() {
+        assert!(BuildingTypeCatalog { types: vec![] }.check().is_ok());
+    }
+
+    #[test]
+    fn check_duplicate() {
+        let mut catalog = BuildingTypeCatalog::default();
+        catalog
+            .types
+            .push(BuildingType::new("Shop", Color::Red, None));
+
+        assert!(catalog.check().is_err());
+    }
+
+    #[test]
+    fn check_empty_name() {
+        let mut catalog = BuildingTypeCatalog::default();
+        catalog.types[0].name = " ".to_string();
+
+        assert!(catalog.check().is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::model::description::WithDescription;
 use crate::model::name::{Name, WithName};
 use crate::model::world::town::towns::WithTowns;
 use crate::model::world::town::TownId;
@@ -25,6 +26,8 @@ pub struct Mountain {
     id: MountainId,
     name: Name,
     towns: HashSet<TownId>,
+    #[serde(default)]
+    description: Option<String>,
 }
 
 impl Mountain {
@@ -33,6 +36,7 @@ impl Mountain {
             id,
             name: Name::new(format!("Mountain {}", id.0)).unwrap(),
             towns: Default::default(),
+            description: None,
         }
     }
 }
@@ -65,3 +69,13 @@ impl WithTowns for Mountain {
         &mut self.towns
     }
 }
+
+impl WithDescription for Mountain {
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn set_description(&mut self, description: Option<String>) {
+        self.description = description;
+    }
+}
@@ -1,4 +1,6 @@
+use crate::model::description::WithDescription;
 use crate::model::name::{Name, WithName};
+use crate::model::visibility::{Visibility, WithVisibility};
 use crate::model::world::town::towns::WithTowns;
 use crate::model::world::town::TownId;
 use crate::utils::storage::{Element, Id};
@@ -25,6 +27,10 @@ pub struct Street {
     id: StreetId,
     name: Name,
     towns: HashSet<TownId>,
+    #[serde(default)]
+    visibility: Visibility,
+    #[serde(default)]
+    description: Option<String>,
 }
 
 impl Street {
@@ -33,6 +39,8 @@ impl Street {
             id,
             name: Name::new(format!("Street {}", id.0)).unwrap(),
             towns: HashSet::new(),
+            visibility: Visibility::default(),
+            description: None,
         }
     }
 }
@@ -65,3 +73,23 @@ impl WithTowns for Street {
         &mut self.towns
     }
 }
+
+impl WithVisibility for Street {
+    fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+}
+
+impl WithDescription for Street {
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn set_description(&mut self, description: Option<String>) {
+        self.description = description;
+    }
+}
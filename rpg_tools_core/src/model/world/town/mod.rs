@@ -3,8 +3,10 @@ pub mod terrain;
 pub mod tile;
 pub mod towns;
 
+use crate::model::description::WithDescription;
 use crate::model::math::size2d::Size2d;
 use crate::model::name::{Name, WithName};
+use crate::model::visibility::{Visibility, WithVisibility};
 use crate::model::world::building::lot::BuildingLot;
 use crate::model::world::building::BuildingId;
 use crate::model::world::street::StreetId;
@@ -35,6 +37,10 @@ pub struct Town {
     id: TownId,
     name: Name,
     pub map: TileMap<TownTile>,
+    #[serde(default)]
+    visibility: Visibility,
+    #[serde(default)]
+    description: Option<String>,
 }
 
 impl Town {
@@ -47,6 +53,8 @@ impl Town {
             id,
             name: Name::new(format!("Town {}", id.0)).unwrap(),
             map: TileMap::simple(size, TownTile::new(Terrain::Plain)),
+            visibility: Visibility::default(),
+            description: None,
         }
     }
 
@@ -55,26 +63,22 @@ impl Town {
     ///
     /// This can leave the town in an inconsistent state!
     pub fn set_lot_construction(&mut self, lot: &BuildingLot, construction: Construction) -> bool {
-        let start_x = self.map.get_size().to_x(lot.tile);
-        let start_y = self.map.get_size().to_y(lot.tile);
         let is_building = construction.is_present();
 
-        for y in start_y..(start_y + lot.size.height()) {
-            for x in start_x..(start_x + lot.size.width()) {
-                if let Some(tile) = self
-                    .map
-                    .get_size()
-                    .to_index(x, y)
-                    .and_then(|index| self.map.get_tile_mut(index))
-                {
-                    if is_building && tile.construction.is_present() {
-                        return false;
-                    }
-
-                    tile.construction = construction.clone();
-                } else {
+        for (x, y) in lot.get_positions(self.map.get_size()) {
+            if let Some(tile) = self
+                .map
+                .get_size()
+                .to_index(x, y)
+                .and_then(|index| self.map.get_tile_mut(index))
+            {
+                if is_building && tile.construction.is_present() {
                     return false;
                 }
+
+                tile.construction = construction.clone();
+            } else {
+                return false;
             }
         }
 
@@ -87,27 +91,14 @@ impl Town {
         lot: &BuildingLot,
         check: F,
     ) -> bool {
-        let start_x = self.map.get_size().to_x(lot.tile);
-        let start_y = self.map.get_size().to_y(lot.tile);
-
-        for y in start_y..(start_y + lot.size.height()) {
-            for x in start_x..(start_x + lot.size.width()) {
-                if let Some(tile) = self
-                    .map
-                    .get_size()
-                    .to_index(x, y)
-                    .and_then(|index| self.map.get_tile(index))
-                {
-                    if !check(&tile.construction) {
-                        return false;
-                    }
-                } else {
-                    return false;
-                }
-            }
-        }
-
-        true
+        lot.get_positions(self.map.get_size())
+            .into_iter()
+            .all(|(x, y)| {
+                self.map
+                    .get_tile_xy(x, y)
+                    .map(|tile| check(&tile.construction))
+                    .unwrap_or(false)
+            })
     }
 
     /// Checks if the [`tiles`](TownTile) of the [`lot`](BuildingLot) match the [`construction`](Construction).
@@ -182,3 +173,23 @@ impl WithName for Town {
         self.name = name;
     }
 }
+
+impl WithVisibility for Town {
+    fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+}
+
+impl WithDescription for Town {
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn set_description(&mut self, description: Option<String>) {
+        self.description = description;
+    }
+}
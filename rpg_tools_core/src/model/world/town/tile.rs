@@ -1,4 +1,5 @@
 use crate::model::color::Color;
+use crate::model::visibility::Visibility;
 use crate::model::world::town::construction::Construction;
 use crate::model::world::town::terrain::Terrain;
 use serde::{Deserialize, Serialize};
@@ -8,6 +9,9 @@ use serde::{Deserialize, Serialize};
 pub struct TownTile {
     pub terrain: Terrain,
     pub construction: Construction,
+    /// The visibility of the [`construction`](Construction).
+    #[serde(default)]
+    pub visibility: Visibility,
 }
 
 impl TownTile {
@@ -15,6 +19,7 @@ impl TownTile {
         Self {
             terrain,
             construction: Construction::None,
+            visibility: Visibility::default(),
         }
     }
 
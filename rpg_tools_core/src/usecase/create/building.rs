@@ -85,6 +85,23 @@ mod tests {
         assert!(data.building_manager.is_empty())
     }
 
+    #[test]
+    fn create_l_shaped() {
+        let mut data = RpgData::default();
+        let town_id = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::square(2)));
+        let other_id = create_building(&mut data, BuildingLot::tile(1)).unwrap();
+        let lot = BuildingLot::from_tiles(town_id, Size2d::square(2), &[0, 2, 3]).unwrap();
+
+        let id = create_building(&mut data, lot).unwrap();
+
+        assert!(is_building(&data, town_id, 0, id));
+        assert!(is_building(&data, town_id, 1, other_id));
+        assert!(is_building(&data, town_id, 2, id));
+        assert!(is_building(&data, town_id, 3, id));
+    }
+
     #[test]
     fn occupied_by_building() {
         let mut data = RpgData::default();
@@ -134,6 +151,6 @@ mod tests {
             data.building_manager.get(id).unwrap(),
             &Building::new(id, BuildingLot::tile(tile))
         );
-        assert!(is_building(&data, town_id, tile, id));
+        assert!(is_building(data, town_id, tile, id));
     }
 }
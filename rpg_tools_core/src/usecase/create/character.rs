@@ -0,0 +1,334 @@
+use crate::model::character::appearance::Appearance;
+use crate::model::character::culture::CultureId;
+use crate::model::character::gender::{Gender, GenderMap};
+use crate::model::character::name::{CharacterName, Lastname, LastnameType};
+use crate::model::character::{Character, CharacterId};
+use crate::model::visibility::{Visibility, WithVisibility};
+use crate::model::world::building::BuildingId;
+use crate::model::world::town::TownId;
+use crate::model::RpgData;
+use crate::usecase::edit::name::generate::CultureNames;
+use crate::utils::storage::{Element, Id};
+use anyhow::{bail, Context, Result};
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+const MAX_ATTEMPTS: usize = 20;
+const GENDERS: [Gender; 3] = [Gender::Female, Gender::Genderless, Gender::Male];
+
+/// The settings to generate a batch of random non-player characters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NpcSettings {
+    pub culture: CultureId,
+    /// The relative weight of each gender.
+    pub genders: GenderMap<u32>,
+    pub lastname: LastnameType,
+    /// The town, whose buildings are used as homes.
+    pub town: Option<TownId>,
+    pub count: usize,
+    /// The same seed generates the same characters.
+    pub seed: u64,
+}
+
+impl NpcSettings {
+    pub fn new(culture: CultureId, count: usize, seed: u64) -> Self {
+        Self {
+            culture,
+            genders: GenderMap::new(1, 0, 1),
+            lastname: LastnameType::Family,
+            town: None,
+            count,
+            seed,
+        }
+    }
+}
+
+/// Generates a batch of random [`characters`](Character) without adding them.
+///
+/// Their ids are the ones they get, if the batch is added with [`create_npcs()`].
+pub fn generate_npcs(data: &RpgData, settings: &NpcSettings) -> Result<Vec<Character>> {
+    let culture = data
+        .cultures
+        .get(settings.culture)
+        .context("Culture doesn't exist")?;
+    let names = CultureNames::new(culture);
+    let genders = WeightedIndex::new([
+        settings.genders.female,
+        settings.genders.genderless,
+        settings.genders.male,
+    ])
+    .context("The gender distribution is invalid!")?;
+    let homes = get_homes(data, settings.town)?;
+    let mut rng = ChaCha8Rng::seed_from_u64(settings.seed);
+    let mut npcs: Vec<Character> = Vec::with_capacity(settings.count);
+
+    for i in 0..settings.count {
+        let gender = GENDERS[genders.sample(&mut rng)];
+        let definition = data
+            .genders
+            .get_by_base(gender)
+            .context(format!("The setting has no gender based on {}!", gender))?;
+        let name = generate_unused_name(data, &npcs, &names, gender, settings.lastname, &mut rng)?;
+        let mut npc = Character::new(CharacterId::new(data.characters.len() + i));
+
+        npc.name = name;
+        npc.gender = definition.name.clone();
+        npc.culture = settings.culture;
+        npc.appearance = Appearance::random(gender, &mut rng);
+        npc.home = homes.choose(&mut rng).copied();
+
+        npcs.push(npc);
+    }
+
+    Ok(npcs)
+}
+
+/// Generates a batch of random [`characters`](Character) & adds them.
+pub fn create_npcs(data: &mut RpgData, settings: &NpcSettings) -> Result<Vec<CharacterId>> {
+    let npcs = generate_npcs(data, settings)?;
+
+    Ok(npcs
+        .into_iter()
+        .map(|npc| data.characters.create(|id| npc.with_id(id)))
+        .collect())
+}
+
+fn get_homes(data: &RpgData, town: Option<TownId>) -> Result<Vec<BuildingId>> {
+    if let Some(town) = town {
+        if !data.town_manager.contains(town) {
+            bail!("Unknown town id {}!", town.id());
+        }
+
+        let homes: Vec<_> = data
+            .building_manager
+            .get_all()
+            .iter()
+            .filter(|building| building.lot.town == town)
+            .filter(|building| building.visibility() == Visibility::Public)
+            .filter(|building| {
+                data.building_types
+                    .is_residence(building.building_type.as_deref())
+            })
+            .map(|building| building.id())
+            .collect();
+
+        if homes.is_empty() {
+            bail!("Town {} has no public residences!", town.id());
+        }
+
+        Ok(homes)
+    } else {
+        Ok(Vec::new())
+    }
+}
+
+fn generate_unused_name<R: Rng>(
+    data: &RpgData,
+    npcs: &[Character],
+    names: &CultureNames,
+    gender: Gender,
+    lastname: LastnameType,
+    rng: &mut R,
+) -> Result<CharacterName> {
+    for _ in 0..MAX_ATTEMPTS {
+        let first = names.generate_first_name(gender, rng)?;
+        let last = generate_lastname(names, gender, lastname, rng)?;
+        let name = CharacterName::simple(first, last);
+
+        if !data
+            .characters
+            .get_all()
+            .iter()
+            .chain(npcs)
+            .any(|c| c.name.eq(&name))
+        {
+            return Ok(name);
+        }
+    }
+
+    bail!("Failed to generate an unused name!")
+}
+
+fn generate_lastname<R: Rng>(
+    names: &CultureNames,
+    gender: Gender,
+    lastname: LastnameType,
+    rng: &mut R,
+) -> Result<Lastname> {
+    Ok(match lastname {
+        LastnameType::None => Lastname::None,
+        LastnameType::Family => Lastname::Family {
+            name: names
+                .generate_family_name(rng)
+                .context("Failed to generate a family name!")?,
+        },
+        LastnameType::Patronymic => {
+            let father = names.generate_first_name(Gender::Male, rng)?;
+            Lastname::Patronymic {
+                name: names.culture.patronymic.get(gender).derive(&father),
+            }
+        }
+        LastnameType::Matronymic => {
+            let mother = names.generate_first_name(Gender::Female, rng)?;
+            Lastname::Matronymic {
+                name: names.culture.matronymic.get(gender).derive(&mother),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::culture::Culture;
+    use crate::model::character::derivation::DerivationRule;
+    use crate::model::character::generator::NameGenerator;
+    use crate::model::math::size2d::Size2d;
+    use crate::model::world::building::lot::BuildingLot;
+    use crate::model::world::town::Town;
+    use crate::usecase::create::building::create_building;
+
+    fn to_strings(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    fn init() -> (RpgData, CultureId) {
+        let mut data = RpgData::default();
+        let id = data.cultures.create(Culture::new);
+        let culture = data.cultures.get_mut(id).unwrap();
+        culture.first_names.female = NameGenerator::List {
+            names: to_strings(&["Anna", "Emma", "Mary"]),
+        };
+        culture.first_names.male = NameGenerator::Markov {
+            examples: to_strings(&["Aldric", "Alwin", "Baldwin", "Edric", "Edwin"]),
+        };
+        culture.family_names = NameGenerator::List {
+            names: to_strings(&["Miller", "Smith", "Taylor"]),
+        };
+        culture.patronymic.female = DerivationRule::new("", "dottir");
+
+        (data, id)
+    }
+
+    #[test]
+    fn same_seed_generates_same_npcs() {
+        let (data, culture) = init();
+        let settings = NpcSettings::new(culture, 5, 42);
+
+        let npcs = generate_npcs(&data, &settings).unwrap();
+
+        assert_eq!(npcs.len(), 5);
+        assert_eq!(npcs, generate_npcs(&data, &settings).unwrap());
+        assert_ne!(
+            npcs,
+            generate_npcs(&data, &NpcSettings::new(culture, 5, 43)).unwrap()
+        );
+    }
+
+    #[test]
+    fn create_batch() {
+        let (mut data, culture) = init();
+        data.characters.create(Character::new);
+        let settings = NpcSettings::new(culture, 3, 7);
+        let npcs = generate_npcs(&data, &settings).unwrap();
+
+        let ids = create_npcs(&mut data, &settings).unwrap();
+
+        assert_eq!(
+            ids,
+            vec![
+                CharacterId::new(1),
+                CharacterId::new(2),
+                CharacterId::new(3)
+            ]
+        );
+        for npc in npcs {
+            assert_eq!(data.characters.get(npc.id()), Some(&npc));
+        }
+    }
+
+    #[test]
+    fn gender_distribution() {
+        let (data, culture) = init();
+        let mut settings = NpcSettings::new(culture, 5, 1);
+        settings.genders = GenderMap::new(1, 0, 0);
+
+        for npc in generate_npcs(&data, &settings).unwrap() {
+            assert_eq!(npc.gender, Gender::Female.to_string());
+        }
+
+        settings.genders = GenderMap::new(0, 0, 0);
+        assert!(generate_npcs(&data, &settings).is_err());
+    }
+
+    #[test]
+    fn patronymic() {
+        let (data, culture) = init();
+        let mut settings = NpcSettings::new(culture, 5, 3);
+        settings.genders = GenderMap::new(1, 0, 0);
+        settings.lastname = LastnameType::Patronymic;
+
+        for npc in generate_npcs(&data, &settings).unwrap() {
+            match npc.name.last() {
+                Lastname::Patronymic { name } => assert!(name.str().ends_with("dottir")),
+                _ => panic!("Expected a patronymic!"),
+            }
+        }
+    }
+
+    #[test]
+    fn homes_in_town() {
+        let (mut data, culture) = init();
+        let town = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::square(2)));
+        let building0 = create_building(&mut data, BuildingLot::tile(0)).unwrap();
+        let building1 = create_building(&mut data, BuildingLot::tile(1)).unwrap();
+        data.building_manager
+            .get_mut(building1)
+            .unwrap()
+            .building_type = Some("Residence".to_string());
+        let hidden = create_building(&mut data, BuildingLot::tile(2)).unwrap();
+        data.building_manager
+            .get_mut(hidden)
+            .unwrap()
+            .set_visibility(Visibility::GmOnly);
+        let shop = create_building(&mut data, BuildingLot::tile(3)).unwrap();
+        data.building_manager.get_mut(shop).unwrap().building_type = Some("Shop".to_string());
+        let mut settings = NpcSettings::new(culture, 10, 5);
+        settings.town = Some(town);
+
+        for npc in generate_npcs(&data, &settings).unwrap() {
+            assert!(npc.home == Some(building0) || npc.home == Some(building1));
+        }
+    }
+
+    #[test]
+    fn town_without_buildings() {
+        let (mut data, culture) = init();
+        let town = data.town_manager.create(Town::new);
+        let mut settings = NpcSettings::new(culture, 1, 0);
+        settings.town = Some(town);
+
+        assert!(generate_npcs(&data, &settings).is_err());
+    }
+
+    #[test]
+    fn unknown_culture() {
+        let data = RpgData::default();
+
+        assert!(generate_npcs(&data, &NpcSettings::new(CultureId::new(0), 1, 0)).is_err());
+    }
+
+    #[test]
+    fn not_enough_names() {
+        let (data, culture) = init();
+        let mut settings = NpcSettings::new(culture, 10, 0);
+        settings.genders = GenderMap::new(1, 0, 0);
+        settings.lastname = LastnameType::None;
+
+        assert!(generate_npcs(&data, &settings).is_err());
+    }
+}
@@ -0,0 +1,40 @@
+use crate::model::event::{Event, EventId};
+use crate::model::RpgData;
+
+/// Adds an [`event`](Event), which happens at the current date of the campaign, if it is known.
+pub fn create_event(data: &mut RpgData) -> EventId {
+    let now = data.time.now;
+    let id = data.events.create(Event::new);
+
+    if let (Some(event), Some(now)) = (data.events.get_mut(id), now) {
+        event.date = now;
+    }
+
+    id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::time::date::Date;
+
+    #[test]
+    fn with_now() {
+        let mut data = RpgData::default();
+        let now = Date::new(1925, 3, 14);
+        data.time.now = Some(now);
+
+        let id = create_event(&mut data);
+
+        assert_eq!(data.events.get(id).unwrap().date, now);
+    }
+
+    #[test]
+    fn without_now() {
+        let mut data = RpgData::default();
+
+        let id = create_event(&mut data);
+
+        assert_eq!(data.events.get(id).unwrap().date, Event::new(id).date);
+    }
+}
@@ -1 +1,3 @@
 pub mod building;
+pub mod character;
+pub mod event;
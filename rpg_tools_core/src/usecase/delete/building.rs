@@ -1,41 +1,58 @@
-use crate::model::world::building::BuildingId;
+use crate::model::element::ElementRef;
+use crate::model::world::building::{Building, BuildingId};
 use crate::model::world::town::construction::Construction;
 use crate::model::RpgData;
-use crate::usecase::delete::DeleteResult;
+use crate::usecase::delete::{remove_weak_references, DeleteResult};
+use crate::usecase::edit::reference::replace_references;
 use crate::utils::storage::DeleteElementResult;
 
 /// Tries to delete a [`building`](Building).
+///
+/// Its residents become homeless, its workers unemployed & it is removed from its events
+/// & organizations.
 pub fn delete_building(data: &mut RpgData, id: BuildingId) -> DeleteResult {
-    let building = match data.building_manager.delete(id) {
-        DeleteElementResult::SwappedAndRemoved { element, .. } => {
-            if let Some(building) = data.building_manager.get_mut(id) {
-                if let Some(town) = data.town_manager.get_mut(building.lot.town) {
-                    town.set_lot_construction(&building.lot, Construction::None);
-                    town.set_lot_construction(&building.lot, Construction::Building { id });
-                }
-            }
-
-            element
+    if data.building_manager.contains(id) {
+        remove_weak_references(data, ElementRef::Building(id));
+    }
+
+    match data.building_manager.delete(id) {
+        DeleteElementResult::SwappedAndRemoved {
+            element,
+            id_to_update,
+        } => {
+            clear_lot(data, &element);
+            replace_references(
+                data,
+                ElementRef::Building(id_to_update),
+                ElementRef::Building(id),
+            );
         }
-        DeleteElementResult::DeletedLastElement { element } => element,
+        DeleteElementResult::DeletedLastElement { element } => clear_lot(data, &element),
         DeleteElementResult::NotFound => return DeleteResult::NotFound,
-    };
+    }
+
+    DeleteResult::Ok
+}
 
+fn clear_lot(data: &mut RpgData, building: &Building) {
     if let Some(town) = data.town_manager.get_mut(building.lot.town) {
         town.set_lot_construction(&building.lot, Construction::None);
     }
-
-    DeleteResult::Ok
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::character::Character;
+    use crate::model::event::Event;
     use crate::model::math::size2d::Size2d;
+    use crate::model::organization::Organization;
     use crate::model::world::building::lot::BuildingLot;
     use crate::model::world::town::Town;
     use crate::model::RpgData;
     use crate::usecase::create::building::create_building;
+    use crate::usecase::edit::event::add_participant;
+    use crate::usecase::edit::organization::{add_building, update_headquarters};
     use crate::usecase::get::town::{is_building, is_free};
 
     #[test]
@@ -72,6 +89,81 @@ mod tests {
         assert!(is_free(&data, town_id, 1));
     }
 
+    #[test]
+    fn test_delete_l_shaped() {
+        let mut data = RpgData::default();
+        let size = Size2d::square(2);
+        let town_id = data.town_manager.create(|id| Town::simple(id, size));
+        let lot = BuildingLot::from_tiles(town_id, size, &[0, 1, 3]).unwrap();
+        let id0 = create_building(&mut data, lot).unwrap();
+        let id1 = create_building(&mut data, BuildingLot::tile(2)).unwrap();
+
+        assert_eq!(DeleteResult::Ok, delete_building(&mut data, id0));
+
+        assert!(is_free(&data, town_id, 0));
+        assert!(is_free(&data, town_id, 1));
+        assert!(is_building(&data, town_id, 2, id0));
+        assert!(is_free(&data, town_id, 3));
+        assert!(!data.building_manager.contains(id1));
+    }
+
+    #[test]
+    fn test_update_occupants() {
+        let mut data = RpgData::default();
+        data.town_manager
+            .create(|id| Town::simple(id, Size2d::square(2)));
+        let id0 = create_building(&mut data, BuildingLot::tile(0)).unwrap();
+        let id1 = create_building(&mut data, BuildingLot::tile(1)).unwrap();
+        let character0 = data.characters.create(Character::new);
+        let character1 = data.characters.create(Character::new);
+        data.characters.get_mut(character0).unwrap().home = Some(id0);
+        data.characters.get_mut(character1).unwrap().home = Some(id1);
+        data.characters.get_mut(character1).unwrap().workplace = Some(id0);
+
+        assert_eq!(DeleteResult::Ok, delete_building(&mut data, id0));
+
+        assert_eq!(data.characters.get(character0).unwrap().home, None);
+        assert_eq!(data.characters.get(character1).unwrap().home, Some(id0));
+        assert_eq!(data.characters.get(character1).unwrap().workplace, None);
+    }
+
+    #[test]
+    fn test_remove_from_events() {
+        let mut data = RpgData::default();
+        data.town_manager
+            .create(|id| Town::simple(id, Size2d::square(2)));
+        let id0 = create_building(&mut data, BuildingLot::tile(0)).unwrap();
+        let id1 = create_building(&mut data, BuildingLot::tile(1)).unwrap();
+        let event = data.events.create(Event::new);
+        add_participant(&mut data, event, ElementRef::Building(id0)).unwrap();
+        add_participant(&mut data, event, ElementRef::Building(id1)).unwrap();
+
+        assert_eq!(DeleteResult::Ok, delete_building(&mut data, id0));
+
+        assert_eq!(
+            data.events.get(event).unwrap().participants(),
+            vec![ElementRef::Building(id0)]
+        );
+    }
+
+    #[test]
+    fn test_remove_from_organizations() {
+        let mut data = RpgData::default();
+        data.town_manager
+            .create(|id| Town::simple(id, Size2d::square(2)));
+        let id0 = create_building(&mut data, BuildingLot::tile(0)).unwrap();
+        let id1 = create_building(&mut data, BuildingLot::tile(1)).unwrap();
+        let organization = data.organizations.create(Organization::new);
+        update_headquarters(&mut data, organization, Some(id0)).unwrap();
+        add_building(&mut data, organization, id1).unwrap();
+
+        assert_eq!(DeleteResult::Ok, delete_building(&mut data, id0));
+
+        let organization = data.organizations.get(organization).unwrap();
+        assert_eq!(organization.headquarters, None);
+        assert_eq!(organization.buildings, vec![id0]);
+    }
+
     #[test]
     fn test_not_found() {
         let mut data = RpgData::default();
@@ -0,0 +1,63 @@
+use crate::model::character::culture::CultureId;
+use crate::model::element::ElementRef;
+use crate::model::RpgData;
+use crate::usecase::delete::element::delete_element;
+use crate::usecase::delete::DeleteResult;
+
+/// Tries to delete a [`culture`](crate::model::character::culture::Culture).
+///
+/// It is blocked, if any [`character`](crate::model::character::Character) belongs to it.
+pub fn delete_culture(data: &mut RpgData, id: CultureId) -> DeleteResult {
+    delete_element(data, id, |data| &mut data.cultures, ElementRef::Culture)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::culture::Culture;
+    use crate::model::character::Character;
+    use crate::usecase::delete::BlockingReason;
+    use crate::utils::storage::Id;
+
+    #[test]
+    fn test_swapped_and_removed() {
+        let mut data = RpgData::default();
+        let id0 = data.cultures.create(Culture::new);
+        let id1 = data.cultures.create(Culture::new);
+        data.cultures.create(Culture::new);
+        let character = data.characters.create(Character::new);
+        data.characters.get_mut(character).unwrap().culture = CultureId::new(2);
+
+        assert_eq!(DeleteResult::Ok, delete_culture(&mut data, id1));
+
+        assert_eq!(data.cultures.len(), 2);
+        assert!(data.cultures.contains(id0));
+        assert_eq!(data.characters.get(character).unwrap().culture, id1);
+    }
+
+    #[test]
+    fn test_blocked_by_character() {
+        let mut data = RpgData::default();
+        let id = data.cultures.create(Culture::new);
+        let character = data.characters.create(Character::new);
+
+        assert_eq!(
+            DeleteResult::Blocked(BlockingReason {
+                characters: vec![character],
+                ..BlockingReason::default()
+            }),
+            delete_culture(&mut data, id)
+        );
+        assert!(data.cultures.contains(id));
+    }
+
+    #[test]
+    fn test_not_found() {
+        let mut data = RpgData::default();
+
+        assert_eq!(
+            DeleteResult::NotFound,
+            delete_culture(&mut data, CultureId::default())
+        );
+    }
+}
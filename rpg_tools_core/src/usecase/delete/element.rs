@@ -0,0 +1,186 @@
+use crate::model::element::ElementRef;
+use crate::model::RpgData;
+use crate::usecase::delete::{remove_weak_references, BlockingReason, DeleteResult};
+use crate::usecase::edit::reference::replace_references;
+use crate::usecase::get::backlink::BacklinkIndex;
+use crate::utils::storage::{DeleteElementResult, Element, Id, Storage};
+
+/// Tries to delete an element, which isn't referenced by any other element except events,
+/// organizations & addresses.
+///
+/// The references to the element, which gets the id of the deleted one, are updated.
+pub fn delete_element<I, T, S, R>(
+    data: &mut RpgData,
+    id: I,
+    get_storage: S,
+    to_ref: R,
+) -> DeleteResult
+where
+    I: Id,
+    T: Element<I>,
+    S: Fn(&mut RpgData) -> &mut Storage<I, T>,
+    R: Fn(I) -> ElementRef,
+{
+    if !get_storage(data).contains(id) {
+        return DeleteResult::NotFound;
+    }
+
+    let element = to_ref(id);
+    let reason = BlockingReason::new(
+        element,
+        BacklinkIndex::without_descriptions(data).get(element),
+    );
+
+    if !reason.is_empty() {
+        return DeleteResult::Blocked(reason);
+    }
+
+    remove_weak_references(data, element);
+
+    match get_storage(data).delete(id) {
+        DeleteElementResult::SwappedAndRemoved { id_to_update, .. } => {
+            replace_references(data, to_ref(id_to_update), to_ref(id))
+        }
+        DeleteElementResult::DeletedLastElement { .. } => {}
+        DeleteElementResult::NotFound => return DeleteResult::NotFound,
+    }
+
+    DeleteResult::Ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::event::Event;
+    use crate::model::math::size2d::Size2d;
+    use crate::model::organization::{Organization, OrganizationId, Relation};
+    use crate::model::world::building::address::Address;
+    use crate::model::world::building::lot::BuildingLot;
+    use crate::model::world::river::{River, RiverId};
+    use crate::model::world::street::{Street, StreetId};
+    use crate::model::world::town::terrain::Terrain;
+    use crate::model::world::town::Town;
+    use crate::usecase::create::building::create_building;
+    use crate::usecase::edit::event::add_participant;
+    use crate::usecase::edit::organization::{update_parent, update_relation};
+    use crate::usecase::edit::town::terrain::edit_terrain;
+
+    fn delete_organization(data: &mut RpgData, id: OrganizationId) -> DeleteResult {
+        delete_element(
+            data,
+            id,
+            |data| &mut data.organizations,
+            ElementRef::Organization,
+        )
+    }
+
+    fn delete_street(data: &mut RpgData, id: StreetId) -> DeleteResult {
+        delete_element(
+            data,
+            id,
+            |data| &mut data.street_manager,
+            ElementRef::Street,
+        )
+    }
+
+    fn delete_river(data: &mut RpgData, id: RiverId) -> DeleteResult {
+        delete_element(data, id, |data| &mut data.river_manager, ElementRef::River)
+    }
+
+    #[test]
+    fn test_swapped_and_removed() {
+        let mut data = RpgData::default();
+        let town = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::square(2)));
+        let id0 = data.river_manager.create(River::new);
+        let id1 = data.river_manager.create(River::new);
+        edit_terrain(&mut data, town, 1, Terrain::River { id: id1 }).unwrap();
+
+        assert_eq!(DeleteResult::Ok, delete_river(&mut data, id0));
+
+        assert_eq!(data.river_manager.len(), 1);
+        let map = &data.town_manager.get(town).unwrap().map;
+        assert_eq!(map.get_tile(1).unwrap().terrain, Terrain::River { id: id0 });
+    }
+
+    #[test]
+    fn test_blocked_by_town() {
+        let mut data = RpgData::default();
+        let town = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::square(2)));
+        let id = data.river_manager.create(River::new);
+        edit_terrain(&mut data, town, 0, Terrain::River { id }).unwrap();
+
+        assert_eq!(
+            DeleteResult::Blocked(BlockingReason {
+                towns: vec![town],
+                ..BlockingReason::default()
+            }),
+            delete_river(&mut data, id)
+        );
+        assert!(data.river_manager.contains(id));
+    }
+
+    #[test]
+    fn test_remove_from_events() {
+        let mut data = RpgData::default();
+        let street0 = data.street_manager.create(Street::new);
+        let street1 = data.street_manager.create(Street::new);
+        let event = data.events.create(Event::new);
+        let participants = [ElementRef::Street(street0), ElementRef::Street(street1)];
+
+        for participant in participants {
+            add_participant(&mut data, event, participant).unwrap();
+        }
+
+        assert_eq!(DeleteResult::Ok, delete_street(&mut data, street0));
+
+        assert_eq!(
+            data.events.get(event).unwrap().participants(),
+            vec![ElementRef::Street(street0)]
+        );
+    }
+
+    #[test]
+    fn test_remove_from_addresses() {
+        let mut data = RpgData::default();
+        let town = data.town_manager.create(Town::new);
+        let street = data.street_manager.create(Street::new);
+        let building = create_building(&mut data, BuildingLot::new(town, 0)).unwrap();
+        data.building_manager.get_mut(building).unwrap().address = Some(Address::new(street, 1));
+
+        assert_eq!(DeleteResult::Ok, delete_street(&mut data, street));
+
+        assert_eq!(data.building_manager.get(building).unwrap().address, None);
+    }
+
+    #[test]
+    fn test_remove_from_organizations() {
+        let mut data = RpgData::default();
+        let id0 = data.organizations.create(Organization::new);
+        let id1 = data.organizations.create(Organization::new);
+        let id2 = data.organizations.create(Organization::new);
+        update_parent(&mut data, id1, Some(id0)).unwrap();
+        update_relation(&mut data, id0, id2, Some(Relation::Allied)).unwrap();
+
+        assert_eq!(DeleteResult::Ok, delete_organization(&mut data, id0));
+
+        let child = data.organizations.get(id1).unwrap();
+        let moved = data.organizations.get(id0).unwrap();
+        assert_eq!(child.parent, None);
+        assert!(moved.relations.is_empty());
+        assert_eq!(data.organizations.len(), 2);
+    }
+
+    #[test]
+    fn test_not_found() {
+        let mut data = RpgData::default();
+
+        assert_eq!(
+            DeleteResult::NotFound,
+            delete_river(&mut data, RiverId::default())
+        );
+    }
+}
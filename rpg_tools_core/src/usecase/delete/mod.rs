@@ -1,6 +1,12 @@
 pub mod building;
+pub mod culture;
+pub mod element;
 
+use crate::model::character::CharacterId;
+use crate::model::element::ElementRef;
+use crate::model::world::building::BuildingId;
 use crate::model::world::town::TownId;
+use crate::model::RpgData;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum DeleteResult {
@@ -11,5 +17,66 @@ pub enum DeleteResult {
 
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct BlockingReason {
+    pub buildings: Vec<BuildingId>,
+    pub characters: Vec<CharacterId>,
     pub towns: Vec<TownId>,
 }
+
+impl BlockingReason {
+    /// Creates the reason from the elements referencing the element to delete.
+    ///
+    /// The addresses of buildings don't block the deletion of their street.
+    pub fn new(element: ElementRef, backlinks: &[ElementRef]) -> Self {
+        let mut reason = Self::default();
+        let is_street = matches!(element, ElementRef::Street(_));
+
+        for backlink in backlinks {
+            match *backlink {
+                ElementRef::Building(id) if !is_street => reason.buildings.push(id),
+                ElementRef::Character(id) => reason.characters.push(id),
+                ElementRef::Town(id) => reason.towns.push(id),
+                _ => {}
+            }
+        }
+
+        reason
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buildings.is_empty() && self.characters.is_empty() && self.towns.is_empty()
+    }
+}
+
+/// Removes a deleted element from all events, organizations, addresses, homes & workplaces,
+/// because they don't block its deletion.
+fn remove_weak_references(data: &mut RpgData, element: ElementRef) {
+    for event in data.events.get_all_mut() {
+        event.remove_participant(element);
+    }
+
+    for organization in data.organizations.get_all_mut() {
+        organization.remove_references(element);
+    }
+
+    if let ElementRef::Building(building) = element {
+        for character in data.characters.get_all_mut() {
+            if character.home == Some(building) {
+                character.home = None;
+            }
+            if character.workplace == Some(building) {
+                character.workplace = None;
+            }
+        }
+    }
+
+    if let ElementRef::Street(street) = element {
+        for building in data.building_manager.get_all_mut() {
+            if building
+                .address
+                .is_some_and(|address| address.street == street)
+            {
+                building.address = None;
+            }
+        }
+    }
+}
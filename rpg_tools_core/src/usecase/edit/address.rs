@@ -0,0 +1,328 @@
+use crate::model::world::building::address::Address;
+use crate::model::world::building::BuildingId;
+use crate::model::world::street::StreetId;
+use crate::model::world::town::construction::Construction;
+use crate::model::world::town::TownId;
+use crate::model::RpgData;
+use crate::usecase::get::address::{get_address_street, get_bordering_streets};
+use crate::usecase::get::entrance::{get_entrance_street, get_front_tile};
+use crate::utils::storage::{Element, Id};
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+
+/// How to number the houses along a street.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct HouseNumbering {
+    /// The number of the first house.
+    pub start: u32,
+    /// Starts at the end of the street, which is at the bottom or right of the town.
+    pub reverse: bool,
+    /// Alternates the numbers between both sides, so that the left side gets the start & every second number
+    /// (e.g. odd numbers) & the right side the others. Otherwise, the houses are numbered consecutively.
+    pub alternate: bool,
+}
+
+impl Default for HouseNumbering {
+    fn default() -> Self {
+        Self {
+            start: 1,
+            reverse: false,
+            alternate: true,
+        }
+    }
+}
+
+/// Assigns house numbers to all [`buildings`](crate::model::world::building::Building)
+/// of a town along each of its streets.
+///
+/// The previous addresses of the town's buildings are removed first.
+pub fn number_houses(
+    data: &mut RpgData,
+    town_id: TownId,
+    numbering: &HouseNumbering,
+) -> Result<()> {
+    let town = data
+        .town_manager
+        .get(town_id)
+        .context("Town doesn't exist")?;
+    let mut streets: Vec<StreetId> = Vec::new();
+
+    for tile in town.map.get_tiles() {
+        if let Construction::Street { id } = tile.construction {
+            if !streets.contains(&id) {
+                streets.push(id);
+            }
+        }
+    }
+
+    for building in data.building_manager.get_all_mut() {
+        if building.lot.town == town_id {
+            building.address = None;
+        }
+    }
+
+    for street in streets {
+        number_street(data, town_id, street, numbering)?;
+    }
+
+    Ok(())
+}
+
+/// Assigns house numbers to the [`buildings`](crate::model::world::building::Building),
+/// whose address is determined by the street, in one town & returns them in the order of their numbers.
+///
+/// The street runs along the longer side of its bounding box. The houses are sorted by the first tile
+/// of the street they border & their side of the street.
+pub fn number_street(
+    data: &mut RpgData,
+    town_id: TownId,
+    street: StreetId,
+    numbering: &HouseNumbering,
+) -> Result<Vec<BuildingId>> {
+    let town = data
+        .town_manager
+        .get(town_id)
+        .context("Town doesn't exist")?;
+    let size = town.map.get_size();
+    let tiles: Vec<usize> = town
+        .map
+        .get_tiles()
+        .iter()
+        .enumerate()
+        .filter(|(_, tile)| tile.construction.is_street(street))
+        .map(|(index, _)| index)
+        .collect();
+
+    if tiles.is_empty() {
+        bail!("The street isn't in the town!");
+    }
+
+    let xs = tiles.iter().map(|&tile| size.to_x(tile));
+    let ys = tiles.iter().map(|&tile| size.to_y(tile));
+    let width = xs.clone().max().unwrap() - xs.min().unwrap();
+    let height = ys.clone().max().unwrap() - ys.min().unwrap();
+    let is_horizontal = width >= height;
+    let sign = if numbering.reverse { -1 } else { 1 };
+    let direction = if is_horizontal { (sign, 0) } else { (0, sign) };
+    let mut sorted_tiles = tiles.clone();
+    sorted_tiles.sort_by_key(|&tile| {
+        let (x, y) = (size.to_x(tile), size.to_y(tile));
+        let key = if is_horizontal { (x, y) } else { (y, x) };
+        (key.0 * sign, key.1)
+    });
+    let ranks: HashMap<usize, usize> = sorted_tiles
+        .into_iter()
+        .enumerate()
+        .map(|(rank, tile)| (tile, rank))
+        .collect();
+
+    let mut houses: Vec<(usize, bool, BuildingId)> = Vec::new();
+
+    for building in data.building_manager.get_all() {
+        if building.lot.town != town_id || get_address_street(data, building) != Some(street) {
+            continue;
+        }
+
+        let mut borders: Vec<(usize, usize)> = building
+            .entrances
+            .iter()
+            .filter(|entrance| get_entrance_street(data, building, entrance) == Some(street))
+            .filter_map(|entrance| {
+                let front = get_front_tile(data, building, entrance)?;
+                let x = size.to_x(building.lot.tile) + entrance.x;
+                let y = size.to_y(building.lot.tile) + entrance.y;
+                Some((front, size.to_index(x, y)?))
+            })
+            .collect();
+
+        if borders.is_empty() {
+            borders = get_bordering_streets(data, building)
+                .into_iter()
+                .filter(|(id, _, _)| *id == street)
+                .map(|(_, street_tile, lot_tile)| (street_tile, lot_tile))
+                .collect();
+        }
+
+        if let Some((street_tile, lot_tile)) =
+            borders.into_iter().min_by_key(|(tile, _)| ranks[tile])
+        {
+            let offset_x = size.to_x(lot_tile) - size.to_x(street_tile);
+            let offset_y = size.to_y(lot_tile) - size.to_y(street_tile);
+            let is_left = direction.0 * offset_y - direction.1 * offset_x <= 0;
+
+            houses.push((ranks[&street_tile], is_left, building.id()));
+        }
+    }
+
+    houses.sort_by_key(|&(rank, is_left, id)| (rank, !is_left, id.id()));
+
+    let mut numbers = Vec::with_capacity(houses.len());
+
+    if numbering.alternate {
+        let mut left = numbering.start;
+        let mut right = numbering.start + 1;
+
+        for &(_, is_left, id) in &houses {
+            let number = if is_left { &mut left } else { &mut right };
+            numbers.push((id, *number));
+            *number += 2;
+        }
+    } else {
+        for (index, &(_, _, id)) in houses.iter().enumerate() {
+            numbers.push((id, numbering.start + index as u32));
+        }
+    }
+
+    for &(id, number) in &numbers {
+        if let Some(building) = data.building_manager.get_mut(id) {
+            building.address = Some(Address::new(street, number));
+        }
+    }
+
+    Ok(numbers.into_iter().map(|(id, _)| id).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::math::side2d::Side2d;
+    use crate::model::math::size2d::Size2d;
+    use crate::model::world::building::entrance::Entrance;
+    use crate::model::world::building::lot::BuildingLot;
+    use crate::model::world::street::Street;
+    use crate::model::world::town::Town;
+    use crate::usecase::create::building::create_building;
+    use crate::usecase::edit::town::add_street::add_street_to_tile;
+    use crate::usecase::edit::town::remove_street::remove_street_from_tile;
+
+    /// A horizontal street in the middle row with 2 houses above & 2 below it.
+    fn init() -> (RpgData, TownId, StreetId) {
+        let mut data = RpgData::default();
+        let town = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::new(3, 3)));
+        let street = data.street_manager.create(Street::new);
+
+        for tile in 3..6 {
+            add_street_to_tile(&mut data, town, tile, street).unwrap();
+        }
+
+        for tile in [0, 2, 6, 8] {
+            create_building(&mut data, BuildingLot::new(town, tile)).unwrap();
+        }
+
+        (data, town, street)
+    }
+
+    fn get_number(data: &RpgData, id: usize) -> Option<u32> {
+        data.building_manager
+            .get(BuildingId::new(id))
+            .and_then(|building| building.address)
+            .map(|address| address.number)
+    }
+
+    fn get_numbers(data: &RpgData) -> Vec<Option<u32>> {
+        (0..4).map(|id| get_number(data, id)).collect()
+    }
+
+    #[test]
+    fn number_alternating_sides() {
+        let (mut data, town, street) = init();
+
+        let ids = number_street(&mut data, town, street, &HouseNumbering::default()).unwrap();
+
+        assert_eq!(
+            ids,
+            vec![
+                BuildingId::new(0),
+                BuildingId::new(2),
+                BuildingId::new(1),
+                BuildingId::new(3)
+            ]
+        );
+        assert_eq!(get_numbers(&data), vec![Some(1), Some(3), Some(2), Some(4)]);
+    }
+
+    #[test]
+    fn number_in_reverse() {
+        let (mut data, town, street) = init();
+        let numbering = HouseNumbering {
+            start: 11,
+            reverse: true,
+            alternate: true,
+        };
+
+        number_street(&mut data, town, street, &numbering).unwrap();
+
+        assert_eq!(
+            get_numbers(&data),
+            vec![Some(14), Some(12), Some(13), Some(11)]
+        );
+    }
+
+    #[test]
+    fn number_consecutively() {
+        let (mut data, town, _street) = init();
+        let numbering = HouseNumbering {
+            alternate: false,
+            ..HouseNumbering::default()
+        };
+
+        number_houses(&mut data, town, &numbering).unwrap();
+
+        assert_eq!(get_numbers(&data), vec![Some(1), Some(3), Some(2), Some(4)]);
+    }
+
+    #[test]
+    fn renumbering_removes_old_addresses() {
+        let (mut data, town, street) = init();
+        let other_town = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::new(1, 1)));
+        let other = create_building(&mut data, BuildingLot::new(other_town, 0)).unwrap();
+        data.building_manager.get_mut(other).unwrap().address = Some(Address::new(street, 9));
+        data.building_manager
+            .get_mut(BuildingId::new(0))
+            .unwrap()
+            .address = Some(Address::new(street, 9));
+        remove_street_from_tile(&mut data, town, 3).unwrap();
+        remove_street_from_tile(&mut data, town, 4).unwrap();
+        remove_street_from_tile(&mut data, town, 5).unwrap();
+
+        number_houses(&mut data, town, &HouseNumbering::default()).unwrap();
+
+        assert_eq!(get_numbers(&data), vec![None, None, None, None]);
+        assert_eq!(get_number(&data, other.id()), Some(9));
+    }
+
+    #[test]
+    fn entrance_chooses_tile() {
+        let (mut data, town, street) = init();
+        data.building_manager
+            .get_mut(BuildingId::new(0))
+            .unwrap()
+            .lot
+            .size = Size2d::new(2, 1);
+        data.building_manager
+            .get_mut(BuildingId::new(0))
+            .unwrap()
+            .entrances = vec![Entrance::new(1, 0, Side2d::Bottom)];
+        let numbering = HouseNumbering {
+            alternate: false,
+            ..HouseNumbering::default()
+        };
+
+        number_street(&mut data, town, street, &numbering).unwrap();
+
+        assert_eq!(get_numbers(&data), vec![Some(2), Some(3), Some(1), Some(4)]);
+    }
+
+    #[test]
+    fn street_not_in_town() {
+        let (mut data, town, _street) = init();
+        let other = data.street_manager.create(Street::new);
+
+        assert!(number_street(&mut data, town, other, &HouseNumbering::default()).is_err());
+        assert!(number_houses(&mut data, TownId::new(1), &HouseNumbering::default()).is_err());
+    }
+}
@@ -0,0 +1,66 @@
+use crate::model::world::building::BuildingId;
+use crate::model::RpgData;
+use anyhow::{Context, Result};
+
+/// Tries to update the [`type`](crate::model::world::building::types::BuildingType) of a
+/// [`building`](crate::model::world::building::Building), which must be defined by the setting.
+pub fn update_building_type(
+    data: &mut RpgData,
+    id: BuildingId,
+    building_type: Option<&str>,
+) -> Result<()> {
+    let building_type = match building_type {
+        Some(name) => Some(data.building_types.parse(name)?.name.clone()),
+        None => None,
+    };
+
+    data.building_manager
+        .get_mut(id)
+        .map(|building| building.building_type = building_type)
+        .context("Building doesn't exist")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::world::building::lot::BuildingLot;
+    use crate::model::world::building::Building;
+
+    fn init() -> (RpgData, BuildingId) {
+        let mut data = RpgData::default();
+        let id = data
+            .building_manager
+            .create(|id| Building::new(id, BuildingLot::tile(0)));
+
+        (data, id)
+    }
+
+    #[test]
+    fn update_and_remove_type() {
+        let (mut data, id) = init();
+
+        assert!(update_building_type(&mut data, id, Some("Church")).is_ok());
+        assert_eq!(
+            data.building_manager.get(id).unwrap().building_type,
+            Some("Church".to_string())
+        );
+
+        assert!(update_building_type(&mut data, id, None).is_ok());
+        assert_eq!(data.building_manager.get(id).unwrap().building_type, None);
+    }
+
+    #[test]
+    fn unknown_type() {
+        let (mut data, id) = init();
+
+        assert!(update_building_type(&mut data, id, Some("Spaceport")).is_err());
+        assert_eq!(data.building_manager.get(id).unwrap().building_type, None);
+    }
+
+    #[test]
+    fn non_existing_building() {
+        let mut data = RpgData::default();
+
+        assert!(update_building_type(&mut data, BuildingId::default(), Some("Shop")).is_err());
+    }
+}
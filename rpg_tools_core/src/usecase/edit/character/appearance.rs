@@ -0,0 +1,47 @@
+use crate::model::character::appearance::Appearance;
+use crate::model::character::CharacterId;
+use crate::model::RpgData;
+use anyhow::{Context, Result};
+
+/// Tries to update the [`appearance`](Appearance) of a [`character`](crate::model::character::Character).
+pub fn update_appearance(
+    data: &mut RpgData,
+    id: CharacterId,
+    appearance: Appearance,
+) -> Result<()> {
+    data.characters
+        .get_mut(id)
+        .map(|character| character.appearance = appearance)
+        .context("Character doesn't exist")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::appearance::{Beard, HairStyle};
+    use crate::model::character::Character;
+
+    #[test]
+    fn success() {
+        let mut data = RpgData::default();
+        let id = data.characters.create(Character::new);
+        let appearance = Appearance {
+            hair_style: HairStyle::Bald,
+            beard: Beard::Full,
+            ..Appearance::default()
+        };
+
+        assert!(update_appearance(&mut data, id, appearance).is_ok());
+
+        assert_eq!(data.characters.get(id).unwrap().appearance, appearance);
+    }
+
+    #[test]
+    fn non_existing_character() {
+        let mut data = RpgData::default();
+
+        assert!(
+            update_appearance(&mut data, CharacterId::default(), Appearance::default()).is_err()
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::model::character::{Character, CharacterId};
+use crate::model::world::building::BuildingId;
+use crate::model::RpgData;
+use crate::utils::storage::Id;
+use anyhow::{bail, Context, Result};
+
+/// Tries to update the home of a [`character`](Character).
+pub fn update_home(data: &mut RpgData, id: CharacterId, home: Option<BuildingId>) -> Result<()> {
+    update_building(data, id, home, |character| &mut character.home)
+}
+
+/// Tries to update the workplace of a [`character`](Character).
+pub fn update_workplace(
+    data: &mut RpgData,
+    id: CharacterId,
+    workplace: Option<BuildingId>,
+) -> Result<()> {
+    update_building(data, id, workplace, |character| &mut character.workplace)
+}
+
+fn update_building<F: FnOnce(&mut Character) -> &mut Option<BuildingId>>(
+    data: &mut RpgData,
+    id: CharacterId,
+    building: Option<BuildingId>,
+    get_field: F,
+) -> Result<()> {
+    if let Some(building) = building {
+        if !data.building_manager.contains(building) {
+            bail!("Unknown building id {}!", building.id());
+        }
+    }
+
+    data.characters
+        .get_mut(id)
+        .map(|character| *get_field(character) = building)
+        .context("Character doesn't exist")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::world::building::lot::BuildingLot;
+    use crate::model::world::town::Town;
+    use crate::usecase::create::building::create_building;
+
+    fn init() -> (RpgData, CharacterId, BuildingId) {
+        let mut data = RpgData::default();
+        data.town_manager.create(Town::new);
+        let building = create_building(&mut data, BuildingLot::tile(0)).unwrap();
+        let character = data.characters.create(Character::new);
+
+        (data, character, building)
+    }
+
+    #[test]
+    fn update_home_and_workplace() {
+        let (mut data, character, building) = init();
+
+        assert!(update_home(&mut data, character, Some(building)).is_ok());
+        assert!(update_workplace(&mut data, character, Some(building)).is_ok());
+
+        let result = data.characters.get(character).unwrap();
+        assert_eq!(result.home, Some(building));
+        assert_eq!(result.workplace, Some(building));
+
+        assert!(update_home(&mut data, character, None).is_ok());
+        assert_eq!(data.characters.get(character).unwrap().home, None);
+    }
+
+    #[test]
+    fn unknown_building() {
+        let (mut data, character, _) = init();
+
+        assert!(update_home(&mut data, character, Some(BuildingId::new(1))).is_err());
+        assert!(update_workplace(&mut data, character, Some(BuildingId::new(1))).is_err());
+    }
+
+    #[test]
+    fn unknown_character() {
+        let (mut data, _, building) = init();
+
+        assert!(update_home(&mut data, CharacterId::new(1), Some(building)).is_err());
+    }
+}
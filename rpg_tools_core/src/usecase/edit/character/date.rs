@@ -0,0 +1,93 @@
+use crate::model::character::CharacterId;
+use crate::model::time::date::Date;
+use crate::model::RpgData;
+use anyhow::{bail, Context, Result};
+
+/// Tries to update the birth & death dates of a
+/// [`character`](crate::model::character::Character).
+///
+/// They must exist in the calendar & the death can't be before the birth.
+pub fn update_life_dates(
+    data: &mut RpgData,
+    id: CharacterId,
+    birth: Option<Date>,
+    death: Option<Date>,
+) -> Result<()> {
+    for date in birth.iter().chain(death.iter()) {
+        data.time.calendar.validate(date)?;
+    }
+
+    if let (Some(birth), Some(death)) = (birth, death) {
+        if death < birth {
+            bail!("The death date is before the birth date!");
+        }
+    }
+
+    data.characters
+        .get_mut(id)
+        .map(|character| {
+            character.birth_date = birth;
+            character.death_date = death;
+        })
+        .context("Character doesn't exist")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::Character;
+    use crate::utils::storage::Id;
+
+    fn init() -> (RpgData, CharacterId) {
+        let mut data = RpgData::default();
+        let id = data.characters.create(Character::new);
+
+        (data, id)
+    }
+
+    #[test]
+    fn success() {
+        let (mut data, id) = init();
+        let birth = Some(Date::new(1855, 10, 9));
+        let death = Some(Date::new(1931, 4, 1));
+
+        assert!(update_life_dates(&mut data, id, birth, death).is_ok());
+
+        let character = data.characters.get(id).unwrap();
+        assert_eq!(character.birth_date, birth);
+        assert_eq!(character.death_date, death);
+
+        assert!(update_life_dates(&mut data, id, None, None).is_ok());
+
+        let character = data.characters.get(id).unwrap();
+        assert_eq!(character.birth_date, None);
+        assert_eq!(character.death_date, None);
+    }
+
+    #[test]
+    fn death_before_birth() {
+        let (mut data, id) = init();
+        let birth = Some(Date::new(1855, 10, 9));
+        let death = Some(Date::new(1855, 10, 8));
+
+        assert!(update_life_dates(&mut data, id, birth, death).is_err());
+        assert!(update_life_dates(&mut data, id, birth, birth).is_ok());
+    }
+
+    #[test]
+    fn invalid_date() {
+        let (mut data, id) = init();
+
+        assert!(update_life_dates(&mut data, id, Some(Date::new(1855, 2, 29)), None).is_err());
+        assert!(update_life_dates(&mut data, id, None, Some(Date::new(1855, 13, 1))).is_err());
+
+        assert_eq!(data.characters.get(id).unwrap().birth_date, None);
+    }
+
+    #[test]
+    fn non_existing_character() {
+        let (mut data, _) = init();
+
+        assert!(update_life_dates(&mut data, CharacterId::new(1), None, None).is_err());
+    }
+}
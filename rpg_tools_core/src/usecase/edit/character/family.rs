@@ -0,0 +1,266 @@
+use crate::model::character::family::MAX_PARENTS;
+use crate::model::character::CharacterId;
+use crate::model::RpgData;
+use crate::usecase::edit::name::derive::sync_derived_lastnames;
+use crate::usecase::get::family::is_ancestor;
+use anyhow::{bail, Context, Result};
+
+/// Tries to add a parent to a [`character`](crate::model::character::Character).
+///
+/// Fails, if it would create a cycle in the family tree
+/// or if the derived last names can't be updated.
+pub fn add_parent(data: &mut RpgData, id: CharacterId, parent: CharacterId) -> Result<()> {
+    if id == parent {
+        bail!("A character can't be its own parent!");
+    } else if !data.characters.contains(parent) {
+        bail!("Parent doesn't exist!");
+    } else if is_ancestor(data, parent, id) {
+        bail!("A character can't be the parent of its ancestor!");
+    }
+
+    let character = data
+        .characters
+        .get_mut(id)
+        .context("Character doesn't exist")?;
+
+    if character.family.is_parent(parent) {
+        bail!("Character already has this parent!");
+    } else if character.family.parents().len() >= MAX_PARENTS {
+        bail!("Character already has {} parents!", MAX_PARENTS);
+    }
+
+    character.family.parents_mut().push(parent);
+
+    if let Err(e) = sync_derived_lastnames(data) {
+        if let Some(character) = data.characters.get_mut(id) {
+            character.family.parents_mut().retain(|id| *id != parent);
+        }
+
+        return Err(e);
+    }
+
+    Ok(())
+}
+
+/// Tries to remove a parent from a [`character`](crate::model::character::Character).
+pub fn remove_parent(data: &mut RpgData, id: CharacterId, parent: CharacterId) -> Result<()> {
+    let parents = data
+        .characters
+        .get_mut(id)
+        .context("Character doesn't exist")?
+        .family
+        .parents_mut();
+
+    if !parents.contains(&parent) {
+        bail!("Character doesn't have this parent!");
+    }
+
+    parents.retain(|id| *id != parent);
+
+    Ok(())
+}
+
+/// Tries to marry 2 [`characters`](crate::model::character::Character).
+pub fn add_spouse(data: &mut RpgData, id: CharacterId, spouse: CharacterId) -> Result<()> {
+    if id == spouse {
+        bail!("A character can't marry itself!");
+    } else if !data.characters.contains(id) {
+        bail!("Character doesn't exist!");
+    }
+
+    let character = data
+        .characters
+        .get_mut(spouse)
+        .context("Spouse doesn't exist")?;
+
+    if character.family.is_spouse(id) {
+        bail!("Characters are already married!");
+    }
+
+    character.family.spouses_mut().push(id);
+
+    if let Some(character) = data.characters.get_mut(id) {
+        character.family.spouses_mut().push(spouse);
+    }
+
+    Ok(())
+}
+
+/// Tries to remove the marriage of 2 [`characters`](crate::model::character::Character).
+pub fn remove_spouse(data: &mut RpgData, id: CharacterId, spouse: CharacterId) -> Result<()> {
+    if !data
+        .characters
+        .get(id)
+        .map(|character| character.family.is_spouse(spouse))
+        .unwrap_or(false)
+    {
+        bail!("Characters are not married!");
+    }
+
+    for (a, b) in [(id, spouse), (spouse, id)] {
+        if let Some(character) = data.characters.get_mut(a) {
+            character.family.spouses_mut().retain(|id| *id != b);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::name::Lastname;
+    use crate::model::character::Character;
+    use crate::model::name::Name;
+    use crate::utils::storage::Id;
+
+    fn init(count: usize) -> (RpgData, Vec<CharacterId>) {
+        let mut data = RpgData::default();
+        let ids = (0..count)
+            .map(|_| data.characters.create(Character::new))
+            .collect();
+        (data, ids)
+    }
+
+    #[test]
+    fn test_add_parent() {
+        let (mut data, ids) = init(2);
+
+        assert!(add_parent(&mut data, ids[0], ids[1]).is_ok());
+
+        assert_eq!(
+            data.characters.get(ids[0]).unwrap().family.parents(),
+            &[ids[1]]
+        );
+    }
+
+    #[test]
+    fn test_add_parent_with_unknown_gender() {
+        let (mut data, ids) = init(2);
+        let name = Name::new("Test").unwrap();
+        let child = data.characters.get_mut(ids[0]).unwrap();
+        child.name.set_last(Lastname::Patronymic { name });
+        data.characters.get_mut(ids[1]).unwrap().gender = "Unknown".to_string();
+
+        assert!(add_parent(&mut data, ids[0], ids[1]).is_err());
+
+        assert!(data
+            .characters
+            .get(ids[0])
+            .unwrap()
+            .family
+            .parents()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_add_parent_to_itself() {
+        let (mut data, ids) = init(1);
+
+        assert!(add_parent(&mut data, ids[0], ids[0]).is_err());
+    }
+
+    #[test]
+    fn test_add_unknown_parent() {
+        let (mut data, ids) = init(1);
+
+        assert!(add_parent(&mut data, ids[0], CharacterId::new(5)).is_err());
+        assert!(add_parent(&mut data, CharacterId::new(5), ids[0]).is_err());
+    }
+
+    #[test]
+    fn test_add_parent_twice() {
+        let (mut data, ids) = init(2);
+
+        assert!(add_parent(&mut data, ids[0], ids[1]).is_ok());
+        assert!(add_parent(&mut data, ids[0], ids[1]).is_err());
+    }
+
+    #[test]
+    fn test_add_too_many_parents() {
+        let (mut data, ids) = init(4);
+
+        assert!(add_parent(&mut data, ids[0], ids[1]).is_ok());
+        assert!(add_parent(&mut data, ids[0], ids[2]).is_ok());
+        assert!(add_parent(&mut data, ids[0], ids[3]).is_err());
+    }
+
+    #[test]
+    fn test_add_parent_with_cycle() {
+        let (mut data, ids) = init(3);
+
+        assert!(add_parent(&mut data, ids[0], ids[1]).is_ok());
+        assert!(add_parent(&mut data, ids[1], ids[2]).is_ok());
+        assert!(add_parent(&mut data, ids[2], ids[0]).is_err());
+        assert!(add_parent(&mut data, ids[1], ids[0]).is_err());
+    }
+
+    #[test]
+    fn test_remove_parent() {
+        let (mut data, ids) = init(2);
+        add_parent(&mut data, ids[0], ids[1]).unwrap();
+
+        assert!(remove_parent(&mut data, ids[0], ids[1]).is_ok());
+        assert!(remove_parent(&mut data, ids[0], ids[1]).is_err());
+
+        assert!(data
+            .characters
+            .get(ids[0])
+            .unwrap()
+            .family
+            .parents()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_add_spouse() {
+        let (mut data, ids) = init(2);
+
+        assert!(add_spouse(&mut data, ids[0], ids[1]).is_ok());
+        assert!(add_spouse(&mut data, ids[1], ids[0]).is_err());
+
+        assert!(data
+            .characters
+            .get(ids[0])
+            .unwrap()
+            .family
+            .is_spouse(ids[1]));
+        assert!(data
+            .characters
+            .get(ids[1])
+            .unwrap()
+            .family
+            .is_spouse(ids[0]));
+    }
+
+    #[test]
+    fn test_add_spouse_to_itself() {
+        let (mut data, ids) = init(1);
+
+        assert!(add_spouse(&mut data, ids[0], ids[0]).is_err());
+    }
+
+    #[test]
+    fn test_remove_spouse() {
+        let (mut data, ids) = init(2);
+        add_spouse(&mut data, ids[0], ids[1]).unwrap();
+
+        assert!(remove_spouse(&mut data, ids[1], ids[0]).is_ok());
+        assert!(remove_spouse(&mut data, ids[1], ids[0]).is_err());
+
+        assert!(data
+            .characters
+            .get(ids[0])
+            .unwrap()
+            .family
+            .spouses()
+            .is_empty());
+        assert!(data
+            .characters
+            .get(ids[1])
+            .unwrap()
+            .family
+            .spouses()
+            .is_empty());
+    }
+}
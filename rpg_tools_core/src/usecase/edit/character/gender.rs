@@ -1,19 +1,25 @@
-use crate::model::character::gender::Gender;
 use crate::model::character::CharacterId;
 use crate::model::RpgData;
+use crate::usecase::edit::name::derive::sync_derived_lastnames;
 use anyhow::{Context, Result};
 
-/// Tries to update the [`gender`](Gender) of an [`character`](crate::model::character::Character).
-pub fn update_gender(data: &mut RpgData, id: CharacterId, gender: Gender) -> Result<()> {
+/// Tries to update the [`gender`](crate::model::character::gender::GenderDefinition) of an
+/// [`character`](crate::model::character::Character), which must be defined by the setting.
+pub fn update_gender(data: &mut RpgData, id: CharacterId, gender: &str) -> Result<()> {
+    let gender = data.genders.parse(gender)?.name.clone();
+
     data.characters
         .get_mut(id)
         .map(|character| character.gender = gender)
-        .context("Character doesn't exist")
+        .context("Character doesn't exist")?;
+
+    sync_derived_lastnames(data)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::character::gender::{Gender, GenderDefinition, Pronouns};
     use crate::model::character::Character;
     use crate::model::RpgData;
 
@@ -22,16 +28,42 @@ mod tests {
         let mut data = RpgData::default();
         let id = data.characters.create(Character::new);
 
-        assert!(update_gender(&mut data, id, Gender::Genderless).is_ok());
+        assert!(update_gender(&mut data, id, "Genderless").is_ok());
+
+        assert_eq!(data.characters.get(id).unwrap().gender, "Genderless");
+    }
+
+    #[test]
+    fn setting_defined_gender() {
+        let mut data = RpgData::default();
+        data.genders.genders.push(GenderDefinition::new(
+            "Nonbinary",
+            Gender::Genderless,
+            Pronouns::new("xe", "xem", "xyr"),
+        ));
+        let id = data.characters.create(Character::new);
+
+        assert!(update_gender(&mut data, id, "Nonbinary").is_ok());
+
+        assert_eq!(data.characters.get(id).unwrap().gender, "Nonbinary");
+    }
+
+    #[test]
+    fn unknown_gender() {
+        let mut data = RpgData::default();
+        let id = data.characters.create(Character::new);
+
+        assert!(update_gender(&mut data, id, "Unknown").is_err());
+        assert!(update_gender(&mut data, id, "male").is_err());
 
-        assert_eq!(data.characters.get(id).unwrap().gender, Gender::Genderless);
+        assert_eq!(data.characters.get(id).unwrap().gender, "Female");
     }
 
     #[test]
     fn non_existing_character() {
         let mut data = RpgData::default();
 
-        assert!(update_gender(&mut data, CharacterId::default(), Gender::Female).is_err());
+        assert!(update_gender(&mut data, CharacterId::default(), "Female").is_err());
 
         assert!(data.characters.is_empty());
     }
@@ -1 +1,6 @@
+pub mod appearance;
+pub mod building;
+pub mod date;
+pub mod family;
 pub mod gender;
+pub mod stats;
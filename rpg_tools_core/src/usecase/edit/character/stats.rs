@@ -0,0 +1,70 @@
+use crate::model::character::CharacterId;
+use crate::model::stats::StatBlock;
+use crate::model::RpgData;
+use anyhow::{Context, Result};
+
+/// Tries to update the [`statistics`](StatBlock) of a
+/// [`character`](crate::model::character::Character), which must be valid for the
+/// [`schema`](crate::model::stats::StatSchema) of the setting.
+pub fn update_stats(data: &mut RpgData, id: CharacterId, stats: StatBlock) -> Result<()> {
+    data.stats.validate(&stats)?;
+
+    data.characters
+        .get_mut(id)
+        .map(|character| character.stats = stats)
+        .context("Character doesn't exist")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::Character;
+    use crate::model::stats::AttributeDefinition;
+    use crate::utils::storage::Id;
+
+    fn init() -> (RpgData, CharacterId) {
+        let mut data = RpgData::default();
+        data.stats.attributes.push(AttributeDefinition {
+            name: "STR".to_string(),
+            min: 15,
+            max: 90,
+            default: 50,
+        });
+        let id = data.characters.create(Character::new);
+
+        (data, id)
+    }
+
+    fn create_block(name: &str, value: i32) -> StatBlock {
+        let mut stats = StatBlock::default();
+        stats.attributes.insert(name.to_string(), value);
+        stats
+    }
+
+    #[test]
+    fn success() {
+        let (mut data, id) = init();
+        let stats = create_block("STR", 60);
+
+        assert!(update_stats(&mut data, id, stats.clone()).is_ok());
+
+        assert_eq!(data.characters.get(id).unwrap().stats, stats);
+    }
+
+    #[test]
+    fn invalid_stats() {
+        let (mut data, id) = init();
+
+        assert!(update_stats(&mut data, id, create_block("STR", 95)).is_err());
+        assert!(update_stats(&mut data, id, create_block("POW", 60)).is_err());
+
+        assert!(data.characters.get(id).unwrap().stats.is_empty());
+    }
+
+    #[test]
+    fn non_existing_character() {
+        let (mut data, _) = init();
+
+        assert!(update_stats(&mut data, CharacterId::new(1), create_block("STR", 60)).is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use crate::model::description::WithDescription;
+use crate::utils::storage::{Element, Id, Storage};
+use anyhow::{Context, Result};
+
+/// Tries to update the description of an [`element`](Element).
+///
+/// An empty description removes it.
+pub fn update_description<ID: Id, ELEMENT: Element<ID> + WithDescription>(
+    storage: &mut Storage<ID, ELEMENT>,
+    id: ID,
+    description: &str,
+) -> Result<()> {
+    let trimmed = description.trim();
+    let description = if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.replace("\r\n", "\n"))
+    };
+
+    storage
+        .get_mut(id)
+        .map(|element| element.set_description(description))
+        .context("Id doesn't exist")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::world::river::{River, RiverId};
+
+    #[test]
+    fn update_valid_description() {
+        let mut storage: Storage<RiverId, River> = Storage::default();
+        let id = storage.create(River::new);
+
+        assert!(update_description(&mut storage, id, " A *river*.\r\n").is_ok());
+
+        assert_eq!(storage.get(id).unwrap().description(), Some("A *river*."));
+    }
+
+    #[test]
+    fn remove_description() {
+        let mut storage: Storage<RiverId, River> = Storage::default();
+        let id = storage.create(River::new);
+        update_description(&mut storage, id, "Text").unwrap();
+
+        assert!(update_description(&mut storage, id, "  ").is_ok());
+
+        assert_eq!(storage.get(id).unwrap().description(), None);
+    }
+
+    #[test]
+    fn update_description_of_unknown_river() {
+        let mut storage: Storage<RiverId, River> = Storage::default();
+
+        assert!(update_description(&mut storage, RiverId::new(0), "Text").is_err());
+    }
+}
@@ -0,0 +1,75 @@
+use crate::model::world::building::entrance::Entrance;
+use crate::model::world::building::{Building, BuildingId};
+use crate::model::RpgData;
+use anyhow::{bail, Context, Result};
+
+/// Tries to add an [`entrance`](Entrance) to a [`building`](Building),
+/// which must be on the outline of its lot.
+pub fn add_entrance(data: &mut RpgData, id: BuildingId, entrance: Entrance) -> Result<()> {
+    let building = get_mut(data, id)?;
+
+    if !building.lot.is_valid_entrance(&entrance) {
+        bail!("The entrance is not on the outline of the lot!");
+    } else if building.entrances.contains(&entrance) {
+        bail!("The entrance already exists!");
+    }
+
+    building.entrances.push(entrance);
+
+    Ok(())
+}
+
+/// Tries to remove an [`entrance`](Entrance) of a [`building`](Building).
+pub fn remove_entrance(data: &mut RpgData, id: BuildingId, index: usize) -> Result<()> {
+    let building = get_mut(data, id)?;
+
+    if index >= building.entrances.len() {
+        bail!("Entrance {} doesn't exist!", index);
+    }
+
+    building.entrances.remove(index);
+
+    Ok(())
+}
+
+fn get_mut(data: &mut RpgData, id: BuildingId) -> Result<&mut Building> {
+    data.building_manager
+        .get_mut(id)
+        .context("Building doesn't exist")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::math::side2d::Side2d;
+    use crate::model::math::size2d::Size2d;
+    use crate::model::world::building::lot::BuildingLot;
+    use crate::model::world::town::TownId;
+    use crate::utils::storage::Id;
+
+    #[test]
+    fn add_and_remove_entrances() {
+        let mut data = RpgData::default();
+        let lot = BuildingLot::big(TownId::default(), 0, Size2d::new(2, 1));
+        let id = data
+            .building_manager
+            .create(|id| Building::new(id, lot.clone()));
+        let entrance = Entrance::new(1, 0, Side2d::Right);
+
+        assert!(add_entrance(&mut data, id, entrance).is_ok());
+        assert!(add_entrance(&mut data, id, entrance).is_err());
+        assert!(add_entrance(&mut data, id, Entrance::new(0, 0, Side2d::Right)).is_err());
+        assert!(add_entrance(&mut data, id, Entrance::new(2, 0, Side2d::Left)).is_err());
+        assert!(add_entrance(&mut data, BuildingId::new(1), entrance).is_err());
+
+        assert_eq!(
+            data.building_manager.get(id).unwrap().entrances,
+            vec![entrance]
+        );
+
+        assert!(remove_entrance(&mut data, id, 1).is_err());
+        assert!(remove_entrance(&mut data, id, 0).is_ok());
+
+        assert!(data.building_manager.get(id).unwrap().entrances.is_empty());
+    }
+}
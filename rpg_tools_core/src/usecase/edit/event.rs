@@ -0,0 +1,108 @@
+use crate::model::element::ElementRef;
+use crate::model::event::EventId;
+use crate::model::time::date::Date;
+use crate::model::RpgData;
+use crate::usecase::get::name::get_element_name;
+use anyhow::{bail, Context, Result};
+
+/// Tries to update the date of an [`event`](crate::model::event::Event),
+/// which must exist in the calendar.
+pub fn update_event_date(data: &mut RpgData, id: EventId, date: Date) -> Result<()> {
+    data.time.calendar.validate(&date)?;
+
+    data.events
+        .get_mut(id)
+        .map(|event| event.date = date)
+        .context("Event doesn't exist")
+}
+
+/// Tries to add an existing character, building, street or town to an
+/// [`event`](crate::model::event::Event).
+pub fn add_participant(data: &mut RpgData, id: EventId, participant: ElementRef) -> Result<()> {
+    if get_element_name(data, participant).is_none() {
+        bail!("The {} doesn't exist!", participant);
+    }
+
+    data.events
+        .get_mut(id)
+        .context("Event doesn't exist")?
+        .add_participant(participant)
+}
+
+/// Tries to remove a participant from an [`event`](crate::model::event::Event).
+pub fn remove_participant(data: &mut RpgData, id: EventId, participant: ElementRef) -> Result<()> {
+    let event = data.events.get_mut(id).context("Event doesn't exist")?;
+
+    if !event.remove_participant(participant) {
+        bail!("The {} doesn't participate in the event!", participant);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::culture::{Culture, CultureId};
+    use crate::model::character::{Character, CharacterId};
+    use crate::model::event::Event;
+    use crate::utils::storage::Id;
+
+    fn init() -> (RpgData, EventId, ElementRef) {
+        let mut data = RpgData::default();
+        let id = data.events.create(Event::new);
+        let character = data.characters.create(Character::new);
+
+        (data, id, ElementRef::Character(character))
+    }
+
+    #[test]
+    fn update_date() {
+        let (mut data, id, _) = init();
+        let date = Date::new(1925, 3, 14);
+
+        assert!(update_event_date(&mut data, id, date).is_ok());
+        assert_eq!(data.events.get(id).unwrap().date, date);
+    }
+
+    #[test]
+    fn update_invalid_date() {
+        let (mut data, id, _) = init();
+
+        assert!(update_event_date(&mut data, id, Date::new(1925, 2, 30)).is_err());
+        assert!(update_event_date(&mut data, EventId::new(1), Date::new(1925, 3, 14)).is_err());
+    }
+
+    #[test]
+    fn add_and_remove() {
+        let (mut data, id, character) = init();
+
+        assert!(add_participant(&mut data, id, character).is_ok());
+        assert_eq!(data.events.get(id).unwrap().participants(), vec![character]);
+
+        assert!(remove_participant(&mut data, id, character).is_ok());
+        assert!(remove_participant(&mut data, id, character).is_err());
+        assert!(data.events.get(id).unwrap().participants().is_empty());
+    }
+
+    #[test]
+    fn add_invalid_participant() {
+        let (mut data, id, _) = init();
+        data.cultures.create(Culture::new);
+
+        let unknown = ElementRef::Character(CharacterId::new(1));
+        let culture = ElementRef::Culture(CultureId::new(0));
+
+        assert!(add_participant(&mut data, id, unknown).is_err());
+        assert!(add_participant(&mut data, id, culture).is_err());
+        assert!(data.events.get(id).unwrap().participants().is_empty());
+    }
+
+    #[test]
+    fn non_existing_event() {
+        let (mut data, _, character) = init();
+
+        assert!(add_participant(&mut data, EventId::new(1), character).is_err());
+        assert!(remove_participant(&mut data, EventId::new(1), character).is_err());
+    }
+}
@@ -0,0 +1,148 @@
+use crate::model::world::building::interior::{Floor, InteriorTile};
+use crate::model::world::building::{Building, BuildingId};
+use crate::model::RpgData;
+use anyhow::{bail, Context, Result};
+
+/// Adds a new top floor, which fits the lot, to a [`building`](Building)
+/// & returns its index.
+pub fn add_floor(data: &mut RpgData, id: BuildingId) -> Result<usize> {
+    let building = get_mut(data, id)?;
+    let index = building.floors.len();
+    let floor = Floor::new(format!("Floor {}", index), building.lot.size);
+
+    building.floors.push(floor);
+
+    Ok(index)
+}
+
+/// Tries to remove a floor of a [`building`](Building).
+pub fn remove_floor(data: &mut RpgData, id: BuildingId, floor: usize) -> Result<()> {
+    let building = get_mut(data, id)?;
+
+    if floor >= building.floors.len() {
+        bail!("Floor {} doesn't exist!", floor);
+    }
+
+    building.floors.remove(floor);
+
+    Ok(())
+}
+
+/// Tries to rename a floor of a [`building`](Building).
+pub fn update_floor_name(
+    data: &mut RpgData,
+    id: BuildingId,
+    floor: usize,
+    name: &str,
+) -> Result<()> {
+    let name = name.trim();
+
+    if name.is_empty() {
+        bail!("Name is empty!");
+    }
+
+    get_floor_mut(data, id, floor)?.name = name.to_string();
+
+    Ok(())
+}
+
+/// Tries to change a tile of the floor plan of a [`building`](Building).
+pub fn edit_interior(
+    data: &mut RpgData,
+    id: BuildingId,
+    floor: usize,
+    index: usize,
+    tile: InteriorTile,
+) -> Result<()> {
+    get_floor_mut(data, id, floor)?
+        .map
+        .get_tile_mut(index)
+        .map(|old| *old = tile)
+        .context(format!("Tile {} is outside the floor!", index))
+}
+
+fn get_mut(data: &mut RpgData, id: BuildingId) -> Result<&mut Building> {
+    data.building_manager
+        .get_mut(id)
+        .context("Building doesn't exist")
+}
+
+fn get_floor_mut(data: &mut RpgData, id: BuildingId, floor: usize) -> Result<&mut Floor> {
+    get_mut(data, id)?
+        .floors
+        .get_mut(floor)
+        .context(format!("Floor {} doesn't exist!", floor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::math::size2d::Size2d;
+    use crate::model::world::building::lot::BuildingLot;
+
+    fn init() -> (RpgData, BuildingId) {
+        let mut data = RpgData::default();
+        let id = data
+            .building_manager
+            .create(|id| Building::new(id, BuildingLot::tile(0)));
+
+        (data, id)
+    }
+
+    fn get_floors(data: &RpgData, id: BuildingId) -> &[Floor] {
+        &data.building_manager.get(id).unwrap().floors
+    }
+
+    #[test]
+    fn add_and_remove_floors() {
+        let (mut data, id) = init();
+
+        assert_eq!(add_floor(&mut data, id).unwrap(), 0);
+        assert_eq!(add_floor(&mut data, id).unwrap(), 1);
+
+        assert_eq!(
+            get_floors(&data, id),
+            &[
+                Floor::new("Floor 0", Size2d::square(1)),
+                Floor::new("Floor 1", Size2d::square(1))
+            ]
+        );
+
+        assert!(remove_floor(&mut data, id, 0).is_ok());
+        assert!(remove_floor(&mut data, id, 1).is_err());
+
+        assert_eq!(get_floors(&data, id)[0].name, "Floor 1");
+    }
+
+    #[test]
+    fn add_floor_to_unknown_building() {
+        let mut data = RpgData::default();
+
+        assert!(add_floor(&mut data, BuildingId::default()).is_err());
+    }
+
+    #[test]
+    fn rename_floor() {
+        let (mut data, id) = init();
+        add_floor(&mut data, id).unwrap();
+
+        assert!(update_floor_name(&mut data, id, 0, " Cellar ").is_ok());
+        assert!(update_floor_name(&mut data, id, 0, " ").is_err());
+        assert!(update_floor_name(&mut data, id, 1, "Attic").is_err());
+
+        assert_eq!(get_floors(&data, id)[0].name, "Cellar");
+    }
+
+    #[test]
+    fn edit_tiles() {
+        let (mut data, id) = init();
+        add_floor(&mut data, id).unwrap();
+
+        assert!(edit_interior(&mut data, id, 0, 5, InteriorTile::Stairs).is_ok());
+        assert!(edit_interior(&mut data, id, 0, 16, InteriorTile::Door).is_err());
+        assert!(edit_interior(&mut data, id, 1, 5, InteriorTile::Door).is_err());
+
+        let map = &get_floors(&data, id)[0].map;
+        assert_eq!(map.get_tile(5), Some(&InteriorTile::Stairs));
+    }
+}
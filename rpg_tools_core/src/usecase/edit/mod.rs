@@ -1,4 +1,16 @@
+pub mod address;
+pub mod building;
 pub mod character;
+pub mod description;
+pub mod entrance;
+pub mod event;
+pub mod interior;
 pub mod name;
+pub mod naming;
+pub mod organization;
+pub mod reference;
 pub mod resize;
+pub mod subdivide;
+pub mod time;
 pub mod town;
+pub mod visibility;
@@ -1,6 +1,7 @@
 use crate::model::character::name::CharacterName;
 use crate::model::character::CharacterId;
 use crate::model::RpgData;
+use crate::usecase::edit::name::derive::sync_derived_lastnames;
 use crate::utils::storage::Element;
 use anyhow::{bail, Context, Result};
 
@@ -23,5 +24,7 @@ pub fn update_character_name(
     data.characters
         .get_mut(id)
         .map(|r| r.name = name)
-        .context("Id doesn't exist")
+        .context("Id doesn't exist")?;
+
+    sync_derived_lastnames(data)
 }
@@ -0,0 +1,211 @@
+use crate::model::character::culture::CultureId;
+use crate::model::character::derivation::DerivationRule;
+use crate::model::character::gender::GenderMap;
+use crate::model::character::name::Lastname;
+use crate::model::character::CharacterId;
+use crate::model::RpgData;
+use crate::usecase::get::family::{get_father, get_mother};
+use crate::utils::storage::Element;
+use anyhow::{Context, Result};
+
+/// Returns the last name of a [`character`](crate::model::character::Character) derived from its parent.
+///
+/// Only a patronymic or matronymic can be derived & only if the father or mother is known.
+/// Fails, if the gender of the character or a parent is unknown.
+pub fn derive_lastname(data: &RpgData, id: CharacterId) -> Result<Option<Lastname>> {
+    let Some(character) = data.characters.get(id) else {
+        return Ok(None);
+    };
+    let culture = data.cultures.get(character.culture);
+
+    Ok(match character.name.last() {
+        Lastname::Patronymic { .. } => {
+            let Some(father) = get_father(data, id)?.and_then(|id| data.characters.get(id)) else {
+                return Ok(None);
+            };
+            let gender = data.genders.get_base(&character.gender)?;
+            let name = culture
+                .map(|culture| culture.patronymic.get(gender))
+                .unwrap_or(&DerivationRule::default())
+                .derive(father.name.first());
+            Some(Lastname::Patronymic { name })
+        }
+        Lastname::Matronymic { .. } => {
+            let Some(mother) = get_mother(data, id)?.and_then(|id| data.characters.get(id)) else {
+                return Ok(None);
+            };
+            let gender = data.genders.get_base(&character.gender)?;
+            let name = culture
+                .map(|culture| culture.matronymic.get(gender))
+                .unwrap_or(&DerivationRule::default())
+                .derive(mother.name.first());
+            Some(Lastname::Matronymic { name })
+        }
+        _ => None,
+    })
+}
+
+/// Updates the derived last names of all [`characters`](crate::model::character::Character).
+///
+/// Needs to be called after a change of names, genders, parents or rules.
+/// Fails without changes, if the gender of a character is unknown.
+pub fn sync_derived_lastnames(data: &mut RpgData) -> Result<()> {
+    let mut updates: Vec<(CharacterId, Lastname)> = Vec::new();
+
+    for character in data.characters.get_all() {
+        if let Some(lastname) = derive_lastname(data, character.id())? {
+            updates.push((character.id(), lastname));
+        }
+    }
+
+    for (id, lastname) in updates {
+        if let Some(character) = data.characters.get_mut(id) {
+            character.name.set_last(lastname);
+        }
+    }
+
+    Ok(())
+}
+
+/// Tries to update the rules of a [`culture`](crate::model::character::culture::Culture)
+/// to derive last names.
+pub fn update_derivation_rules(
+    data: &mut RpgData,
+    id: CultureId,
+    patronymic: GenderMap<DerivationRule>,
+    matronymic: GenderMap<DerivationRule>,
+) -> Result<()> {
+    let culture = data.cultures.get_mut(id).context("Culture doesn't exist")?;
+
+    culture.patronymic = patronymic;
+    culture.matronymic = matronymic;
+
+    sync_derived_lastnames(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::culture::Culture;
+    use crate::model::character::gender::Gender;
+    use crate::model::character::name::CharacterName;
+    use crate::model::character::Character;
+    use crate::model::name::Name;
+    use crate::usecase::edit::character::family::{add_parent, remove_parent};
+    use crate::usecase::edit::name::character::update_character_name;
+
+    fn create_character(data: &mut RpgData, first: &str, gender: Gender) -> CharacterId {
+        let id = data.characters.create(Character::new);
+        let character = data.characters.get_mut(id).unwrap();
+        character.name = CharacterName::only_first(Name::new(first).unwrap());
+        character.gender = gender.to_string();
+        id
+    }
+
+    fn set_lastname(data: &mut RpgData, id: CharacterId, lastname: Lastname) {
+        data.characters.get_mut(id).unwrap().name.set_last(lastname);
+    }
+
+    fn get_lastname(data: &RpgData, id: CharacterId) -> &str {
+        data.characters
+            .get(id)
+            .unwrap()
+            .name
+            .last()
+            .name()
+            .unwrap()
+            .str()
+    }
+
+    fn init() -> (RpgData, CharacterId, CharacterId, CharacterId) {
+        let mut data = RpgData::default();
+        let culture = data.cultures.create(Culture::new);
+        update_derivation_rules(
+            &mut data,
+            culture,
+            GenderMap::new(
+                DerivationRule::new("", "sdottir"),
+                DerivationRule::new("", "sbarn"),
+                DerivationRule::new("", "sson"),
+            ),
+            GenderMap::new(
+                DerivationRule::new("", "sdottir"),
+                DerivationRule::default(),
+                DerivationRule::new("", "sson"),
+            ),
+        )
+        .unwrap();
+        let father = create_character(&mut data, "Magnus", Gender::Male);
+        let mother = create_character(&mut data, "Helga", Gender::Female);
+        let child = create_character(&mut data, "Astrid", Gender::Female);
+        let name = Name::new("Unknown").unwrap();
+        set_lastname(&mut data, child, Lastname::Patronymic { name });
+
+        (data, father, mother, child)
+    }
+
+    #[test]
+    fn derive_patronymic() {
+        let (mut data, father, mother, child) = init();
+
+        add_parent(&mut data, child, mother).unwrap();
+        assert_eq!(get_lastname(&data, child), "Unknown");
+
+        add_parent(&mut data, child, father).unwrap();
+        assert_eq!(get_lastname(&data, child), "Magnussdottir");
+    }
+
+    #[test]
+    fn derive_matronymic() {
+        let (mut data, _father, mother, child) = init();
+        let name = Name::new("Unknown").unwrap();
+        set_lastname(&mut data, child, Lastname::Matronymic { name });
+
+        add_parent(&mut data, child, mother).unwrap();
+
+        assert_eq!(get_lastname(&data, child), "Helgasdottir");
+    }
+
+    #[test]
+    fn sync_after_renaming_parent() {
+        let (mut data, father, _mother, child) = init();
+        add_parent(&mut data, child, father).unwrap();
+
+        let name = CharacterName::only_first(Name::new("Olaf").unwrap());
+        update_character_name(&mut data, father, name).unwrap();
+
+        assert_eq!(get_lastname(&data, child), "Olafsdottir");
+    }
+
+    #[test]
+    fn keep_name_after_removing_parent() {
+        let (mut data, father, _mother, child) = init();
+        add_parent(&mut data, child, father).unwrap();
+
+        remove_parent(&mut data, child, father).unwrap();
+
+        assert_eq!(get_lastname(&data, child), "Magnussdottir");
+    }
+
+    #[test]
+    fn ignore_family_names() {
+        let (mut data, father, _mother, child) = init();
+        let name = Name::new("Smith").unwrap();
+        set_lastname(&mut data, child, Lastname::Family { name });
+
+        add_parent(&mut data, child, father).unwrap();
+
+        assert_eq!(get_lastname(&data, child), "Smith");
+    }
+
+    #[test]
+    fn unknown_gender_of_parent() {
+        let (mut data, father, _mother, child) = init();
+        add_parent(&mut data, child, father).unwrap();
+        data.characters.get_mut(father).unwrap().gender = "Unknown".to_string();
+
+        assert!(derive_lastname(&data, child).is_err());
+        assert!(sync_derived_lastnames(&mut data).is_err());
+        assert_eq!(get_lastname(&data, child), "Magnussdottir");
+    }
+}
@@ -0,0 +1,265 @@
+use crate::model::character::culture::{Culture, CultureId};
+use crate::model::character::gender::{Gender, GenderMap};
+use crate::model::character::generator::{NameGenerator, NameSampler};
+use crate::model::character::name::{CharacterName, Lastname};
+use crate::model::character::CharacterId;
+use crate::model::name::{Name, WithName};
+use crate::model::RpgData;
+use crate::usecase::edit::name::character::update_character_name;
+use anyhow::{bail, Context, Result};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+const MAX_ATTEMPTS: usize = 20;
+
+/// Tries to update the [`name generators`](NameGenerator) of a
+/// [`culture`](crate::model::character::culture::Culture).
+pub fn update_name_generators(
+    data: &mut RpgData,
+    id: CultureId,
+    first_names: GenderMap<NameGenerator>,
+    family_names: NameGenerator,
+) -> Result<()> {
+    let culture = data.cultures.get_mut(id).context("Culture doesn't exist")?;
+
+    culture.first_names = first_names;
+    culture.family_names = family_names;
+
+    Ok(())
+}
+
+/// The [`name samplers`](NameSampler) of a [`culture`](Culture), which are prepared once
+/// to generate many names.
+pub struct CultureNames<'a> {
+    pub culture: &'a Culture,
+    first_names: GenderMap<NameSampler<'a>>,
+    family_names: NameSampler<'a>,
+}
+
+impl<'a> CultureNames<'a> {
+    pub fn new(culture: &'a Culture) -> Self {
+        Self {
+            culture,
+            first_names: GenderMap::new(
+                culture.first_names.female.sampler(),
+                culture.first_names.genderless.sampler(),
+                culture.first_names.male.sampler(),
+            ),
+            family_names: culture.family_names.sampler(),
+        }
+    }
+
+    /// Generates a random first name for a character of the culture & gender.
+    pub fn generate_first_name<R: Rng>(&self, gender: Gender, rng: &mut R) -> Result<Name> {
+        let first_names = self.first_names.get(gender);
+
+        if first_names.is_none() {
+            bail!(
+                "Culture '{}' has no first names for {}!",
+                self.culture.name(),
+                gender
+            );
+        }
+
+        first_names
+            .generate(rng)
+            .context("Failed to generate a first name!")
+    }
+
+    /// Generates a random family name, if the culture has any.
+    pub fn generate_family_name<R: Rng>(&self, rng: &mut R) -> Option<Name> {
+        self.family_names.generate(rng)
+    }
+}
+
+/// Generates a random [`name`](CharacterName) for a character of a culture & gender,
+/// which isn't used by another character yet.
+///
+/// A derived *lastname* is kept & updated by [`sync_derived_lastnames()`](crate::usecase::edit::name::derive::sync_derived_lastnames), while
+/// otherwise a family name is generated.
+pub fn generate_name<R: Rng>(
+    data: &RpgData,
+    culture: CultureId,
+    gender: Gender,
+    lastname: &Lastname,
+    rng: &mut R,
+) -> Result<CharacterName> {
+    let culture = data
+        .cultures
+        .get(culture)
+        .context("Culture doesn't exist")?;
+    let names = CultureNames::new(culture);
+
+    for _ in 0..MAX_ATTEMPTS {
+        let first = names.generate_first_name(gender, rng)?;
+        let last = match lastname {
+            Lastname::Patronymic { .. } | Lastname::Matronymic { .. } => lastname.clone(),
+            _ => names
+                .generate_family_name(rng)
+                .map(|name| Lastname::Family { name })
+                .unwrap_or_default(),
+        };
+        let name = CharacterName::simple(first, last);
+
+        if !data.characters.get_all().iter().any(|c| c.name.eq(&name)) {
+            return Ok(name);
+        }
+    }
+
+    bail!("Failed to generate an unused name!")
+}
+
+/// Generates a random name for a [`character`](crate::model::character::Character) based on
+/// its culture & gender and updates it. The same seed generates the same name.
+pub fn generate_character_name(
+    data: &mut RpgData,
+    id: CharacterId,
+    seed: u64,
+) -> Result<CharacterName> {
+    let character = data.characters.get(id).context("Character doesn't exist")?;
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let name = generate_name(
+        data,
+        character.culture,
+        data.genders.get_base(&character.gender)?,
+        character.name.last(),
+        &mut rng,
+    )?;
+
+    update_character_name(data, id, name.clone())?;
+
+    Ok(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::Character;
+    use crate::utils::storage::Id;
+
+    fn init() -> (RpgData, CultureId) {
+        let mut data = RpgData::default();
+        let culture_id = data.cultures.create(Culture::new);
+        let culture = data.cultures.get_mut(culture_id).unwrap();
+        culture.first_names.male = NameGenerator::Markov {
+            examples: vec!["Aldric", "Alwin", "Baldwin", "Edric", "Edwin", "Godric"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        };
+        culture.family_names = NameGenerator::List {
+            names: vec!["Miller".to_string()],
+        };
+
+        (data, culture_id)
+    }
+
+    #[test]
+    fn generate_with_seed() {
+        let (mut data, _) = init();
+        let id0 = data.characters.create(Character::new);
+        let id1 = data.characters.create(Character::new);
+        data.characters.get_mut(id0).unwrap().gender = Gender::Male.to_string();
+        data.characters.get_mut(id1).unwrap().gender = Gender::Male.to_string();
+
+        let name0 = generate_character_name(&mut data, id0, 42).unwrap();
+
+        assert_eq!(
+            name0.last(),
+            &Lastname::Family {
+                name: Name::new("Miller").unwrap()
+            }
+        );
+        assert_eq!(data.characters.get(id0).unwrap().name, name0);
+
+        // the same seed would generate the same name, which is already used
+        let name1 = generate_character_name(&mut data, id1, 42).unwrap();
+        assert_ne!(name0, name1);
+    }
+
+    #[test]
+    fn keep_derived_lastname() {
+        let (mut data, _) = init();
+        let id = data.characters.create(Character::new);
+        let character = data.characters.get_mut(id).unwrap();
+        let patronymic = Lastname::Patronymic {
+            name: Name::new("Aldricson").unwrap(),
+        };
+        character.gender = Gender::Male.to_string();
+        character.name = CharacterName::simple(Name::new("Edwin").unwrap(), patronymic.clone());
+
+        let name = generate_character_name(&mut data, id, 42).unwrap();
+
+        assert_eq!(name.last(), &patronymic);
+    }
+
+    #[test]
+    fn same_seed_generates_same_name() {
+        let (data, culture) = init();
+        let mut rng0 = ChaCha8Rng::seed_from_u64(9);
+        let mut rng1 = ChaCha8Rng::seed_from_u64(9);
+
+        assert_eq!(
+            generate_name(&data, culture, Gender::Male, &Lastname::None, &mut rng0).unwrap(),
+            generate_name(&data, culture, Gender::Male, &Lastname::None, &mut rng1).unwrap()
+        );
+    }
+
+    #[test]
+    fn no_first_names_for_gender() {
+        let (data, culture) = init();
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+        assert!(generate_name(&data, culture, Gender::Female, &Lastname::None, &mut rng).is_err());
+    }
+
+    #[test]
+    fn unknown_culture() {
+        let data = RpgData::default();
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+        assert!(generate_name(
+            &data,
+            CultureId::new(5),
+            Gender::Male,
+            &Lastname::None,
+            &mut rng
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn all_names_used() {
+        let (mut data, culture) = init();
+        data.cultures.get_mut(culture).unwrap().first_names.female = NameGenerator::List {
+            names: vec!["Anna".to_string()],
+        };
+        let id = data.characters.create(Character::new);
+        data.characters.get_mut(id).unwrap().name = CharacterName::simple(
+            Name::new("Anna").unwrap(),
+            Lastname::Family {
+                name: Name::new("Miller").unwrap(),
+            },
+        );
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+        assert!(generate_name(&data, culture, Gender::Female, &Lastname::None, &mut rng).is_err());
+    }
+
+    #[test]
+    fn update_generators() {
+        let (mut data, culture) = init();
+
+        assert!(update_name_generators(
+            &mut data,
+            culture,
+            GenderMap::default(),
+            NameGenerator::None
+        )
+        .is_ok());
+
+        let culture = data.cultures.get(culture).unwrap();
+        assert_eq!(culture.first_names, GenderMap::default());
+        assert_eq!(culture.family_names, NameGenerator::None);
+    }
+}
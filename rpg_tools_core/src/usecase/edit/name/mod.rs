@@ -1,4 +1,6 @@
 pub mod character;
+pub mod derive;
+pub mod generate;
 
 use crate::model::name::{Name, WithName};
 use crate::utils::storage::{Element, Id, Storage};
@@ -10,6 +12,20 @@ pub fn update_name<ID: Id, ELEMENT: Element<ID> + WithName>(
     id: ID,
     name: &str,
 ) -> Result<()> {
+    let name = check_name(storage, id, name)?;
+
+    storage
+        .get_mut(id)
+        .map(|r| r.set_name(name))
+        .context("Id doesn't exist")
+}
+
+/// Checks, if the name is valid for an [`element`](Element) without updating it.
+pub fn check_name<ID: Id, ELEMENT: Element<ID> + WithName>(
+    storage: &Storage<ID, ELEMENT>,
+    id: ID,
+    name: &str,
+) -> Result<Name> {
     if let Some(name) = Name::new(name) {
         if storage
             .get_all()
@@ -20,10 +36,7 @@ pub fn update_name<ID: Id, ELEMENT: Element<ID> + WithName>(
             bail!("Name '{}' already exists!", name)
         }
 
-        storage
-            .get_mut(id)
-            .map(|r| r.set_name(name))
-            .context("Id doesn't exist")
+        Ok(name)
     } else {
         bail!("Name is empty!")
     }
@@ -35,7 +48,7 @@ mod tests {
     use crate::model::world::mountain::{Mountain, MountainId};
     use crate::utils::storage::Id;
 
-    const VALID_NAME: &'static str = "Test";
+    const VALID_NAME: &str = "Test";
 
     #[test]
     fn update_empty_name() {
@@ -74,6 +87,19 @@ mod tests {
         assert!(update_name(&mut storage, id1, VALID_NAME).is_err());
     }
 
+    #[test]
+    fn check_name_without_update() {
+        let mut storage: Storage<MountainId, Mountain> = Storage::default();
+        let id0 = storage.create(Mountain::new);
+        let id1 = storage.create(Mountain::new);
+        update_name(&mut storage, id0, VALID_NAME).unwrap();
+
+        assert_eq!(check_name(&storage, id1, " Name ").unwrap().str(), "Name");
+        assert!(check_name(&storage, id1, VALID_NAME).is_err());
+        assert!(check_name(&storage, id0, VALID_NAME).is_ok());
+        assert_eq!(storage.get(id1).unwrap().name().str(), "Mountain 1");
+    }
+
     fn test_invalid_name(name: &str) {
         let mut storage: Storage<MountainId, Mountain> = Storage::default();
         let id = storage.create(Mountain::new);
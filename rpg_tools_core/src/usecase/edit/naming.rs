@@ -0,0 +1,50 @@
+use crate::model::character::culture::CultureId;
+use crate::model::character::naming::NamingConvention;
+use crate::model::RpgData;
+use anyhow::{Context, Result};
+
+/// Tries to update the [`naming convention`](NamingConvention) of a
+/// [`culture`](crate::model::character::culture::Culture).
+pub fn update_naming_convention(
+    data: &mut RpgData,
+    id: CultureId,
+    naming: NamingConvention,
+) -> Result<()> {
+    data.cultures
+        .get_mut(id)
+        .map(|culture| culture.naming = naming)
+        .context("Culture doesn't exist")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::culture::Culture;
+    use crate::model::character::naming::NameOrder;
+
+    #[test]
+    fn success() {
+        let mut data = RpgData::default();
+        let id = data.cultures.create(Culture::new);
+        let naming = NamingConvention {
+            order: NameOrder::FamilyFirst,
+            ..NamingConvention::default()
+        };
+
+        assert!(update_naming_convention(&mut data, id, naming.clone()).is_ok());
+
+        assert_eq!(data.cultures.get(id).unwrap().naming, naming);
+    }
+
+    #[test]
+    fn non_existing_culture() {
+        let mut data = RpgData::default();
+
+        assert!(update_naming_convention(
+            &mut data,
+            CultureId::default(),
+            NamingConvention::default()
+        )
+        .is_err());
+    }
+}
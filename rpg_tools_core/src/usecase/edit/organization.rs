@@ -0,0 +1,280 @@
+use crate::model::character::CharacterId;
+use crate::model::organization::{Member, Organization, OrganizationId, Relation};
+use crate::model::world::building::BuildingId;
+use crate::model::RpgData;
+use anyhow::{bail, Context, Result};
+
+/// Adds a [`character`](crate::model::character::Character) as member to an
+/// [`organization`](Organization) or updates the role of an existing member.
+pub fn add_member(
+    data: &mut RpgData,
+    id: OrganizationId,
+    character: CharacterId,
+    role: &str,
+) -> Result<()> {
+    if !data.characters.contains(character) {
+        bail!("Character doesn't exist");
+    }
+
+    let organization = get_mut(data, id)?;
+    let role = role.trim();
+
+    match organization
+        .members
+        .iter_mut()
+        .find(|member| member.character == character)
+    {
+        Some(member) => member.role = role.to_string(),
+        None => organization.members.push(Member::new(character, role)),
+    }
+
+    Ok(())
+}
+
+/// Removes a member from an [`organization`](Organization).
+pub fn remove_member(data: &mut RpgData, id: OrganizationId, character: CharacterId) -> Result<()> {
+    let organization = get_mut(data, id)?;
+    let count = organization.members.len();
+
+    organization
+        .members
+        .retain(|member| member.character != character);
+
+    if organization.members.len() == count {
+        bail!("The character isn't a member of the organization!");
+    }
+
+    Ok(())
+}
+
+/// Tries to update the headquarters of an [`organization`](Organization).
+pub fn update_headquarters(
+    data: &mut RpgData,
+    id: OrganizationId,
+    building: Option<BuildingId>,
+) -> Result<()> {
+    if let Some(building) = building {
+        if !data.building_manager.contains(building) {
+            bail!("Building doesn't exist");
+        }
+    }
+
+    get_mut(data, id)?.headquarters = building;
+
+    Ok(())
+}
+
+/// Adds a building to the ones owned by an [`organization`](Organization).
+pub fn add_building(data: &mut RpgData, id: OrganizationId, building: BuildingId) -> Result<()> {
+    if !data.building_manager.contains(building) {
+        bail!("Building doesn't exist");
+    }
+
+    let organization = get_mut(data, id)?;
+
+    if !organization.buildings.contains(&building) {
+        organization.buildings.push(building);
+    }
+
+    Ok(())
+}
+
+/// Removes a building from the ones owned by an [`organization`](Organization).
+pub fn remove_building(data: &mut RpgData, id: OrganizationId, building: BuildingId) -> Result<()> {
+    let organization = get_mut(data, id)?;
+
+    if !organization.buildings.contains(&building) {
+        bail!("The building isn't owned by the organization!");
+    }
+
+    organization.buildings.retain(|owned| *owned != building);
+
+    Ok(())
+}
+
+/// Tries to update the parent of an [`organization`](Organization),
+/// which must not be the organization itself or one of its descendants.
+pub fn update_parent(
+    data: &mut RpgData,
+    id: OrganizationId,
+    parent: Option<OrganizationId>,
+) -> Result<()> {
+    if let Some(parent) = parent {
+        if !data.organizations.contains(parent) {
+            bail!("Parent doesn't exist");
+        }
+
+        let mut ancestor = Some(parent);
+
+        while let Some(current) = ancestor {
+            if current == id {
+                bail!("An organization can't be its own ancestor!");
+            }
+
+            ancestor = data.organizations.get(current).and_then(|o| o.parent);
+        }
+    }
+
+    get_mut(data, id)?.parent = parent;
+
+    Ok(())
+}
+
+/// Sets or removes the relation between 2 [`organizations`](Organization) in both directions.
+pub fn update_relation(
+    data: &mut RpgData,
+    id: OrganizationId,
+    other: OrganizationId,
+    relation: Option<Relation>,
+) -> Result<()> {
+    if id == other {
+        bail!("An organization can't have a relation with itself!");
+    } else if !data.organizations.contains(id) {
+        bail!("Organization doesn't exist");
+    } else if !data.organizations.contains(other) {
+        bail!("Other organization doesn't exist");
+    }
+
+    for (source, target) in [(id, other), (other, id)] {
+        let relations = &mut get_mut(data, source)?.relations;
+
+        match relation {
+            Some(relation) => relations.insert(target, relation),
+            None => relations.remove(&target),
+        };
+    }
+
+    Ok(())
+}
+
+fn get_mut(data: &mut RpgData, id: OrganizationId) -> Result<&mut Organization> {
+    data.organizations
+        .get_mut(id)
+        .context("Organization doesn't exist")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::Character;
+    use crate::model::world::building::lot::BuildingLot;
+    use crate::model::world::town::Town;
+    use crate::usecase::create::building::create_building;
+    use crate::utils::storage::Id;
+
+    fn init() -> (RpgData, OrganizationId, OrganizationId) {
+        let mut data = RpgData::default();
+        let id0 = data.organizations.create(Organization::new);
+        let id1 = data.organizations.create(Organization::new);
+
+        (data, id0, id1)
+    }
+
+    fn get(data: &RpgData, id: OrganizationId) -> &Organization {
+        data.organizations.get(id).unwrap()
+    }
+
+    #[test]
+    fn add_and_update_member() {
+        let (mut data, id, _) = init();
+        let character = data.characters.create(Character::new);
+
+        assert!(add_member(&mut data, id, character, "Member").is_ok());
+        assert!(add_member(&mut data, id, character, " Leader ").is_ok());
+
+        assert_eq!(
+            get(&data, id).members,
+            vec![Member::new(character, "Leader")]
+        );
+    }
+
+    #[test]
+    fn add_unknown_member() {
+        let (mut data, id, _) = init();
+
+        assert!(add_member(&mut data, id, CharacterId::default(), "").is_err());
+        assert!(get(&data, id).members.is_empty());
+    }
+
+    #[test]
+    fn remove_members() {
+        let (mut data, id, _) = init();
+        let character = data.characters.create(Character::new);
+        add_member(&mut data, id, character, "").unwrap();
+
+        assert!(remove_member(&mut data, id, character).is_ok());
+        assert!(remove_member(&mut data, id, character).is_err());
+        assert!(get(&data, id).members.is_empty());
+    }
+
+    #[test]
+    fn headquarters_and_buildings() {
+        let (mut data, id, _) = init();
+        data.town_manager.create(Town::new);
+        let building = create_building(&mut data, BuildingLot::tile(0)).unwrap();
+
+        assert!(update_headquarters(&mut data, id, Some(building)).is_ok());
+        assert!(add_building(&mut data, id, building).is_ok());
+        assert!(add_building(&mut data, id, building).is_ok());
+        assert!(update_headquarters(&mut data, id, Some(BuildingId::new(5))).is_err());
+        assert!(add_building(&mut data, id, BuildingId::new(5)).is_err());
+
+        assert_eq!(get(&data, id).headquarters, Some(building));
+        assert_eq!(get(&data, id).buildings, vec![building]);
+
+        assert!(remove_building(&mut data, id, building).is_ok());
+        assert!(remove_building(&mut data, id, building).is_err());
+        assert!(update_headquarters(&mut data, id, None).is_ok());
+
+        assert_eq!(get(&data, id).get_all_buildings(), vec![]);
+    }
+
+    #[test]
+    fn update_parents() {
+        let (mut data, id0, id1) = init();
+        let id2 = data.organizations.create(Organization::new);
+
+        assert!(update_parent(&mut data, id1, Some(id0)).is_ok());
+        assert!(update_parent(&mut data, id2, Some(id1)).is_ok());
+
+        assert!(update_parent(&mut data, id0, Some(id0)).is_err());
+        assert!(update_parent(&mut data, id0, Some(id2)).is_err());
+        assert!(update_parent(&mut data, id0, Some(OrganizationId::new(9))).is_err());
+        assert_eq!(get(&data, id0).parent, None);
+
+        assert!(update_parent(&mut data, id2, None).is_ok());
+        assert_eq!(get(&data, id2).parent, None);
+    }
+
+    #[test]
+    fn update_relations_in_both_directions() {
+        let (mut data, id0, id1) = init();
+
+        assert!(update_relation(&mut data, id0, id1, Some(Relation::Hostile)).is_ok());
+
+        assert_eq!(
+            get(&data, id0).relations.get(&id1),
+            Some(&Relation::Hostile)
+        );
+        assert_eq!(
+            get(&data, id1).relations.get(&id0),
+            Some(&Relation::Hostile)
+        );
+
+        assert!(update_relation(&mut data, id1, id0, None).is_ok());
+
+        assert!(get(&data, id0).relations.is_empty());
+        assert!(get(&data, id1).relations.is_empty());
+    }
+
+    #[test]
+    fn invalid_relations() {
+        let (mut data, id0, _) = init();
+        let unknown = OrganizationId::new(5);
+
+        assert!(update_relation(&mut data, id0, id0, Some(Relation::Allied)).is_err());
+        assert!(update_relation(&mut data, id0, unknown, Some(Relation::Allied)).is_err());
+        assert!(update_relation(&mut data, unknown, id0, Some(Relation::Allied)).is_err());
+        assert!(get(&data, id0).relations.is_empty());
+    }
+}
@@ -0,0 +1,164 @@
+use crate::model::element::ElementRef;
+use crate::model::world::town::construction::Construction;
+use crate::model::world::town::terrain::Terrain;
+use crate::model::RpgData;
+
+/// Replaces all references to an element with references to another element of the same type.
+///
+/// This is needed, if an element changes its id.
+pub fn replace_references(data: &mut RpgData, old: ElementRef, new: ElementRef) {
+    for event in data.events.get_all_mut() {
+        event.replace_participant(old, new);
+    }
+
+    for organization in data.organizations.get_all_mut() {
+        organization.replace_references(old, new);
+    }
+
+    match (old, new) {
+        (ElementRef::Building(old), ElementRef::Building(new)) => {
+            replace_constructions(
+                data,
+                Construction::Building { id: old },
+                Construction::Building { id: new },
+            );
+
+            for character in data.characters.get_all_mut() {
+                if character.home == Some(old) {
+                    character.home = Some(new);
+                }
+                if character.workplace == Some(old) {
+                    character.workplace = Some(new);
+                }
+            }
+        }
+        (ElementRef::Character(old), ElementRef::Character(new)) => {
+            for character in data.characters.get_all_mut() {
+                character.family.replace(old, new);
+            }
+        }
+        (ElementRef::Culture(old), ElementRef::Culture(new)) => {
+            for character in data.characters.get_all_mut() {
+                if character.culture == old {
+                    character.culture = new;
+                }
+            }
+        }
+        (ElementRef::Mountain(old), ElementRef::Mountain(new)) => {
+            replace_terrain(data, |terrain| match terrain {
+                Terrain::Hill { id } if id == old => Some(Terrain::Hill { id: new }),
+                Terrain::Mountain { id } if id == old => Some(Terrain::Mountain { id: new }),
+                _ => None,
+            });
+        }
+        (ElementRef::River(old), ElementRef::River(new)) => {
+            replace_terrain(data, |terrain| match terrain {
+                Terrain::River { id } if id == old => Some(Terrain::River { id: new }),
+                _ => None,
+            });
+        }
+        (ElementRef::Street(old), ElementRef::Street(new)) => {
+            replace_constructions(
+                data,
+                Construction::Street { id: old },
+                Construction::Street { id: new },
+            );
+
+            for building in data.building_manager.get_all_mut() {
+                if let Some(address) = &mut building.address {
+                    if address.street == old {
+                        address.street = new;
+                    }
+                }
+            }
+        }
+        (ElementRef::Town(old), ElementRef::Town(new)) => {
+            for building in data.building_manager.get_all_mut() {
+                if building.lot.town == old {
+                    building.lot.town = new;
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn replace_constructions(data: &mut RpgData, old: Construction, new: Construction) {
+    for town in data.town_manager.get_all_mut() {
+        for index in 0..town.map.get_size().tiles() {
+            if let Some(tile) = town.map.get_tile_mut(index) {
+                if tile.construction == old {
+                    tile.construction = new.clone();
+                }
+            }
+        }
+    }
+}
+
+fn replace_terrain<F: Fn(Terrain) -> Option<Terrain>>(data: &mut RpgData, f: F) {
+    for town in data.town_manager.get_all_mut() {
+        for index in 0..town.map.get_size().tiles() {
+            if let Some(tile) = town.map.get_tile_mut(index) {
+                if let Some(terrain) = f(tile.terrain) {
+                    tile.terrain = terrain;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::math::size2d::Size2d;
+    use crate::model::world::building::address::Address;
+    use crate::model::world::building::lot::BuildingLot;
+    use crate::model::world::building::Building;
+    use crate::model::world::mountain::{Mountain, MountainId};
+    use crate::model::world::street::StreetId;
+    use crate::model::world::town::Town;
+    use crate::usecase::edit::town::terrain::edit_terrain;
+    use crate::utils::storage::Id;
+
+    #[test]
+    fn replace_mountain() {
+        let mut data = RpgData::default();
+        let town = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::square(2)));
+        let mountain = data.mountain_manager.create(Mountain::new);
+        let new = MountainId::new(1);
+        edit_terrain(&mut data, town, 1, Terrain::Mountain { id: mountain }).unwrap();
+
+        replace_references(
+            &mut data,
+            ElementRef::Mountain(mountain),
+            ElementRef::Mountain(new),
+        );
+
+        let map = &data.town_manager.get(town).unwrap().map;
+        assert_eq!(map.get_tile(0).unwrap().terrain, Terrain::Plain);
+        assert_eq!(
+            map.get_tile(1).unwrap().terrain,
+            Terrain::Mountain { id: new }
+        );
+    }
+
+    #[test]
+    fn replace_street_of_address() {
+        let mut data = RpgData::default();
+        let old = StreetId::new(0);
+        let new = StreetId::new(1);
+        let id = data
+            .building_manager
+            .create(|id| Building::new(id, BuildingLot::tile(0)));
+        data.building_manager.get_mut(id).unwrap().address = Some(Address::new(old, 3));
+
+        replace_references(&mut data, ElementRef::Street(old), ElementRef::Street(new));
+
+        assert_eq!(
+            data.building_manager.get(id).unwrap().address,
+            Some(Address::new(new, 3))
+        );
+    }
+}
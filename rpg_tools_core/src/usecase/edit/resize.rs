@@ -1,4 +1,6 @@
 use crate::model::math::size2d::Size2d;
+use crate::model::world::building::entrance::Entrance;
+use crate::model::world::building::interior::Floor;
 use crate::model::world::building::lot::BuildingLot;
 use crate::model::world::building::BuildingId;
 use crate::model::world::town::construction::Construction;
@@ -20,52 +22,165 @@ pub fn resize_town(data: &mut RpgData, id: TownId, width: u32, height: u32) -> R
         .context("Town doesn't exist")
 }
 
+/// Tries to resize the bounding box of the lot of a [`building`](crate::model::world::building::Building).
+/// New tiles become part of the lot, which must stay connected.
 pub fn resize_building(
     data: &mut RpgData,
     building_id: BuildingId,
     width: u32,
     height: u32,
 ) -> Result<()> {
-    let lot = data
-        .building_manager
+    let lot = get_lot(data, building_id)?;
+    let new_lot = lot.resize(Size2d::new(width, height));
+    let size = new_lot.size;
+
+    if !new_lot.is_connected() {
+        bail!("The resized lot would be split!");
+    }
+
+    update_lot(
+        data,
+        building_id,
+        &lot,
+        new_lot,
+        |floor| floor.resize(size),
+        |_| {},
+    )
+}
+
+/// Tries to change the tiles of the lot of a [`building`](crate::model::world::building::Building),
+/// which must be connected & free or already part of the lot.
+pub fn update_lot_tiles(
+    data: &mut RpgData,
+    building_id: BuildingId,
+    tiles: &[usize],
+) -> Result<()> {
+    let lot = get_lot(data, building_id)?;
+    let town_size = data
+        .town_manager
+        .get(lot.town)
+        .map(|town| town.map.get_size())
+        .context("Town doesn't exist")?;
+    let new_lot = BuildingLot::from_tiles(lot.town, town_size, tiles)?;
+    let offset_x = town_size.to_x(lot.tile) - town_size.to_x(new_lot.tile);
+    let offset_y = town_size.to_y(lot.tile) - town_size.to_y(new_lot.tile);
+    let size = new_lot.size;
+
+    update_lot(
+        data,
+        building_id,
+        &lot,
+        new_lot,
+        |floor| floor.reshape(offset_x, offset_y, size),
+        |entrance| entrance.shift(offset_x, offset_y),
+    )
+}
+
+/// Tries to move a [`building`](crate::model::world::building::Building) to another tile
+/// of the same or another town, which becomes the top left tile of its lot.
+pub fn move_building(
+    data: &mut RpgData,
+    building_id: BuildingId,
+    town: TownId,
+    tile: usize,
+) -> Result<()> {
+    let lot = get_lot(data, building_id)?;
+    let new_lot = lot.relocate(town, tile);
+
+    update_lot(data, building_id, &lot, new_lot, |_| {}, |_| {})
+}
+
+/// Tries to rotate a [`building`](crate::model::world::building::Building) & its floors clockwise
+/// by 90° around the top left tile of its lot.
+pub fn rotate_building(data: &mut RpgData, building_id: BuildingId) -> Result<()> {
+    let lot = get_lot(data, building_id)?;
+    let new_lot = lot.rotate();
+    let size = lot.size;
+
+    update_lot(
+        data,
+        building_id,
+        &lot,
+        new_lot,
+        Floor::rotate,
+        |entrance| entrance.rotate(size),
+    )
+}
+
+fn get_lot(data: &RpgData, building_id: BuildingId) -> Result<BuildingLot> {
+    data.building_manager
         .get(building_id)
         .map(|building| building.lot.clone())
-        .context("Building doesn't exist")?;
+        .context("Building doesn't exist")
+}
 
-    if let Some(town) = data.town_manager.get_mut(lot.town) {
-        let new_lot = BuildingLot {
-            size: Size2d::new(width, height),
-            ..lot
-        };
-        if town.can_update_building(&new_lot, building_id) {
-            if !town.set_lot_construction(&lot, Construction::None) {
-                panic!("Couldn't clear lot")
-            }
-            if !town.set_lot_construction(&new_lot, Construction::Building { id: building_id }) {
-                panic!("Couldn't update lot")
-            }
-
-            if let Some(building) = data.building_manager.get_mut(building_id) {
-                building.lot = new_lot.clone();
-            }
-        } else {
-            bail!("Resize impossible");
+/// Moves the [`building`](crate::model::world::building::Building) to a new lot,
+/// which can be in another town, & updates its floors & entrances.
+/// Entrances, which are no longer on the outline of the lot, are removed.
+/// The address is removed, because it depends on the lot.
+fn update_lot<F: FnMut(&mut Floor), G: FnMut(&mut Entrance)>(
+    data: &mut RpgData,
+    building_id: BuildingId,
+    lot: &BuildingLot,
+    new_lot: BuildingLot,
+    update_floor: F,
+    update_entrance: G,
+) -> Result<()> {
+    let construction = Construction::Building { id: building_id };
+
+    if let Some(town) = data.town_manager.get(new_lot.town) {
+        if !town.can_update_building(&new_lot, building_id) {
+            bail!("The new lot is outside the town or occupied!");
         }
     } else {
         bail!("Town doesn't exist")
     }
 
+    if let Some(town) = data.town_manager.get_mut(lot.town) {
+        if !town.set_lot_construction(lot, Construction::None) {
+            panic!("Couldn't clear lot")
+        }
+    }
+
+    if let Some(town) = data.town_manager.get_mut(new_lot.town) {
+        if !town.set_lot_construction(&new_lot, construction) {
+            panic!("Couldn't update lot")
+        }
+    }
+
+    if let Some(building) = data.building_manager.get_mut(building_id) {
+        building.floors.iter_mut().for_each(update_floor);
+        building.entrances.iter_mut().for_each(update_entrance);
+        building
+            .entrances
+            .retain(|entrance| new_lot.is_valid_entrance(entrance));
+
+        if building.lot != new_lot {
+            building.address = None;
+        }
+
+        building.lot = new_lot;
+    }
+
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::math::side2d::Side2d;
+    use crate::model::world::building::address::Address;
+    use crate::model::world::building::interior::{get_interior_size, InteriorTile};
     use crate::model::world::building::Building;
+    use crate::model::world::street::StreetId;
     use crate::model::world::town::{Town, TownId};
     use crate::model::RpgData;
     use crate::usecase::create::building::create_building;
+    use crate::usecase::delete::building::delete_building;
+    use crate::usecase::delete::DeleteResult;
+    use crate::usecase::edit::interior::add_floor;
     use crate::usecase::get::town::get_constructions;
+    use crate::utils::storage::Id;
 
     #[test]
     fn resize_non_existing_town() {
@@ -106,6 +221,296 @@ mod tests {
         )
     }
 
+    #[test]
+    fn resize_building_with_floors() {
+        let mut data = RpgData::default();
+        let town_id = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::new(3, 2)));
+        let building_id = create_building(&mut data, BuildingLot::new(town_id, 0)).unwrap();
+        add_floor(&mut data, building_id).unwrap();
+
+        assert!(resize_building(&mut data, building_id, 2, 1).is_ok());
+
+        let floor = &data.building_manager.get(building_id).unwrap().floors[0];
+        assert_eq!(floor.map.get_size(), get_interior_size(Size2d::new(2, 1)));
+    }
+
+    #[test]
+    fn resize_shaped_building() {
+        let mut data = RpgData::default();
+        let town_id = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::new(3, 2)));
+        let building_id = create_building(&mut data, BuildingLot::new(town_id, 0)).unwrap();
+        let construction = Construction::Building { id: building_id };
+        update_lot_tiles(&mut data, building_id, &[0, 3, 4]).unwrap();
+
+        assert!(resize_building(&mut data, building_id, 3, 2).is_ok());
+
+        assert_eq!(
+            get_constructions(&data, town_id),
+            vec![
+                &construction,
+                &Construction::None,
+                &construction,
+                &construction,
+                &construction,
+                &construction
+            ]
+        );
+    }
+
+    #[test]
+    fn resize_would_split_lot() {
+        let mut data = RpgData::default();
+        let town_id = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::new(3, 2)));
+        let building_id = create_building(&mut data, BuildingLot::new(town_id, 0)).unwrap();
+        update_lot_tiles(&mut data, building_id, &[0, 3, 4, 5, 2]).unwrap();
+
+        assert!(resize_building(&mut data, building_id, 3, 1).is_err());
+        assert!(resize_building(&mut data, building_id, 2, 2).is_ok());
+    }
+
+    #[test]
+    fn update_lot_to_l_shape() {
+        let mut data = RpgData::default();
+        let town_id = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::new(3, 2)));
+        let building_id = create_building(&mut data, BuildingLot::new(town_id, 1)).unwrap();
+        let construction = Construction::Building { id: building_id };
+        add_floor(&mut data, building_id).unwrap();
+
+        assert!(update_lot_tiles(&mut data, building_id, &[1, 3, 4]).is_ok());
+
+        let building = data.building_manager.get(building_id).unwrap();
+        assert_eq!(building.lot.tile, 0);
+        assert_eq!(building.lot.mask, vec![false, true, true, true]);
+        assert_eq!(
+            building.floors[0].map.get_tile(4),
+            Some(&InteriorTile::Wall)
+        );
+        assert_eq!(
+            building.floors[0].map.get_tile(0),
+            Some(&InteriorTile::Floor)
+        );
+        assert_eq!(
+            get_constructions(&data, town_id),
+            vec![
+                &Construction::None,
+                &construction,
+                &Construction::None,
+                &construction,
+                &construction,
+                &Construction::None
+            ]
+        );
+    }
+
+    #[test]
+    fn update_lot_with_invalid_tiles() {
+        let mut data = RpgData::default();
+        let town_id = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::new(3, 2)));
+        let building_id = create_building(&mut data, BuildingLot::new(town_id, 0)).unwrap();
+        let other_id = create_building(&mut data, BuildingLot::new(town_id, 1)).unwrap();
+
+        assert!(update_lot_tiles(&mut data, building_id, &[]).is_err());
+        assert!(update_lot_tiles(&mut data, building_id, &[0, 2]).is_err());
+        assert!(update_lot_tiles(&mut data, building_id, &[0, 1]).is_err());
+        assert!(update_lot_tiles(&mut data, building_id, &[0, 6]).is_err());
+        assert!(update_lot_tiles(&mut data, other_id, &[1, 2]).is_ok());
+    }
+
+    #[test]
+    fn move_building_inside_town() {
+        let mut data = RpgData::default();
+        let town_id = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::new(3, 2)));
+        let lot = BuildingLot::big(town_id, 0, Size2d::new(2, 1));
+        let building_id = create_building(&mut data, lot).unwrap();
+        let construction = Construction::Building { id: building_id };
+
+        assert!(move_building(&mut data, building_id, town_id, 4).is_ok());
+
+        assert_eq!(
+            data.building_manager.get(building_id).unwrap().lot,
+            BuildingLot::big(town_id, 4, Size2d::new(2, 1))
+        );
+        assert_eq!(
+            get_constructions(&data, town_id),
+            vec![
+                &Construction::None,
+                &Construction::None,
+                &Construction::None,
+                &Construction::None,
+                &construction,
+                &construction
+            ]
+        );
+    }
+
+    #[test]
+    fn move_building_removes_address() {
+        let mut data = RpgData::default();
+        let town_id = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::new(3, 2)));
+        let building_id = create_building(&mut data, BuildingLot::new(town_id, 0)).unwrap();
+        data.building_manager.get_mut(building_id).unwrap().address =
+            Some(Address::new(StreetId::default(), 7));
+
+        assert!(move_building(&mut data, building_id, town_id, 0).is_ok());
+        assert!(data
+            .building_manager
+            .get(building_id)
+            .unwrap()
+            .address
+            .is_some());
+
+        assert!(move_building(&mut data, building_id, town_id, 4).is_ok());
+        assert_eq!(
+            data.building_manager.get(building_id).unwrap().address,
+            None
+        );
+    }
+
+    #[test]
+    fn move_building_overlapping_itself() {
+        let mut data = RpgData::default();
+        let town_id = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::new(3, 1)));
+        let lot = BuildingLot::big(town_id, 0, Size2d::new(2, 1));
+        let building_id = create_building(&mut data, lot).unwrap();
+
+        assert!(move_building(&mut data, building_id, town_id, 1).is_ok());
+        assert!(move_building(&mut data, building_id, town_id, 2).is_err());
+
+        assert_eq!(data.building_manager.get(building_id).unwrap().lot.tile, 1);
+        assert!(get_constructions(&data, town_id)[0] == &Construction::None);
+    }
+
+    #[test]
+    fn move_building_to_other_town() {
+        let mut data = RpgData::default();
+        let town0 = data.town_manager.create(Town::new);
+        let town1 = data.town_manager.create(Town::new);
+        let building_id = create_building(&mut data, BuildingLot::new(town0, 0)).unwrap();
+        let other_id = create_building(&mut data, BuildingLot::new(town1, 0)).unwrap();
+
+        assert!(move_building(&mut data, building_id, town1, 0).is_err());
+        assert_eq!(delete_building(&mut data, other_id), DeleteResult::Ok);
+        assert!(move_building(&mut data, building_id, town1, 0).is_ok());
+        assert!(move_building(&mut data, building_id, TownId::new(5), 0).is_err());
+
+        assert_eq!(
+            data.building_manager.get(building_id).unwrap().lot,
+            BuildingLot::new(town1, 0)
+        );
+        assert_eq!(get_constructions(&data, town0), vec![&Construction::None]);
+        assert_eq!(
+            get_constructions(&data, town1),
+            vec![&Construction::Building { id: building_id }]
+        );
+    }
+
+    #[test]
+    fn rotate_building_with_entrance() {
+        let mut data = RpgData::default();
+        let town_id = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::new(2, 2)));
+        let lot = BuildingLot::big(town_id, 0, Size2d::new(2, 1));
+        let building_id = create_building(&mut data, lot).unwrap();
+        let entrance = Entrance::new(1, 0, Side2d::Right);
+        data.building_manager
+            .get_mut(building_id)
+            .unwrap()
+            .entrances = vec![entrance];
+
+        assert!(rotate_building(&mut data, building_id).is_ok());
+
+        assert_eq!(
+            data.building_manager.get(building_id).unwrap().entrances,
+            vec![Entrance::new(0, 1, Side2d::Bottom)]
+        );
+    }
+
+    #[test]
+    fn resize_building_removes_covered_entrance() {
+        let mut data = RpgData::default();
+        let town_id = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::new(3, 2)));
+        let building_id = create_building(&mut data, BuildingLot::new(town_id, 0)).unwrap();
+        let top = Entrance::new(0, 0, Side2d::Top);
+        let right = Entrance::new(0, 0, Side2d::Right);
+        data.building_manager
+            .get_mut(building_id)
+            .unwrap()
+            .entrances = vec![top, right];
+
+        assert!(resize_building(&mut data, building_id, 2, 1).is_ok());
+
+        assert_eq!(
+            data.building_manager.get(building_id).unwrap().entrances,
+            vec![top]
+        );
+    }
+
+    #[test]
+    fn rotate_building_with_floor() {
+        let mut data = RpgData::default();
+        let town_id = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::new(2, 2)));
+        let lot = BuildingLot::big(town_id, 0, Size2d::new(2, 1));
+        let building_id = create_building(&mut data, lot).unwrap();
+        let construction = Construction::Building { id: building_id };
+        add_floor(&mut data, building_id).unwrap();
+
+        assert!(rotate_building(&mut data, building_id).is_ok());
+
+        let building = data.building_manager.get(building_id).unwrap();
+        assert_eq!(
+            building.lot,
+            BuildingLot::big(town_id, 0, Size2d::new(1, 2))
+        );
+        assert_eq!(
+            building.floors[0].map.get_size(),
+            get_interior_size(Size2d::new(1, 2))
+        );
+        assert_eq!(
+            get_constructions(&data, town_id),
+            vec![
+                &construction,
+                &Construction::None,
+                &construction,
+                &Construction::None
+            ]
+        );
+    }
+
+    #[test]
+    fn rotate_building_outside_town() {
+        let mut data = RpgData::default();
+        let town_id = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::new(2, 1)));
+        let lot = BuildingLot::big(town_id, 0, Size2d::new(2, 1));
+        let building_id = create_building(&mut data, lot.clone()).unwrap();
+
+        assert!(rotate_building(&mut data, building_id).is_err());
+
+        assert_eq!(data.building_manager.get(building_id).unwrap().lot, lot);
+    }
+
     #[test]
     fn resize_building_with_unknown_town() {
         let mut data = RpgData::default();
@@ -0,0 +1,456 @@
+use crate::model::description::WithDescription;
+use crate::model::element::ElementRef;
+use crate::model::math::axis2d::Axis2d;
+use crate::model::math::size2d::Size2d;
+use crate::model::visibility::WithVisibility;
+use crate::model::world::building::lot::BuildingLot;
+use crate::model::world::building::BuildingId;
+use crate::model::RpgData;
+use crate::usecase::create::building::create_building;
+use crate::usecase::delete::building::delete_building;
+use crate::usecase::edit::reference::replace_references;
+use crate::usecase::edit::resize::update_lot_tiles;
+use crate::utils::storage::Id;
+use anyhow::{bail, Context, Result};
+
+/// The result of [`merge_buildings()`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct MergeResult {
+    /// The id of the merged building.
+    /// It differs from the original id, if the building was moved to fill the gap.
+    pub building: BuildingId,
+    /// The id of the removed building.
+    /// It can now belong to the merged building or another building, which was moved to fill the gap.
+    pub removed: BuildingId,
+}
+
+/// Tries to split the lot of a [`building`](crate::model::world::building::Building)
+/// into equal parts along an axis & returns the ids of the new buildings.
+///
+/// The building keeps the first part & every other part becomes a new building
+/// of the same type, visibility & description
+/// & with a copy of its floors & the entrances on its outline.
+pub fn split_building(
+    data: &mut RpgData,
+    id: BuildingId,
+    parts: u32,
+    axis: Axis2d,
+) -> Result<Vec<BuildingId>> {
+    let building = data
+        .building_manager
+        .get(id)
+        .context("Building doesn't exist")?;
+    let lot = building.lot.clone();
+    let building_type = building.building_type.clone();
+    let floors = building.floors.clone();
+    let entrances = building.entrances.clone();
+    let visibility = building.visibility();
+    let description = building.description().map(str::to_string);
+    let town_size = get_town_size(data, &lot)?;
+    let length = match axis {
+        Axis2d::Horizontal => lot.size.width(),
+        Axis2d::Vertical => lot.size.height(),
+    };
+
+    if parts < 2 {
+        bail!("A building must be split into at least 2 parts!");
+    } else if length < parts as i32 {
+        bail!("The building is too small for {} parts!", parts);
+    }
+
+    let tiles = lot
+        .get_tiles(town_size)
+        .context("The lot is outside the town!")?;
+    let start_x = town_size.to_x(lot.tile);
+    let start_y = town_size.to_y(lot.tile);
+    let mut slices = Vec::new();
+
+    for part in 0..parts as i32 {
+        let start = part * length / parts as i32;
+        let end = (part + 1) * length / parts as i32;
+        let slice: Vec<usize> = tiles
+            .iter()
+            .copied()
+            .filter(|&tile| {
+                let position = match axis {
+                    Axis2d::Horizontal => town_size.to_x(tile) - start_x,
+                    Axis2d::Vertical => town_size.to_y(tile) - start_y,
+                };
+                position >= start && position < end
+            })
+            .collect();
+
+        BuildingLot::from_tiles(lot.town, town_size, &slice)
+            .context(format!("Part {} of the split lot is invalid!", part))?;
+
+        slices.push(slice);
+    }
+
+    update_lot_tiles(data, id, &slices[0])?;
+
+    let mut ids = Vec::new();
+
+    for slice in &slices[1..] {
+        let new_lot = BuildingLot::from_tiles(lot.town, town_size, slice)?;
+        let offset_x = start_x - town_size.to_x(new_lot.tile);
+        let offset_y = start_y - town_size.to_y(new_lot.tile);
+        let size = new_lot.size;
+        let new_id = create_building(data, new_lot)?;
+
+        if let Some(building) = data.building_manager.get_mut(new_id) {
+            building.building_type = building_type.clone();
+            building.set_visibility(visibility);
+            building.set_description(description.clone());
+            building.floors = floors.clone();
+            building
+                .floors
+                .iter_mut()
+                .for_each(|floor| floor.reshape(offset_x, offset_y, size));
+            building.entrances = entrances.clone();
+            building
+                .entrances
+                .iter_mut()
+                .for_each(|entrance| entrance.shift(offset_x, offset_y));
+            building
+                .entrances
+                .retain(|entrance| building.lot.is_valid_entrance(entrance));
+        }
+
+        ids.push(new_id);
+    }
+
+    Ok(ids)
+}
+
+/// Tries to merge 2 adjacent [`buildings`](crate::model::world::building::Building) of the same town.
+///
+/// The first building gets the lot of both, their references & their entrances on the new outline.
+/// The second building & its floors are removed.
+pub fn merge_buildings(
+    data: &mut RpgData,
+    id: BuildingId,
+    other: BuildingId,
+) -> Result<MergeResult> {
+    if id == other {
+        bail!("A building can't be merged with itself!");
+    }
+
+    let lot = get_lot(data, id)?;
+    let other_lot = get_lot(data, other)?;
+
+    if lot.town != other_lot.town {
+        bail!("The buildings are in different towns!");
+    }
+
+    let town_size = get_town_size(data, &lot)?;
+    let mut tiles = lot
+        .get_tiles(town_size)
+        .context("The lot is outside the town!")?;
+    tiles.extend(
+        other_lot
+            .get_tiles(town_size)
+            .context("The other lot is outside the town!")?,
+    );
+
+    if BuildingLot::from_tiles(lot.town, town_size, &tiles).is_err() {
+        bail!("The buildings are not adjacent!");
+    }
+
+    let is_last = id.id() + 1 == data.building_manager.len();
+    let other_entrances = data
+        .building_manager
+        .get(other)
+        .map(|building| building.entrances.clone())
+        .unwrap_or_default();
+
+    replace_references(data, ElementRef::Building(other), ElementRef::Building(id));
+    delete_building(data, other);
+
+    let building = if is_last { other } else { id };
+
+    update_lot_tiles(data, building, &tiles)?;
+
+    if let Some(merged) = data.building_manager.get_mut(building) {
+        let offset_x = town_size.to_x(other_lot.tile) - town_size.to_x(merged.lot.tile);
+        let offset_y = town_size.to_y(other_lot.tile) - town_size.to_y(merged.lot.tile);
+
+        for mut entrance in other_entrances {
+            entrance.shift(offset_x, offset_y);
+
+            if merged.lot.is_valid_entrance(&entrance) {
+                merged.entrances.push(entrance);
+            }
+        }
+    }
+
+    Ok(MergeResult {
+        building,
+        removed: other,
+    })
+}
+
+fn get_lot(data: &RpgData, id: BuildingId) -> Result<BuildingLot> {
+    data.building_manager
+        .get(id)
+        .map(|building| building.lot.clone())
+        .context("Building doesn't exist")
+}
+
+fn get_town_size(data: &RpgData, lot: &BuildingLot) -> Result<Size2d> {
+    data.town_manager
+        .get(lot.town)
+        .map(|town| town.map.get_size())
+        .context("Town doesn't exist")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::Character;
+    use crate::model::math::side2d::Side2d;
+    use crate::model::visibility::Visibility;
+    use crate::model::world::building::entrance::Entrance;
+    use crate::model::world::building::interior::{get_interior_size, Floor};
+    use crate::model::world::town::construction::Construction;
+    use crate::model::world::town::{Town, TownId};
+    use crate::usecase::edit::interior::add_floor;
+    use crate::usecase::get::town::get_constructions;
+
+    fn init(size: Size2d) -> (RpgData, TownId) {
+        let mut data = RpgData::default();
+        let town_id = data.town_manager.create(|id| Town::simple(id, size));
+
+        (data, town_id)
+    }
+
+    fn building(id: usize) -> Construction {
+        Construction::Building {
+            id: BuildingId::new(id),
+        }
+    }
+
+    fn get_building_lot(data: &RpgData, id: usize) -> &BuildingLot {
+        &data.building_manager.get(BuildingId::new(id)).unwrap().lot
+    }
+
+    fn get_floors(data: &RpgData, id: usize) -> &[Floor] {
+        &data
+            .building_manager
+            .get(BuildingId::new(id))
+            .unwrap()
+            .floors
+    }
+
+    #[test]
+    fn split_horizontally() {
+        let (mut data, town_id) = init(Size2d::new(4, 2));
+        let id =
+            create_building(&mut data, BuildingLot::big(town_id, 0, Size2d::new(4, 2))).unwrap();
+        data.building_manager.get_mut(id).unwrap().building_type = Some("House".to_string());
+        add_floor(&mut data, id).unwrap();
+
+        let ids = split_building(&mut data, id, 3, Axis2d::Horizontal).unwrap();
+
+        assert_eq!(ids, vec![BuildingId::new(1), BuildingId::new(2)]);
+        assert_eq!(
+            get_building_lot(&data, 0),
+            &BuildingLot::big(town_id, 0, Size2d::new(1, 2))
+        );
+        assert_eq!(
+            get_building_lot(&data, 1),
+            &BuildingLot::big(town_id, 1, Size2d::new(1, 2))
+        );
+        assert_eq!(
+            get_building_lot(&data, 2),
+            &BuildingLot::big(town_id, 2, Size2d::new(2, 2))
+        );
+        assert_eq!(
+            get_constructions(&data, town_id),
+            vec![
+                &building(0),
+                &building(1),
+                &building(2),
+                &building(2),
+                &building(0),
+                &building(1),
+                &building(2),
+                &building(2)
+            ]
+        );
+
+        for id in 0..3 {
+            let new_building = data.building_manager.get(BuildingId::new(id)).unwrap();
+            assert_eq!(new_building.building_type, Some("House".to_string()));
+        }
+
+        assert_eq!(
+            get_floors(&data, 2)[0].map.get_size(),
+            get_interior_size(Size2d::new(2, 2))
+        );
+    }
+
+    #[test]
+    fn split_vertically() {
+        let (mut data, town_id) = init(Size2d::new(2, 2));
+        let id =
+            create_building(&mut data, BuildingLot::big(town_id, 0, Size2d::square(2))).unwrap();
+
+        assert!(split_building(&mut data, id, 2, Axis2d::Vertical).is_ok());
+
+        assert_eq!(
+            get_constructions(&data, town_id),
+            vec![&building(0), &building(0), &building(1), &building(1)]
+        );
+    }
+
+    #[test]
+    fn split_keeps_visibility_and_description() {
+        let (mut data, town_id) = init(Size2d::new(2, 1));
+        let id =
+            create_building(&mut data, BuildingLot::big(town_id, 0, Size2d::new(2, 1))).unwrap();
+        let building = data.building_manager.get_mut(id).unwrap();
+        building.set_visibility(Visibility::GmOnly);
+        building.set_description(Some("Secret".to_string()));
+
+        let ids = split_building(&mut data, id, 2, Axis2d::Horizontal).unwrap();
+
+        let new_building = data.building_manager.get(ids[0]).unwrap();
+        assert_eq!(new_building.visibility(), Visibility::GmOnly);
+        assert_eq!(new_building.description(), Some("Secret"));
+    }
+
+    #[test]
+    fn split_into_invalid_parts() {
+        let (mut data, town_id) = init(Size2d::new(3, 2));
+        let id = create_building(&mut data, BuildingLot::new(town_id, 0)).unwrap();
+        update_lot_tiles(&mut data, id, &[0, 1, 2, 3, 5]).unwrap();
+
+        assert!(split_building(&mut data, id, 1, Axis2d::Horizontal).is_err());
+        assert!(split_building(&mut data, id, 4, Axis2d::Horizontal).is_err());
+        assert!(split_building(&mut data, id, 2, Axis2d::Vertical).is_err());
+        assert!(split_building(&mut data, BuildingId::new(1), 2, Axis2d::Vertical).is_err());
+
+        assert_eq!(data.building_manager.len(), 1);
+        assert_eq!(
+            get_constructions(&data, town_id),
+            vec![
+                &building(0),
+                &building(0),
+                &building(0),
+                &building(0),
+                &Construction::None,
+                &building(0)
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_adjacent_buildings() {
+        let (mut data, town_id) = init(Size2d::new(3, 2));
+        let id0 = create_building(&mut data, BuildingLot::new(town_id, 0)).unwrap();
+        let id1 =
+            create_building(&mut data, BuildingLot::big(town_id, 3, Size2d::new(2, 1))).unwrap();
+        let character = data.characters.create(Character::new);
+        data.characters.get_mut(character).unwrap().home = Some(id1);
+
+        assert_eq!(
+            merge_buildings(&mut data, id0, id1).unwrap(),
+            MergeResult {
+                building: id0,
+                removed: id1
+            }
+        );
+
+        assert_eq!(data.building_manager.len(), 1);
+        assert_eq!(
+            get_building_lot(&data, 0).mask,
+            vec![true, false, true, true]
+        );
+        assert_eq!(data.characters.get(character).unwrap().home, Some(id0));
+        assert_eq!(
+            get_constructions(&data, town_id),
+            vec![
+                &building(0),
+                &Construction::None,
+                &Construction::None,
+                &building(0),
+                &building(0),
+                &Construction::None
+            ]
+        );
+    }
+
+    #[test]
+    fn split_and_merge_keep_entrances() {
+        let (mut data, town_id) = init(Size2d::new(2, 1));
+        let id =
+            create_building(&mut data, BuildingLot::big(town_id, 0, Size2d::new(2, 1))).unwrap();
+        data.building_manager.get_mut(id).unwrap().entrances = vec![
+            Entrance::new(0, 0, Side2d::Left),
+            Entrance::new(1, 0, Side2d::Bottom),
+        ];
+
+        let ids = split_building(&mut data, id, 2, Axis2d::Horizontal).unwrap();
+
+        assert_eq!(
+            data.building_manager.get(id).unwrap().entrances,
+            vec![Entrance::new(0, 0, Side2d::Left)]
+        );
+        assert_eq!(
+            data.building_manager.get(ids[0]).unwrap().entrances,
+            vec![Entrance::new(0, 0, Side2d::Bottom)]
+        );
+
+        merge_buildings(&mut data, id, ids[0]).unwrap();
+
+        assert_eq!(
+            data.building_manager.get(id).unwrap().entrances,
+            vec![
+                Entrance::new(0, 0, Side2d::Left),
+                Entrance::new(1, 0, Side2d::Bottom)
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_last_building() {
+        let (mut data, town_id) = init(Size2d::new(3, 1));
+        let id0 = create_building(&mut data, BuildingLot::new(town_id, 0)).unwrap();
+        let id1 = create_building(&mut data, BuildingLot::new(town_id, 1)).unwrap();
+        let id2 = create_building(&mut data, BuildingLot::new(town_id, 2)).unwrap();
+
+        assert_eq!(
+            merge_buildings(&mut data, id2, id1).unwrap(),
+            MergeResult {
+                building: id1,
+                removed: id1
+            }
+        );
+
+        assert_eq!(
+            get_building_lot(&data, 1),
+            &BuildingLot::big(town_id, 1, Size2d::new(2, 1))
+        );
+        assert_eq!(
+            get_constructions(&data, town_id),
+            vec![&building(0), &building(1), &building(1)]
+        );
+        assert!(merge_buildings(&mut data, id0, id2).is_err());
+    }
+
+    #[test]
+    fn merge_invalid_buildings() {
+        let (mut data, town_id) = init(Size2d::new(3, 1));
+        let other_town = data.town_manager.create(Town::new);
+        let id0 = create_building(&mut data, BuildingLot::new(town_id, 0)).unwrap();
+        let id1 = create_building(&mut data, BuildingLot::new(town_id, 2)).unwrap();
+        let id2 = create_building(&mut data, BuildingLot::new(other_town, 0)).unwrap();
+
+        assert!(merge_buildings(&mut data, id0, id0).is_err());
+        assert!(merge_buildings(&mut data, id0, id1).is_err());
+        assert!(merge_buildings(&mut data, id0, id2).is_err());
+        assert!(merge_buildings(&mut data, id0, BuildingId::new(3)).is_err());
+
+        assert_eq!(data.building_manager.len(), 3);
+    }
+}
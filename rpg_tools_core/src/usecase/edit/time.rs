@@ -0,0 +1,39 @@
+use crate::model::time::date::Date;
+use crate::model::RpgData;
+use anyhow::Result;
+
+/// Tries to update the current date of the campaign, which must exist in the calendar.
+pub fn update_now(data: &mut RpgData, now: Option<Date>) -> Result<()> {
+    if let Some(date) = &now {
+        data.time.calendar.validate(date)?;
+    }
+
+    data.time.now = now;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn success() {
+        let mut data = RpgData::default();
+        let now = Some(Date::new(1925, 3, 14));
+
+        assert!(update_now(&mut data, now).is_ok());
+        assert_eq!(data.time.now, now);
+
+        assert!(update_now(&mut data, None).is_ok());
+        assert_eq!(data.time.now, None);
+    }
+
+    #[test]
+    fn invalid_date() {
+        let mut data = RpgData::default();
+
+        assert!(update_now(&mut data, Some(Date::new(1925, 2, 30))).is_err());
+        assert_eq!(data.time.now, None);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::model::name::WithName;
+use crate::model::visibility::{Visibility, WithVisibility};
+use crate::model::world::town::TownId;
+use crate::model::RpgData;
+use crate::utils::storage::{Element, Id, Storage};
+use anyhow::{bail, Context, Result};
+
+/// Tries to update the [`visibility`](Visibility) of an [`element`](Element).
+pub fn update_visibility<ID: Id, ELEMENT: Element<ID> + WithVisibility>(
+    storage: &mut Storage<ID, ELEMENT>,
+    id: ID,
+    visibility: Visibility,
+) -> Result<()> {
+    storage
+        .get_mut(id)
+        .map(|element| element.set_visibility(visibility))
+        .context("Id doesn't exist")
+}
+
+/// Tries to reveal a [`hidden`](Visibility::Hidden) [`element`](Element) to the players.
+pub fn reveal<ID: Id, ELEMENT: Element<ID> + WithVisibility>(
+    storage: &mut Storage<ID, ELEMENT>,
+    id: ID,
+) -> Result<()> {
+    let element = storage.get_mut(id).context("Id doesn't exist")?;
+
+    if element.visibility() != Visibility::Hidden {
+        bail!("Only hidden elements can be revealed!");
+    }
+
+    element.set_visibility(Visibility::Public);
+
+    Ok(())
+}
+
+/// Tries to update the [`visibility`](Visibility) of the [`construction`](crate::model::world::town::construction::Construction)
+/// of a [`tile`](crate::model::world::town::tile::TownTile).
+pub fn update_construction_visibility(
+    data: &mut RpgData,
+    town_id: TownId,
+    tile: usize,
+    visibility: Visibility,
+) -> Result<()> {
+    if let Some(town) = data.town_manager.get_mut(town_id) {
+        if let Some(tile) = town.map.get_tile_mut(tile) {
+            tile.visibility = visibility;
+            Ok(())
+        } else {
+            bail!("Tile {} is outside town {}!", tile, town.name());
+        }
+    } else {
+        bail!("Unknown town id {}!", town_id.id());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::world::building::lot::BuildingLot;
+    use crate::model::world::building::BuildingId;
+    use crate::model::world::town::Town;
+    use crate::usecase::create::building::create_building;
+
+    #[test]
+    fn update_building() {
+        let mut data = RpgData::default();
+        data.town_manager.create(Town::new);
+        let id = create_building(&mut data, BuildingLot::tile(0)).unwrap();
+
+        assert!(update_visibility(&mut data.building_manager, id, Visibility::GmOnly).is_ok());
+
+        assert_visibility(&data, id, Visibility::GmOnly);
+    }
+
+    #[test]
+    fn update_unknown_building() {
+        let mut data = RpgData::default();
+
+        assert!(update_visibility(
+            &mut data.building_manager,
+            BuildingId::default(),
+            Visibility::GmOnly
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn reveal_hidden_building() {
+        let mut data = RpgData::default();
+        data.town_manager.create(Town::new);
+        let id = create_building(&mut data, BuildingLot::tile(0)).unwrap();
+        update_visibility(&mut data.building_manager, id, Visibility::Hidden).unwrap();
+
+        assert!(reveal(&mut data.building_manager, id).is_ok());
+
+        assert_visibility(&data, id, Visibility::Public);
+    }
+
+    #[test]
+    fn reveal_gm_only_building() {
+        let mut data = RpgData::default();
+        data.town_manager.create(Town::new);
+        let id = create_building(&mut data, BuildingLot::tile(0)).unwrap();
+        update_visibility(&mut data.building_manager, id, Visibility::GmOnly).unwrap();
+
+        assert!(reveal(&mut data.building_manager, id).is_err());
+
+        assert_visibility(&data, id, Visibility::GmOnly);
+    }
+
+    #[test]
+    fn update_tile() {
+        let mut data = RpgData::default();
+        let town_id = data.town_manager.create(Town::new);
+
+        assert!(update_construction_visibility(&mut data, town_id, 0, Visibility::Hidden).is_ok());
+
+        assert_eq!(
+            data.town_manager
+                .get(town_id)
+                .and_then(|town| town.map.get_tile(0))
+                .map(|tile| tile.visibility),
+            Some(Visibility::Hidden)
+        );
+    }
+
+    #[test]
+    fn update_tile_outside_map() {
+        let mut data = RpgData::default();
+        let town_id = data.town_manager.create(Town::new);
+
+        assert!(update_construction_visibility(&mut data, town_id, 1, Visibility::Hidden).is_err());
+    }
+
+    #[test]
+    fn update_tile_of_unknown_town() {
+        let mut data = RpgData::default();
+
+        assert!(update_construction_visibility(
+            &mut data,
+            TownId::default(),
+            0,
+            Visibility::Hidden
+        )
+        .is_err());
+    }
+
+    fn assert_visibility(data: &RpgData, id: BuildingId, visibility: Visibility) {
+        assert_eq!(
+            data.building_manager.get(id).unwrap().visibility(),
+            visibility
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use crate::model::math::side2d::Side2d;
+use crate::model::name::WithName;
+use crate::model::world::building::{Building, BuildingId};
+use crate::model::world::street::StreetId;
+use crate::model::world::town::construction::Construction;
+use crate::model::RpgData;
+use crate::usecase::get::entrance::get_entrance_street;
+
+/// Returns the address of a [`building`](Building) like *12 Church Street*.
+pub fn address(data: &RpgData, id: BuildingId) -> Option<String> {
+    let address = data.building_manager.get(id)?.address?;
+    let street = data.street_manager.get(address.street)?;
+
+    Some(format!("{} {}", address.number, street.name()))
+}
+
+/// Returns the street, which determines the address of a [`building`](Building).
+///
+/// It is the street in front of the first entrance facing a street.
+/// Without such an entrance, it is the first street bordering the lot.
+pub fn get_address_street(data: &RpgData, building: &Building) -> Option<StreetId> {
+    building
+        .entrances
+        .iter()
+        .find_map(|entrance| get_entrance_street(data, building, entrance))
+        .or_else(|| {
+            get_bordering_streets(data, building)
+                .into_iter()
+                .map(|(street, _, _)| street)
+                .next()
+        })
+}
+
+/// Returns the streets bordering the lot of a [`building`](Building)
+/// with the tile of the street & the tile of the lot.
+pub fn get_bordering_streets(data: &RpgData, building: &Building) -> Vec<(StreetId, usize, usize)> {
+    let Some(town) = data.town_manager.get(building.lot.town) else {
+        return Vec::new();
+    };
+    let size = town.map.get_size();
+    let mut streets = Vec::new();
+
+    for (x, y) in building.lot.get_positions(size) {
+        let Some(lot_tile) = size.to_index(x, y) else {
+            continue;
+        };
+
+        for side in Side2d::get_all() {
+            let (offset_x, offset_y) = side.get_offset();
+
+            if let Some(street_tile) = size.to_index(x + offset_x, y + offset_y) {
+                if let Some(Construction::Street { id }) = town
+                    .map
+                    .get_tile(street_tile)
+                    .map(|tile| &tile.construction)
+                {
+                    streets.push((*id, street_tile, lot_tile));
+                }
+            }
+        }
+    }
+
+    streets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::math::size2d::Size2d;
+    use crate::model::name::Name;
+    use crate::model::world::building::address::Address;
+    use crate::model::world::building::entrance::Entrance;
+    use crate::model::world::building::lot::BuildingLot;
+    use crate::model::world::street::Street;
+    use crate::model::world::town::Town;
+    use crate::usecase::create::building::create_building;
+    use crate::usecase::edit::town::add_street::add_street_to_tile;
+
+    #[test]
+    fn format_address() {
+        let mut data = RpgData::default();
+        data.town_manager.create(Town::new);
+        let street = data.street_manager.create(Street::new);
+        let id = create_building(&mut data, BuildingLot::tile(0)).unwrap();
+        data.street_manager
+            .get_mut(street)
+            .unwrap()
+            .set_name(Name::new("Church Street").unwrap());
+
+        assert_eq!(address(&data, id), None);
+
+        data.building_manager.get_mut(id).unwrap().address = Some(Address::new(street, 12));
+
+        assert_eq!(address(&data, id), Some("12 Church Street".to_string()));
+    }
+
+    #[test]
+    fn entrance_determines_street() {
+        let mut data = RpgData::default();
+        let town = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::new(3, 1)));
+        let street0 = data.street_manager.create(Street::new);
+        let street1 = data.street_manager.create(Street::new);
+        add_street_to_tile(&mut data, town, 0, street0).unwrap();
+        add_street_to_tile(&mut data, town, 2, street1).unwrap();
+        let id = create_building(&mut data, BuildingLot::new(town, 1)).unwrap();
+
+        let building = data.building_manager.get(id).unwrap();
+        assert_eq!(
+            get_bordering_streets(&data, building),
+            vec![(street0, 0, 1), (street1, 2, 1)]
+        );
+        assert_eq!(get_address_street(&data, building), Some(street0));
+
+        data.building_manager.get_mut(id).unwrap().entrances =
+            vec![Entrance::new(0, 0, Side2d::Right)];
+
+        let building = data.building_manager.get(id).unwrap();
+        assert_eq!(get_address_street(&data, building), Some(street1));
+    }
+}
@@ -0,0 +1,114 @@
+use crate::model::character::CharacterId;
+use crate::model::RpgData;
+
+/// Returns the age of a [`character`](crate::model::character::Character) at the current
+/// date of the campaign, or at its death, if it died before.
+///
+/// Returns nothing, if the dates are unknown or the character isn't born yet.
+pub fn get_age(data: &RpgData, id: CharacterId) -> Option<u32> {
+    let character = data.characters.get(id)?;
+    let birth = character.birth_date?;
+    let end = match (character.death_date, data.time.now) {
+        (Some(death), Some(now)) => death.min(now),
+        (Some(death), None) => death,
+        (None, Some(now)) => now,
+        (None, None) => return None,
+    };
+
+    birth.get_years_until(&end)
+}
+
+/// Is the [`character`](crate::model::character::Character) dead at the current date of the
+/// campaign? Without a current date, every death is in the past.
+pub fn is_dead(data: &RpgData, id: CharacterId) -> bool {
+    data.characters
+        .get(id)
+        .and_then(|character| character.death_date)
+        .map(|death| data.time.now.map(|now| death <= now).unwrap_or(true))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::Character;
+    use crate::model::time::date::Date;
+    use crate::utils::storage::Id;
+
+    fn init(birth: Option<Date>, death: Option<Date>, now: Option<Date>) -> (RpgData, CharacterId) {
+        let mut data = RpgData::default();
+        let id = data.characters.create(Character::new);
+        let character = data.characters.get_mut(id).unwrap();
+        character.birth_date = birth;
+        character.death_date = death;
+        data.time.now = now;
+
+        (data, id)
+    }
+
+    #[test]
+    fn alive() {
+        let (data, id) = init(
+            Some(Date::new(1855, 10, 9)),
+            None,
+            Some(Date::new(1925, 3, 14)),
+        );
+
+        assert_eq!(get_age(&data, id), Some(69));
+        assert!(!is_dead(&data, id));
+    }
+
+    #[test]
+    fn died_before_now() {
+        let (data, id) = init(
+            Some(Date::new(1855, 10, 9)),
+            Some(Date::new(1920, 10, 9)),
+            Some(Date::new(1925, 3, 14)),
+        );
+
+        assert_eq!(get_age(&data, id), Some(65));
+        assert!(is_dead(&data, id));
+    }
+
+    #[test]
+    fn dies_after_now() {
+        let (data, id) = init(
+            Some(Date::new(1855, 10, 9)),
+            Some(Date::new(1930, 1, 1)),
+            Some(Date::new(1925, 3, 14)),
+        );
+
+        assert_eq!(get_age(&data, id), Some(69));
+        assert!(!is_dead(&data, id));
+    }
+
+    #[test]
+    fn without_now() {
+        let (data, id) = init(Some(Date::new(1855, 10, 9)), None, None);
+        assert_eq!(get_age(&data, id), None);
+        assert!(!is_dead(&data, id));
+
+        let (data, id) = init(None, Some(Date::new(1920, 10, 9)), None);
+        assert_eq!(get_age(&data, id), None);
+        assert!(is_dead(&data, id));
+    }
+
+    #[test]
+    fn not_born_yet() {
+        let (data, id) = init(
+            Some(Date::new(1930, 1, 1)),
+            None,
+            Some(Date::new(1925, 3, 14)),
+        );
+
+        assert_eq!(get_age(&data, id), None);
+    }
+
+    #[test]
+    fn non_existing_character() {
+        let (data, _) = init(None, None, None);
+
+        assert_eq!(get_age(&data, CharacterId::new(1)), None);
+        assert!(!is_dead(&data, CharacterId::new(1)));
+    }
+}
@@ -0,0 +1,277 @@
+use crate::model::element::ElementRef;
+use crate::model::world::town::construction::Construction;
+use crate::model::world::town::terrain::Terrain;
+use crate::model::RpgData;
+use crate::usecase::get::link::{could_link_to, get_descriptions, get_links, resolve_link};
+use crate::utils::storage::Element;
+use std::collections::HashMap;
+
+/// An index of which elements reference an element of the [`rpg data`](RpgData).
+///
+/// The references are:
+/// * the tiles of a town to its terrain, streets & buildings
+/// * a character to its culture, home, workplace, parents & spouses
+/// * the lot of a building to its town & its address to its street
+/// * an event to its participants
+/// * an organization to its members, buildings, parent & related organizations
+/// * a description to the elements it links to
+#[derive(Debug, Default)]
+pub struct BacklinkIndex {
+    backlinks: HashMap<ElementRef, Vec<ElementRef>>,
+}
+
+impl BacklinkIndex {
+    pub fn new(data: &RpgData) -> Self {
+        let mut index = Self::without_descriptions(data);
+
+        for (source, description) in get_descriptions(data) {
+            for link in get_links(description) {
+                if let Ok(target) = resolve_link(data, link) {
+                    index.add(source, target);
+                }
+            }
+        }
+
+        index
+    }
+
+    /// Creates an index without the links in the descriptions.
+    ///
+    /// Only these references need to be updated, if an element is deleted.
+    pub fn without_descriptions(data: &RpgData) -> Self {
+        let mut index = Self::default();
+
+        visit_references(data, |source, target| index.add(source, target));
+
+        index
+    }
+
+    /// Returns all elements referencing the element.
+    pub fn get(&self, element: ElementRef) -> &[ElementRef] {
+        self.backlinks
+            .get(&element)
+            .map(|sources| sources.as_slice())
+            .unwrap_or_default()
+    }
+
+    fn add(&mut self, source: ElementRef, target: ElementRef) {
+        if source == target {
+            return;
+        }
+
+        let sources = self.backlinks.entry(target).or_default();
+
+        if !sources.contains(&source) {
+            sources.push(source);
+        }
+    }
+}
+
+/// Returns all elements referencing the element in the same order as [`BacklinkIndex`].
+///
+/// Only the links in descriptions, which could reference the element, are resolved.
+pub fn get_backlinks(data: &RpgData, element: ElementRef) -> Vec<ElementRef> {
+    let mut backlinks = Vec::new();
+    let mut add = |source: ElementRef| {
+        if source != element && !backlinks.contains(&source) {
+            backlinks.push(source);
+        }
+    };
+
+    visit_references(data, |source, target| {
+        if target == element {
+            add(source);
+        }
+    });
+
+    for (source, description) in get_descriptions(data) {
+        if get_links(description).into_iter().any(|link| {
+            could_link_to(data, link, element)
+                && resolve_link(data, link).is_ok_and(|target| target == element)
+        }) {
+            add(source);
+        }
+    }
+
+    backlinks
+}
+
+/// Calls *f* with the source & target of each reference except the links in descriptions.
+fn visit_references<F: FnMut(ElementRef, ElementRef)>(data: &RpgData, mut f: F) {
+    for town in data.town_manager.get_all() {
+        let source = ElementRef::Town(town.id());
+
+        for tile in town.map.get_tiles() {
+            match tile.terrain {
+                Terrain::Hill { id } | Terrain::Mountain { id } => {
+                    f(source, ElementRef::Mountain(id))
+                }
+                Terrain::River { id } => f(source, ElementRef::River(id)),
+                Terrain::Plain => {}
+            }
+
+            match tile.construction {
+                Construction::Building { id } => f(source, ElementRef::Building(id)),
+                Construction::Street { id } => f(source, ElementRef::Street(id)),
+                Construction::None => {}
+            }
+        }
+    }
+
+    for character in data.characters.get_all() {
+        let source = ElementRef::Character(character.id());
+
+        f(source, ElementRef::Culture(character.culture));
+
+        for building in character.home.iter().chain(&character.workplace) {
+            f(source, ElementRef::Building(*building));
+        }
+
+        for relative in character
+            .family
+            .parents()
+            .iter()
+            .chain(character.family.spouses())
+        {
+            f(source, ElementRef::Character(*relative));
+        }
+    }
+
+    for event in data.events.get_all() {
+        let source = ElementRef::Event(event.id());
+
+        for participant in event.participants() {
+            f(source, participant);
+        }
+    }
+
+    for organization in data.organizations.get_all() {
+        let source = ElementRef::Organization(organization.id());
+
+        for reference in organization.references() {
+            f(source, reference);
+        }
+    }
+
+    for building in data.building_manager.get_all() {
+        let source = ElementRef::Building(building.id());
+
+        f(source, ElementRef::Town(building.lot.town));
+
+        if let Some(address) = &building.address {
+            f(source, ElementRef::Street(address.street));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::culture::Culture;
+    use crate::model::character::Character;
+    use crate::model::math::size2d::Size2d;
+    use crate::model::world::building::address::Address;
+    use crate::model::world::building::lot::BuildingLot;
+    use crate::model::world::mountain::Mountain;
+    use crate::model::world::street::Street;
+    use crate::model::world::town::Town;
+    use crate::usecase::create::building::create_building;
+    use crate::usecase::edit::description::update_description;
+    use crate::usecase::edit::name::update_name;
+    use crate::usecase::edit::town::add_street::add_street_to_tile;
+    use crate::usecase::edit::town::terrain::edit_terrain;
+
+    #[test]
+    fn town_references_terrain_streets_and_buildings() {
+        let mut data = RpgData::default();
+        let town = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::square(3)));
+        let mountain = data.mountain_manager.create(Mountain::new);
+        let street = data.street_manager.create(Street::new);
+        edit_terrain(&mut data, town, 0, Terrain::Hill { id: mountain }).unwrap();
+        add_street_to_tile(&mut data, town, 1, street).unwrap();
+        let building = create_building(&mut data, BuildingLot::tile(2)).unwrap();
+
+        let index = BacklinkIndex::new(&data);
+
+        let town_ref = ElementRef::Town(town);
+        assert_eq!(index.get(ElementRef::Mountain(mountain)), &[town_ref]);
+        assert_eq!(index.get(ElementRef::Street(street)), &[town_ref]);
+        assert_eq!(index.get(ElementRef::Building(building)), &[town_ref]);
+        assert_eq!(index.get(town_ref), &[ElementRef::Building(building)]);
+    }
+
+    #[test]
+    fn address_references_street() {
+        let mut data = RpgData::default();
+        data.town_manager.create(Town::new);
+        let street = data.street_manager.create(Street::new);
+        let building = create_building(&mut data, BuildingLot::tile(0)).unwrap();
+        data.building_manager.get_mut(building).unwrap().address = Some(Address::new(street, 1));
+
+        assert_eq!(
+            get_backlinks(&data, ElementRef::Street(street)),
+            vec![ElementRef::Building(building)]
+        );
+    }
+
+    #[test]
+    fn character_references_culture() {
+        let mut data = RpgData::default();
+        let culture = data.cultures.create(Culture::new);
+        let character = data.characters.create(Character::new);
+
+        assert_eq!(
+            get_backlinks(&data, ElementRef::Culture(culture)),
+            vec![ElementRef::Character(character)]
+        );
+    }
+
+    #[test]
+    fn description_references_linked_element() {
+        let mut data = RpgData::default();
+        let town = data.town_manager.create(Town::new);
+        let mountain = data.mountain_manager.create(Mountain::new);
+        update_name(&mut data.town_manager, town, "Arkham").unwrap();
+        update_description(&mut data.mountain_manager, mountain, "Near [[Arkham]]").unwrap();
+
+        assert_eq!(
+            get_backlinks(&data, ElementRef::Town(town)),
+            vec![ElementRef::Mountain(mountain)]
+        );
+    }
+
+    #[test]
+    fn backlinks_match_index() {
+        let mut data = RpgData::default();
+        let town = data.town_manager.create(Town::new);
+        let mountain = data.mountain_manager.create(Mountain::new);
+        let street = data.street_manager.create(Street::new);
+        update_name(&mut data.town_manager, town, "Arkham").unwrap();
+        update_name(&mut data.street_manager, street, "Arkham").unwrap();
+        update_description(&mut data.mountain_manager, mountain, "[[town:Arkham]]").unwrap();
+        update_description(&mut data.street_manager, street, "[[Arkham]]").unwrap();
+        let index = BacklinkIndex::new(&data);
+
+        for element in [
+            ElementRef::Town(town),
+            ElementRef::Mountain(mountain),
+            ElementRef::Street(street),
+        ] {
+            assert_eq!(get_backlinks(&data, element), index.get(element));
+        }
+        assert_eq!(
+            get_backlinks(&data, ElementRef::Town(town)),
+            vec![ElementRef::Mountain(mountain)]
+        );
+    }
+
+    #[test]
+    fn unreferenced_element() {
+        let mut data = RpgData::default();
+        let mountain = data.mountain_manager.create(Mountain::new);
+
+        assert!(get_backlinks(&data, ElementRef::Mountain(mountain)).is_empty());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::model::world::building::entrance::Entrance;
+use crate::model::world::building::{Building, BuildingId};
+use crate::model::world::street::StreetId;
+use crate::model::world::town::construction::Construction;
+use crate::model::world::town::TownId;
+use crate::model::RpgData;
+use crate::utils::storage::Element;
+
+/// Returns the index of the town tile in front of an [`entrance`](Entrance) of a [`building`](Building),
+/// if it is inside the town.
+pub fn get_front_tile(data: &RpgData, building: &Building, entrance: &Entrance) -> Option<usize> {
+    let size = data.town_manager.get(building.lot.town)?.map.get_size();
+    let (x, y) = entrance.get_front();
+
+    size.to_index(
+        size.to_x(building.lot.tile) + x,
+        size.to_y(building.lot.tile) + y,
+    )
+}
+
+/// Returns the street in front of an [`entrance`](Entrance) of a [`building`](Building).
+pub fn get_entrance_street(
+    data: &RpgData,
+    building: &Building,
+    entrance: &Entrance,
+) -> Option<StreetId> {
+    let tile = get_front_tile(data, building, entrance)?;
+
+    match data
+        .town_manager
+        .get(building.lot.town)?
+        .map
+        .get_tile(tile)?
+        .construction
+    {
+        Construction::Street { id } => Some(id),
+        _ => None,
+    }
+}
+
+/// Does an [`entrance`](Entrance) of a [`building`](Building) face a street?
+pub fn faces_street(data: &RpgData, building: &Building, entrance: &Entrance) -> bool {
+    get_entrance_street(data, building, entrance).is_some()
+}
+
+/// Does any entrance of a [`building`](Building) face a street?
+pub fn has_street_access(data: &RpgData, id: BuildingId) -> bool {
+    data.building_manager
+        .get(id)
+        .map(|building| {
+            building
+                .entrances
+                .iter()
+                .any(|entrance| faces_street(data, building, entrance))
+        })
+        .unwrap_or(false)
+}
+
+/// Returns the [`buildings`](Building) of a town without street access.
+pub fn get_buildings_without_street_access(data: &RpgData, town: TownId) -> Vec<BuildingId> {
+    data.building_manager
+        .get_all()
+        .iter()
+        .filter(|building| building.lot.town == town)
+        .map(|building| building.id())
+        .filter(|&id| !has_street_access(data, id))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::math::side2d::Side2d;
+    use crate::model::math::size2d::Size2d;
+    use crate::model::world::building::lot::BuildingLot;
+    use crate::model::world::street::Street;
+    use crate::model::world::town::Town;
+    use crate::usecase::create::building::create_building;
+    use crate::usecase::edit::town::add_street::add_street_to_tile;
+
+    #[test]
+    fn street_access() {
+        let mut data = RpgData::default();
+        let town_id = data
+            .town_manager
+            .create(|id| Town::simple(id, Size2d::new(2, 2)));
+        let street_id = data.street_manager.create(Street::new);
+        add_street_to_tile(&mut data, town_id, 2, street_id).unwrap();
+        let id0 = create_building(&mut data, BuildingLot::new(town_id, 0)).unwrap();
+        let id1 = create_building(&mut data, BuildingLot::new(town_id, 1)).unwrap();
+        let bottom = Entrance::new(0, 0, Side2d::Bottom);
+        let top = Entrance::new(0, 0, Side2d::Top);
+        data.building_manager.get_mut(id0).unwrap().entrances = vec![top, bottom];
+        data.building_manager.get_mut(id1).unwrap().entrances = vec![top];
+        let building0 = data.building_manager.get(id0).unwrap();
+
+        assert_eq!(get_front_tile(&data, building0, &bottom), Some(2));
+        assert_eq!(get_front_tile(&data, building0, &top), None);
+        assert_eq!(
+            get_entrance_street(&data, building0, &bottom),
+            Some(street_id)
+        );
+        assert!(!faces_street(&data, building0, &top));
+        assert!(has_street_access(&data, id0));
+        assert!(!has_street_access(&data, id1));
+        assert_eq!(
+            get_buildings_without_street_access(&data, town_id),
+            vec![id1]
+        );
+    }
+}
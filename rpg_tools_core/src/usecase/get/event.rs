@@ -0,0 +1,88 @@
+use crate::model::element::ElementRef;
+use crate::model::event::{Event, EventId};
+use crate::model::visibility::{Audience, WithVisibility};
+use crate::model::RpgData;
+use crate::utils::storage::{Element, Id};
+
+/// Returns all [`events`](Event) visible to the audience in chronological order.
+///
+/// Events at the same date are ordered by id.
+pub fn get_timeline(data: &RpgData, audience: Audience) -> Vec<EventId> {
+    sort_chronologically(
+        data.events
+            .get_all()
+            .iter()
+            .filter(|event| event.is_visible_to(audience))
+            .collect(),
+    )
+}
+
+/// Returns the [`events`](Event) of an element visible to the audience in chronological order.
+pub fn get_history(data: &RpgData, element: ElementRef, audience: Audience) -> Vec<EventId> {
+    sort_chronologically(
+        data.events
+            .get_all()
+            .iter()
+            .filter(|event| event.is_visible_to(audience) && event.has_participant(element))
+            .collect(),
+    )
+}
+
+fn sort_chronologically(mut events: Vec<&Event>) -> Vec<EventId> {
+    events.sort_by_key(|event| (event.date, event.id().id()));
+    events.into_iter().map(|event| event.id()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::Character;
+    use crate::model::time::date::Date;
+    use crate::model::visibility::Visibility;
+
+    fn create_event(data: &mut RpgData, date: Date, visibility: Visibility) -> EventId {
+        let id = data.events.create(Event::new);
+        let event = data.events.get_mut(id).unwrap();
+        event.date = date;
+        event.set_visibility(visibility);
+        id
+    }
+
+    #[test]
+    fn timeline() {
+        let mut data = RpgData::default();
+        let id0 = create_event(&mut data, Date::new(1925, 3, 14), Visibility::Public);
+        let id1 = create_event(&mut data, Date::new(1920, 1, 1), Visibility::Hidden);
+        let id2 = create_event(&mut data, Date::new(1925, 3, 14), Visibility::Public);
+        let id3 = create_event(&mut data, Date::new(1924, 12, 31), Visibility::Public);
+
+        assert_eq!(
+            get_timeline(&data, Audience::GameMaster),
+            vec![id1, id3, id0, id2]
+        );
+        assert_eq!(get_timeline(&data, Audience::Players), vec![id3, id0, id2]);
+    }
+
+    #[test]
+    fn history() {
+        let mut data = RpgData::default();
+        let character = ElementRef::Character(data.characters.create(Character::new));
+        let id0 = create_event(&mut data, Date::new(1925, 3, 14), Visibility::Public);
+        create_event(&mut data, Date::new(1922, 1, 1), Visibility::Public);
+        let id2 = create_event(&mut data, Date::new(1921, 1, 1), Visibility::GmOnly);
+
+        for id in [id0, id2] {
+            data.events
+                .get_mut(id)
+                .unwrap()
+                .add_participant(character)
+                .unwrap();
+        }
+
+        assert_eq!(
+            get_history(&data, character, Audience::GameMaster),
+            vec![id2, id0]
+        );
+        assert_eq!(get_history(&data, character, Audience::Players), vec![id0]);
+    }
+}
@@ -0,0 +1,156 @@
+use crate::model::character::gender::Gender;
+use crate::model::character::CharacterId;
+use crate::model::RpgData;
+use crate::utils::storage::Element;
+use anyhow::Result;
+use std::collections::HashSet;
+
+/// Returns the parents of a [`character`](crate::model::character::Character).
+pub fn get_parents(data: &RpgData, id: CharacterId) -> Vec<CharacterId> {
+    data.characters
+        .get(id)
+        .map(|character| character.family.parents().to_vec())
+        .unwrap_or_default()
+}
+
+/// Returns the first male parent of a [`character`](crate::model::character::Character).
+///
+/// Fails, if the gender of a parent is unknown.
+pub fn get_father(data: &RpgData, id: CharacterId) -> Result<Option<CharacterId>> {
+    get_parent_with_gender(data, id, Gender::Male)
+}
+
+/// Returns the first female parent of a [`character`](crate::model::character::Character).
+///
+/// Fails, if the gender of a parent is unknown.
+pub fn get_mother(data: &RpgData, id: CharacterId) -> Result<Option<CharacterId>> {
+    get_parent_with_gender(data, id, Gender::Female)
+}
+
+fn get_parent_with_gender(
+    data: &RpgData,
+    id: CharacterId,
+    gender: Gender,
+) -> Result<Option<CharacterId>> {
+    for parent in get_parents(data, id) {
+        if let Some(character) = data.characters.get(parent) {
+            if data.genders.get_base(&character.gender)? == gender {
+                return Ok(Some(parent));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Returns the spouses of a [`character`](crate::model::character::Character).
+pub fn get_spouses(data: &RpgData, id: CharacterId) -> Vec<CharacterId> {
+    data.characters
+        .get(id)
+        .map(|character| character.family.spouses().to_vec())
+        .unwrap_or_default()
+}
+
+/// Returns the children of a [`character`](crate::model::character::Character).
+pub fn get_children(data: &RpgData, id: CharacterId) -> Vec<CharacterId> {
+    data.characters
+        .get_all()
+        .iter()
+        .filter(|character| character.family.is_parent(id))
+        .map(|character| character.id())
+        .collect()
+}
+
+/// Returns the characters sharing at least one parent with a [`character`](crate::model::character::Character).
+pub fn get_siblings(data: &RpgData, id: CharacterId) -> Vec<CharacterId> {
+    let parents = get_parents(data, id);
+
+    data.characters
+        .get_all()
+        .iter()
+        .filter(|character| character.id() != id)
+        .filter(|character| {
+            parents
+                .iter()
+                .any(|parent| character.family.is_parent(*parent))
+        })
+        .map(|character| character.id())
+        .collect()
+}
+
+/// Returns the parents, grandparents & so on of a [`character`](crate::model::character::Character).
+pub fn get_ancestors(data: &RpgData, id: CharacterId) -> Vec<CharacterId> {
+    collect_relatives(data, id, get_parents)
+}
+
+/// Returns the children, grandchildren & so on of a [`character`](crate::model::character::Character).
+pub fn get_descendants(data: &RpgData, id: CharacterId) -> Vec<CharacterId> {
+    collect_relatives(data, id, get_children)
+}
+
+/// Is *ancestor* a parent, grandparent & so on of *id*?
+pub fn is_ancestor(data: &RpgData, id: CharacterId, ancestor: CharacterId) -> bool {
+    get_ancestors(data, id).contains(&ancestor)
+}
+
+fn collect_relatives<F: Fn(&RpgData, CharacterId) -> Vec<CharacterId>>(
+    data: &RpgData,
+    id: CharacterId,
+    get_next: F,
+) -> Vec<CharacterId> {
+    let mut visited = HashSet::from([id]);
+    let mut relatives = Vec::new();
+    let mut current = vec![id];
+
+    while !current.is_empty() {
+        let mut next_generation = Vec::new();
+
+        for relative in current.into_iter().flat_map(|id| get_next(data, id)) {
+            if visited.insert(relative) {
+                relatives.push(relative);
+                next_generation.push(relative);
+            }
+        }
+
+        current = next_generation;
+    }
+
+    relatives
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::Character;
+    use crate::usecase::edit::character::family::{add_parent, add_spouse};
+
+    #[test]
+    fn test_relatives() {
+        let mut data = RpgData::default();
+        let grandparent = data.characters.create(Character::new);
+        let parent = data.characters.create(Character::new);
+        let spouse = data.characters.create(Character::new);
+        let child0 = data.characters.create(Character::new);
+        let child1 = data.characters.create(Character::new);
+        add_parent(&mut data, parent, grandparent).unwrap();
+        add_spouse(&mut data, parent, spouse).unwrap();
+        add_parent(&mut data, child0, parent).unwrap();
+        add_parent(&mut data, child0, spouse).unwrap();
+        add_parent(&mut data, child1, parent).unwrap();
+
+        assert_eq!(get_parents(&data, child0), vec![parent, spouse]);
+        assert_eq!(get_spouses(&data, spouse), vec![parent]);
+        assert_eq!(get_children(&data, parent), vec![child0, child1]);
+        assert_eq!(get_siblings(&data, child1), vec![child0]);
+        assert_eq!(
+            get_ancestors(&data, child0),
+            vec![parent, spouse, grandparent]
+        );
+        assert_eq!(
+            get_descendants(&data, grandparent),
+            vec![parent, child0, child1]
+        );
+        assert!(is_ancestor(&data, child1, grandparent));
+        assert!(!is_ancestor(&data, child1, spouse));
+    }
+}
@@ -0,0 +1,389 @@
+use crate::model::character::naming::NameFormat;
+use crate::model::description::WithDescription;
+use crate::model::element::ElementRef;
+use crate::model::name::WithName;
+use crate::model::RpgData;
+use crate::usecase::get::name::{format_character_name, get_element_name};
+use crate::utils::storage::{Element, Id, Storage};
+use anyhow::{bail, Result};
+
+const START: &str = "[[";
+const END: &str = "]]";
+
+/// A link in a description, which couldn't be resolved.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BrokenLink {
+    /// The element with the description.
+    pub element: ElementRef,
+    /// The link without the brackets.
+    pub link: String,
+    pub error: String,
+}
+
+/// Returns all links of a text without the brackets.
+///
+/// ```
+///# use rpg_tools_core::usecase::get::link::get_links;
+/// assert_eq!(get_links("[[A]] and [[town:B]]"), vec!["A", "town:B"]);
+/// assert!(get_links("[[A").is_empty());
+/// ```
+pub fn get_links(text: &str) -> Vec<&str> {
+    let mut links = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find(START) {
+        let after_start = &rest[start + START.len()..];
+
+        if let Some(end) = after_start.find(END) {
+            links.push(&after_start[..end]);
+            rest = &after_start[end + END.len()..];
+        } else {
+            break;
+        }
+    }
+
+    links
+}
+
+/// Replaces all links of a text with the result of *f*.
+///
+/// ```
+///# use rpg_tools_core::usecase::get::link::replace_links;
+/// assert_eq!(replace_links("A [[B]] C [[D", |link| link.to_lowercase()), "A b C [[D");
+/// ```
+pub fn replace_links<F: FnMut(&str) -> String>(text: &str, mut f: F) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find(START) {
+        let after_start = &rest[start + START.len()..];
+
+        if let Some(end) = after_start.find(END) {
+            result.push_str(&rest[..start]);
+            result.push_str(&f(&after_start[..end]));
+            rest = &after_start[end + END.len()..];
+        } else {
+            break;
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Tries to resolve a link like *Arkham* or *town:Arkham* to an [`element`](ElementRef).
+///
+/// Fails, if no or multiple elements match.
+pub fn resolve_link(data: &RpgData, link: &str) -> Result<ElementRef> {
+    let (storage, name) = split_link(link);
+
+    let elements = find_by_name(data, storage, name)?;
+
+    match elements.len() {
+        0 => bail!("Found no element named '{}'!", name),
+        1 => Ok(elements[0]),
+        _ => bail!("Found multiple elements named '{}'!", name),
+    }
+}
+
+/// Could the link reference the [`element`](ElementRef)?
+///
+/// It only compares the names of this element & is much cheaper than [`resolve_link()`],
+/// but the link can still be ambiguous.
+pub fn could_link_to(data: &RpgData, link: &str, element: ElementRef) -> bool {
+    let (storage, name) = split_link(link);
+
+    if storage.is_some_and(|storage| storage != element.storage()) {
+        return false;
+    }
+
+    match element {
+        ElementRef::Character(id) => data.characters.get(id).is_some_and(|character| {
+            character.name.to_string().eq(name)
+                || format_character_name(data, character, NameFormat::Full)
+                    .is_ok_and(|full| full.eq(name))
+        }),
+        _ => get_element_name(data, element).is_some_and(|element_name| element_name.eq(name)),
+    }
+}
+
+fn split_link(link: &str) -> (Option<&str>, &str) {
+    match link.split_once(':') {
+        Some((storage, name)) => (Some(storage.trim()), name.trim()),
+        None => (None, link.trim()),
+    }
+}
+
+/// Returns the descriptions of all elements.
+pub fn get_descriptions(data: &RpgData) -> Vec<(ElementRef, &str)> {
+    let mut descriptions = Vec::new();
+
+    add_descriptions(
+        &mut descriptions,
+        &data.building_manager,
+        ElementRef::Building,
+    );
+    add_descriptions(&mut descriptions, &data.characters, ElementRef::Character);
+    add_descriptions(&mut descriptions, &data.cultures, ElementRef::Culture);
+    add_descriptions(&mut descriptions, &data.events, ElementRef::Event);
+    add_descriptions(
+        &mut descriptions,
+        &data.mountain_manager,
+        ElementRef::Mountain,
+    );
+    add_descriptions(
+        &mut descriptions,
+        &data.organizations,
+        ElementRef::Organization,
+    );
+    add_descriptions(&mut descriptions, &data.river_manager, ElementRef::River);
+    add_descriptions(&mut descriptions, &data.street_manager, ElementRef::Street);
+    add_descriptions(&mut descriptions, &data.town_manager, ElementRef::Town);
+
+    descriptions
+}
+
+/// Returns all links in all descriptions, which couldn't be resolved.
+pub fn get_broken_links(data: &RpgData) -> Vec<BrokenLink> {
+    get_descriptions(data)
+        .into_iter()
+        .flat_map(|(element, description)| {
+            get_links(description).into_iter().filter_map(move |link| {
+                resolve_link(data, link).err().map(|e| BrokenLink {
+                    element,
+                    link: link.to_string(),
+                    error: e.to_string(),
+                })
+            })
+        })
+        .collect()
+}
+
+fn add_descriptions<'a, I: Id, T: Element<I> + WithDescription, F: Fn(I) -> ElementRef>(
+    descriptions: &mut Vec<(ElementRef, &'a str)>,
+    storage: &'a Storage<I, T>,
+    to_ref: F,
+) {
+    for element in storage.get_all() {
+        if let Some(description) = element.description() {
+            descriptions.push((to_ref(element.id()), description));
+        }
+    }
+}
+
+fn find_by_name(data: &RpgData, storage: Option<&str>, name: &str) -> Result<Vec<ElementRef>> {
+    let mut elements = Vec::new();
+    let search_all = storage.is_none();
+    let is_storage = |s: &str| search_all || storage == Some(s);
+
+    if is_storage("building") {
+        find_in_storage(
+            &mut elements,
+            &data.building_manager,
+            name,
+            ElementRef::Building,
+        );
+    }
+    if is_storage("character") {
+        elements.extend(
+            data.characters
+                .get_all()
+                .iter()
+                .filter(|character| {
+                    character.name.to_string().eq(name)
+                        || format_character_name(data, character, NameFormat::Full)
+                            .is_ok_and(|full| full.eq(name))
+                })
+                .map(|character| ElementRef::Character(character.id())),
+        );
+    }
+    if is_storage("culture") {
+        find_in_storage(&mut elements, &data.cultures, name, ElementRef::Culture);
+    }
+    if is_storage("event") {
+        find_in_storage(&mut elements, &data.events, name, ElementRef::Event);
+    }
+    if is_storage("mountain") {
+        find_in_storage(
+            &mut elements,
+            &data.mountain_manager,
+            name,
+            ElementRef::Mountain,
+        );
+    }
+    if is_storage("organization") {
+        find_in_storage(
+            &mut elements,
+            &data.organizations,
+            name,
+            ElementRef::Organization,
+        );
+    }
+    if is_storage("river") {
+        find_in_storage(&mut elements, &data.river_manager, name, ElementRef::River);
+    }
+    if is_storage("street") {
+        find_in_storage(
+            &mut elements,
+            &data.street_manager,
+            name,
+            ElementRef::Street,
+        );
+    }
+    if is_storage("town") {
+        find_in_storage(&mut elements, &data.town_manager, name, ElementRef::Town);
+    }
+
+    if let Some(storage) = storage {
+        if !is_known_storage(storage) {
+            bail!("Unknown type '{}'!", storage);
+        }
+    }
+
+    Ok(elements)
+}
+
+fn is_known_storage(storage: &str) -> bool {
+    matches!(
+        storage,
+        "building"
+            | "character"
+            | "culture"
+            | "event"
+            | "mountain"
+            | "organization"
+            | "river"
+            | "street"
+            | "town"
+    )
+}
+
+fn find_in_storage<I: Id, T: Element<I> + WithName, F: Fn(I) -> ElementRef>(
+    elements: &mut Vec<ElementRef>,
+    storage: &Storage<I, T>,
+    name: &str,
+    to_ref: F,
+) {
+    elements.extend(
+        storage
+            .get_all()
+            .iter()
+            .filter(|element| element.name().str().eq(name))
+            .map(|element| to_ref(element.id())),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::culture::Culture;
+    use crate::model::character::name::{CharacterName, Lastname};
+    use crate::model::character::naming::NameOrder;
+    use crate::model::character::Character;
+    use crate::model::name::Name;
+    use crate::model::world::river::River;
+    use crate::model::world::street::Street;
+    use crate::model::world::town::{Town, TownId};
+    use crate::usecase::edit::description::update_description;
+    use crate::usecase::edit::name::update_name;
+
+    #[test]
+    fn resolve_without_type() {
+        let data = init();
+
+        assert_eq!(
+            resolve_link(&data, "Arkham").unwrap(),
+            ElementRef::Town(TownId::new(0))
+        );
+    }
+
+    #[test]
+    fn resolve_with_type() {
+        let data = init();
+
+        assert_eq!(
+            resolve_link(&data, "town: Miskatonic").unwrap(),
+            ElementRef::Town(TownId::new(1))
+        );
+    }
+
+    #[test]
+    fn resolve_ambiguous_link() {
+        let data = init();
+
+        assert!(resolve_link(&data, "Miskatonic").is_err());
+    }
+
+    #[test]
+    fn resolve_unknown_name() {
+        let data = init();
+
+        assert!(resolve_link(&data, "Dunwich").is_err());
+        assert!(resolve_link(&data, "river:Arkham").is_err());
+    }
+
+    #[test]
+    fn resolve_unknown_type() {
+        let data = init();
+
+        assert!(resolve_link(&data, "planet:Arkham").is_err());
+    }
+
+    #[test]
+    fn resolve_character_by_formatted_name() {
+        let mut data = init();
+        let culture = data.cultures.create(Culture::new);
+        data.cultures.get_mut(culture).unwrap().naming.order = NameOrder::FamilyFirst;
+        let id = data.characters.create(Character::new);
+        let character = data.characters.get_mut(id).unwrap();
+        character.culture = culture;
+        character.name = CharacterName::simple(
+            Name::new("Taro").unwrap(),
+            Lastname::Family {
+                name: Name::new("Tanaka").unwrap(),
+            },
+        );
+
+        assert_eq!(
+            resolve_link(&data, "Tanaka Taro").unwrap(),
+            ElementRef::Character(id)
+        );
+        assert_eq!(
+            resolve_link(&data, "character:Taro Tanaka").unwrap(),
+            ElementRef::Character(id)
+        );
+    }
+
+    #[test]
+    fn find_broken_links() {
+        let mut data = init();
+        let id = TownId::new(0);
+        update_description(
+            &mut data.town_manager,
+            id,
+            "Near the [[river:Miskatonic]], far from [[Dunwich]].",
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_broken_links(&data),
+            vec![BrokenLink {
+                element: ElementRef::Town(id),
+                link: "Dunwich".to_string(),
+                error: "Found no element named 'Dunwich'!".to_string(),
+            }]
+        );
+    }
+
+    fn init() -> RpgData {
+        let mut data = RpgData::default();
+        let arkham = data.town_manager.create(Town::new);
+        let miskatonic = data.town_manager.create(Town::new);
+        let river = data.river_manager.create(River::new);
+        data.street_manager.create(Street::new);
+        update_name(&mut data.town_manager, arkham, "Arkham").unwrap();
+        update_name(&mut data.town_manager, miskatonic, "Miskatonic").unwrap();
+        update_name(&mut data.river_manager, river, "Miskatonic").unwrap();
+        data
+    }
+}
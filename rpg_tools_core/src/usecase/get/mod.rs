@@ -1,3 +1,13 @@
+pub mod address;
+pub mod age;
+pub mod backlink;
+pub mod entrance;
+pub mod event;
+pub mod family;
+pub mod link;
 pub mod name;
+pub mod occupant;
+pub mod organization;
+pub mod pronoun;
 pub mod town;
 pub mod towns;
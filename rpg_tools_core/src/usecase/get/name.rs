@@ -1,5 +1,10 @@
-use crate::model::character::Character;
-use crate::model::name::Name;
+use crate::model::character::naming::{NameFormat, NamingConvention};
+use crate::model::character::{Character, CharacterId};
+use crate::model::element::ElementRef;
+use crate::model::name::{Name, WithName};
+use crate::model::RpgData;
+use crate::utils::storage::{Element, Id, Storage};
+use anyhow::Result;
 
 pub fn get_str(name: Option<&Name>) -> &str {
     name.map(|n| n.str()).unwrap_or("")
@@ -16,3 +21,49 @@ pub fn get_middle_name(character: &Character) -> &str {
 pub fn get_last_name(character: &Character) -> &str {
     get_str(character.name.last().name())
 }
+
+/// Returns the name of a [`character`](Character) formatted according to its culture.
+///
+/// Fails, if the gender of the character is unknown.
+pub fn format_character_name(
+    data: &RpgData,
+    character: &Character,
+    format: NameFormat,
+) -> Result<String> {
+    let gender = data.genders.get_base(&character.gender)?;
+
+    Ok(data
+        .cultures
+        .get(character.culture)
+        .map(|culture| &culture.naming)
+        .unwrap_or(&NamingConvention::default())
+        .format(&character.name, gender, format))
+}
+
+/// Returns the full name of a [`character`](Character) formatted according to its culture,
+/// or the unformatted name, if its gender is unknown.
+pub fn get_character_name(data: &RpgData, id: CharacterId) -> Option<String> {
+    data.characters.get(id).map(|character| {
+        format_character_name(data, character, NameFormat::Full)
+            .unwrap_or_else(|_| character.name.to_string())
+    })
+}
+
+/// Returns the name of any [`element`](ElementRef).
+pub fn get_element_name(data: &RpgData, element: ElementRef) -> Option<String> {
+    match element {
+        ElementRef::Building(id) => get_name(&data.building_manager, id),
+        ElementRef::Character(id) => get_character_name(data, id),
+        ElementRef::Culture(id) => get_name(&data.cultures, id),
+        ElementRef::Event(id) => get_name(&data.events, id),
+        ElementRef::Mountain(id) => get_name(&data.mountain_manager, id),
+        ElementRef::Organization(id) => get_name(&data.organizations, id),
+        ElementRef::River(id) => get_name(&data.river_manager, id),
+        ElementRef::Street(id) => get_name(&data.street_manager, id),
+        ElementRef::Town(id) => get_name(&data.town_manager, id),
+    }
+}
+
+fn get_name<I: Id, T: Element<I> + WithName>(storage: &Storage<I, T>, id: I) -> Option<String> {
+    storage.get(id).map(|element| element.name().to_string())
+}
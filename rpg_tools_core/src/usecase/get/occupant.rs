@@ -0,0 +1,48 @@
+use crate::model::character::{Character, CharacterId};
+use crate::model::world::building::BuildingId;
+use crate::model::RpgData;
+use crate::utils::storage::Element;
+
+/// Returns the characters living in a [`building`](crate::model::world::building::Building).
+pub fn get_residents(data: &RpgData, building: BuildingId) -> Vec<CharacterId> {
+    get_occupants(data, |character| character.home == Some(building))
+}
+
+/// Returns the characters working in a [`building`](crate::model::world::building::Building).
+pub fn get_workers(data: &RpgData, building: BuildingId) -> Vec<CharacterId> {
+    get_occupants(data, |character| character.workplace == Some(building))
+}
+
+fn get_occupants<F: Fn(&Character) -> bool>(data: &RpgData, filter: F) -> Vec<CharacterId> {
+    data.characters
+        .get_all()
+        .iter()
+        .filter(|&character| filter(character))
+        .map(|character| character.id())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::storage::Id;
+
+    #[test]
+    fn get_residents_and_workers() {
+        let mut data = RpgData::default();
+        let building0 = BuildingId::new(0);
+        let building1 = BuildingId::new(1);
+        let id0 = data.characters.create(Character::new);
+        let id1 = data.characters.create(Character::new);
+        let id2 = data.characters.create(Character::new);
+        data.characters.get_mut(id0).unwrap().home = Some(building0);
+        data.characters.get_mut(id1).unwrap().home = Some(building0);
+        data.characters.get_mut(id1).unwrap().workplace = Some(building1);
+        data.characters.get_mut(id2).unwrap().workplace = Some(building0);
+
+        assert_eq!(get_residents(&data, building0), vec![id0, id1]);
+        assert_eq!(get_workers(&data, building0), vec![id2]);
+        assert!(get_residents(&data, building1).is_empty());
+        assert_eq!(get_workers(&data, building1), vec![id1]);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::model::character::CharacterId;
+use crate::model::organization::OrganizationId;
+use crate::model::world::building::BuildingId;
+use crate::model::RpgData;
+use crate::utils::storage::Element;
+
+/// Returns the organizations, whose parent is the given one.
+pub fn get_children(data: &RpgData, id: OrganizationId) -> Vec<OrganizationId> {
+    data.organizations
+        .get_all()
+        .iter()
+        .filter(|organization| organization.parent == Some(id))
+        .map(|organization| organization.id())
+        .collect()
+}
+
+/// Returns the organizations & roles of a [`character`](crate::model::character::Character).
+pub fn get_memberships(data: &RpgData, character: CharacterId) -> Vec<(OrganizationId, &str)> {
+    data.organizations
+        .get_all()
+        .iter()
+        .filter_map(|organization| {
+            organization
+                .get_member(character)
+                .map(|member| (organization.id(), member.role.as_str()))
+        })
+        .collect()
+}
+
+/// Returns the organizations, which use a building as headquarters or own it.
+pub fn get_owners(data: &RpgData, building: BuildingId) -> Vec<OrganizationId> {
+    data.organizations
+        .get_all()
+        .iter()
+        .filter(|organization| organization.get_all_buildings().contains(&building))
+        .map(|organization| organization.id())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::Character;
+    use crate::model::organization::{Member, Organization};
+    use crate::utils::storage::Id;
+
+    #[test]
+    fn test_get_children() {
+        let mut data = RpgData::default();
+        let parent = data.organizations.create(Organization::new);
+        let child = data.organizations.create(Organization::new);
+        data.organizations.get_mut(child).unwrap().parent = Some(parent);
+
+        assert_eq!(get_children(&data, parent), vec![child]);
+        assert!(get_children(&data, child).is_empty());
+    }
+
+    #[test]
+    fn test_get_memberships() {
+        let mut data = RpgData::default();
+        let character = data.characters.create(Character::new);
+        let id0 = data.organizations.create(Organization::new);
+        let id1 = data.organizations.create(Organization::new);
+        data.organizations.create(Organization::new);
+        data.organizations
+            .get_mut(id0)
+            .unwrap()
+            .members
+            .push(Member::new(character, "Leader"));
+        data.organizations
+            .get_mut(id1)
+            .unwrap()
+            .members
+            .push(Member::new(character, "Spy"));
+
+        assert_eq!(
+            get_memberships(&data, character),
+            vec![(id0, "Leader"), (id1, "Spy")]
+        );
+    }
+
+    #[test]
+    fn test_get_owners() {
+        let mut data = RpgData::default();
+        let building = BuildingId::new(1);
+        let id0 = data.organizations.create(Organization::new);
+        let id1 = data.organizations.create(Organization::new);
+        data.organizations.create(Organization::new);
+        data.organizations.get_mut(id0).unwrap().headquarters = Some(building);
+        data.organizations.get_mut(id1).unwrap().buildings = vec![building];
+
+        assert_eq!(get_owners(&data, building), vec![id0, id1]);
+    }
+}
@@ -0,0 +1,107 @@
+use crate::model::character::gender::Pronouns;
+use crate::model::character::naming::NameFormat;
+use crate::model::character::CharacterId;
+use crate::model::RpgData;
+use crate::usecase::get::name::format_character_name;
+use crate::utils::template::fill_template;
+use anyhow::{Context, Result};
+
+/// Returns the [`pronouns`](Pronouns) of a [`character`](crate::model::character::Character)
+/// based on its gender.
+pub fn get_pronouns(data: &RpgData, id: CharacterId) -> Result<&Pronouns> {
+    let character = data.characters.get(id).context("Character doesn't exist")?;
+
+    Ok(&data.genders.parse(&character.gender)?.pronouns)
+}
+
+/// Fills a template with the names & pronouns of a
+/// [`character`](crate::model::character::Character), e.g. for generated descriptions.
+///
+/// Supports `{name}`, `{short}`, `{formal}`, `{subject}`, `{object}` & `{possessive}`,
+/// which are capitalized, if they start with an uppercase letter like `{Subject}`.
+pub fn format_character_text(data: &RpgData, id: CharacterId, template: &str) -> Result<String> {
+    let character = data.characters.get(id).context("Character doesn't exist")?;
+    let pronouns = get_pronouns(data, id)?;
+
+    fill_template(template, |key| match key {
+        "name" => format_character_name(data, character, NameFormat::Full).ok(),
+        "short" => format_character_name(data, character, NameFormat::Short).ok(),
+        "formal" => format_character_name(data, character, NameFormat::Formal).ok(),
+        "subject" => Some(pronouns.subject.clone()),
+        "object" => Some(pronouns.object.clone()),
+        "possessive" => Some(pronouns.possessive.clone()),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::gender::{Gender, GenderDefinition};
+    use crate::model::character::name::CharacterName;
+    use crate::model::character::Character;
+    use crate::model::name::Name;
+    use crate::utils::storage::Id;
+
+    fn init(gender: &str) -> (RpgData, CharacterId) {
+        let mut data = RpgData::default();
+        data.genders.genders.push(GenderDefinition::new(
+            "Nonbinary",
+            Gender::Genderless,
+            Pronouns::new("xe", "xem", "xyr"),
+        ));
+        let id = data.characters.create(Character::new);
+        let character = data.characters.get_mut(id).unwrap();
+        character.name = CharacterName::only_first(Name::new("Henry").unwrap());
+        character.gender = gender.to_string();
+
+        (data, id)
+    }
+
+    #[test]
+    fn default_genders() {
+        let (data, id) = init("Male");
+
+        assert_eq!(
+            format_character_text(
+                &data,
+                id,
+                "{name} reads. {Subject} likes {possessive} books."
+            )
+            .unwrap(),
+            "Henry reads. He likes his books."
+        );
+    }
+
+    #[test]
+    fn setting_defined_gender() {
+        let (data, id) = init("Nonbinary");
+
+        assert_eq!(
+            format_character_text(&data, id, "{Subject} gave {object} {possessive} book.").unwrap(),
+            "Xe gave xem xyr book."
+        );
+    }
+
+    #[test]
+    fn unknown_gender() {
+        let (data, id) = init("Unknown");
+
+        assert!(get_pronouns(&data, id).is_err());
+        assert!(format_character_text(&data, id, "{name}").is_err());
+    }
+
+    #[test]
+    fn invalid_template() {
+        let (data, id) = init("Female");
+
+        assert!(format_character_text(&data, id, "{unknown}").is_err());
+    }
+
+    #[test]
+    fn non_existing_character() {
+        let (data, _) = init("Female");
+
+        assert!(format_character_text(&data, CharacterId::new(1), "{name}").is_err());
+    }
+}
@@ -1,9 +1,11 @@
+use crate::model::visibility::{Audience, WithVisibility};
 use crate::model::world::building::lot::BuildingLot;
-use crate::model::world::building::BuildingId;
+use crate::model::world::building::{Building, BuildingId};
 use crate::model::world::street::StreetId;
 use crate::model::world::town::construction::Construction;
 use crate::model::world::town::terrain::Terrain;
-use crate::model::world::town::TownId;
+use crate::model::world::town::tile::TownTile;
+use crate::model::world::town::{Town, TownId};
 use crate::model::RpgData;
 
 // Construction
@@ -78,6 +80,49 @@ pub fn is_any_street(data: &RpgData, town_id: TownId, tile: usize) -> bool {
     })
 }
 
+/// Can the [`audience`](Audience) see the [`construction`](Construction) of the [`tile`](TownTile)?
+pub fn is_construction_visible(data: &RpgData, tile: &TownTile, audience: Audience) -> bool {
+    if !tile.visibility.is_visible_to(audience) {
+        return false;
+    }
+
+    match tile.construction {
+        Construction::Building { id } => data
+            .building_manager
+            .get(id)
+            .map(|building| building.is_visible_to(audience))
+            .unwrap_or(false),
+        Construction::Street { id } => data
+            .street_manager
+            .get(id)
+            .map(|street| street.is_visible_to(audience))
+            .unwrap_or(false),
+        Construction::None => true,
+    }
+}
+
+/// Can the [`audience`](Audience) see the [`building`](Building) & all tiles of its lot in the [`town`](Town)?
+pub fn is_building_visible(
+    data: &RpgData,
+    town: &Town,
+    building: &Building,
+    audience: Audience,
+) -> bool {
+    building.is_visible_to(audience)
+        && building
+            .lot
+            .get_tiles(town.map.get_size())
+            .map(|tiles| {
+                tiles.into_iter().all(|index| {
+                    town.map
+                        .get_tile(index)
+                        .map(|tile| is_construction_visible(data, tile, audience))
+                        .unwrap_or(false)
+                })
+            })
+            .unwrap_or(false)
+}
+
 // Terrain
 
 pub fn is_terrain(data: &RpgData, town_id: TownId, tile: usize, terrain: &Terrain) -> bool {
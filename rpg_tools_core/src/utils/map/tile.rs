@@ -35,6 +35,21 @@ impl<Tile: Clone> TileMap<Tile> {
         Self::new(size, tiles).unwrap()
     }
 
+    /// Rotates an edge map clockwise by 90°.
+    pub fn rotate_clockwise(&self) -> TileMap<Tile> {
+        let size = Size2d::new(self.size.height() as u32, self.size.width() as u32);
+        let mut tiles = Vec::with_capacity(self.tiles.len());
+
+        for y in 0..size.height() {
+            for x in 0..size.width() {
+                let old_index = self.size.to_index_risky(y, self.size.height() - 1 - x);
+                tiles.push(self.tiles[old_index].clone());
+            }
+        }
+
+        Self::new(size, tiles).unwrap()
+    }
+
     pub fn get_size(&self) -> Size2d {
         self.size
     }
@@ -111,6 +126,14 @@ mod tests {
         assert_eq!(new_map, old_map.resize(new_size, 0));
     }
 
+    #[test]
+    fn test_rotate_clockwise() {
+        let old_map = TileMap::new(Size2d::new(3, 2), vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let new_map = TileMap::new(Size2d::new(2, 3), vec![4, 1, 5, 2, 6, 3]).unwrap();
+
+        assert_eq!(new_map, old_map.rotate_clockwise());
+    }
+
     #[test]
     fn test_get_invalid_tile() {
         let size = Size2d::new(2, 3);
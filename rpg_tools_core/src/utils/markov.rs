@@ -0,0 +1,144 @@
+use crate::utils::text::capitalize;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashMap;
+
+const START: char = '^';
+const END: char = '$';
+const MAX_ATTEMPTS: usize = 100;
+
+/// A character based markov chain, which generates new words similar to its training examples.
+#[derive(Clone, Debug, Default)]
+pub struct MarkovChain {
+    order: usize,
+    transitions: HashMap<String, Vec<char>>,
+    min_length: usize,
+    max_length: usize,
+}
+
+impl MarkovChain {
+    /// Trains a markov chain with the given order on the examples.
+    pub fn train<S: AsRef<str>>(examples: &[S], order: usize) -> Self {
+        let order = order.max(1);
+        let mut transitions: HashMap<String, Vec<char>> = HashMap::new();
+        let mut min_length = usize::MAX;
+        let mut max_length = 0;
+
+        for example in examples {
+            let word: Vec<char> = example.as_ref().trim().to_lowercase().chars().collect();
+
+            if word.is_empty() {
+                continue;
+            }
+
+            min_length = min_length.min(word.len());
+            max_length = max_length.max(word.len());
+
+            let mut state: Vec<char> = vec![START; order];
+
+            for &c in word.iter().chain([END].iter()) {
+                transitions
+                    .entry(state.iter().collect())
+                    .or_default()
+                    .push(c);
+                state.remove(0);
+                state.push(c);
+            }
+        }
+
+        Self {
+            order,
+            transitions,
+            min_length: min_length.min(max_length),
+            max_length,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.transitions.is_empty()
+    }
+
+    /// Generates a capitalized word, which is as long as the shortest & the longest example.
+    ///
+    /// ```
+    ///# use rand::SeedableRng;
+    ///# use rand_chacha::ChaCha8Rng;
+    ///# use rpg_tools_core::utils::markov::MarkovChain;
+    /// let chain = MarkovChain::train(&["anna"], 2);
+    /// let mut rng = ChaCha8Rng::seed_from_u64(42);
+    ///
+    /// assert_eq!(chain.generate(&mut rng), Some("Anna".to_string()));
+    /// assert_eq!(MarkovChain::train::<&str>(&[], 2).generate(&mut rng), None);
+    /// ```
+    pub fn generate<R: Rng>(&self, rng: &mut R) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+
+        for _ in 0..MAX_ATTEMPTS {
+            if let Some(word) = self.try_generate(rng) {
+                return Some(capitalize(&word));
+            }
+        }
+
+        None
+    }
+
+    fn try_generate<R: Rng>(&self, rng: &mut R) -> Option<String> {
+        let mut state: Vec<char> = vec![START; self.order];
+        let mut word = String::new();
+
+        loop {
+            let next = *self
+                .transitions
+                .get(&state.iter().collect::<String>())?
+                .choose(rng)?;
+
+            if next == END {
+                let length = word.chars().count();
+
+                return (length >= self.min_length && length <= self.max_length).then_some(word);
+            } else if word.chars().count() >= self.max_length {
+                return None;
+            }
+
+            word.push(next);
+            state.remove(0);
+            state.push(next);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    const EXAMPLES: [&str; 6] = ["Aldric", "Alwin", "Baldwin", "Edric", "Edwin", "Godric"];
+
+    #[test]
+    fn same_seed_generates_same_words() {
+        let chain = MarkovChain::train(&EXAMPLES, 2);
+        let mut rng0 = ChaCha8Rng::seed_from_u64(7);
+        let mut rng1 = ChaCha8Rng::seed_from_u64(7);
+
+        for _ in 0..10 {
+            assert_eq!(chain.generate(&mut rng0), chain.generate(&mut rng1));
+        }
+    }
+
+    #[test]
+    fn generated_words_respect_length() {
+        let chain = MarkovChain::train(&EXAMPLES, 2);
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+
+        for _ in 0..20 {
+            let word = chain.generate(&mut rng).unwrap();
+            let length = word.chars().count();
+
+            assert!((5..=7).contains(&length), "{}", word);
+            assert!(word.chars().next().unwrap().is_uppercase());
+        }
+    }
+}
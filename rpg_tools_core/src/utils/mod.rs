@@ -1,3 +1,6 @@
 pub mod io;
 pub mod map;
+pub mod markov;
 pub mod storage;
+pub mod template;
+pub mod text;
@@ -67,6 +67,10 @@ impl<I: Id, T: Element<I>> Storage<I, T> {
         &self.elements
     }
 
+    pub fn get_all_mut(&mut self) -> &mut [T] {
+        &mut self.elements
+    }
+
     pub fn get(&self, id: I) -> Option<&T> {
         self.elements.get(id.id())
     }
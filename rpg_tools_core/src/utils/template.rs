@@ -0,0 +1,108 @@
+use crate::utils::text::capitalize;
+use anyhow::{bail, Context, Result};
+
+/// Replaces the placeholders like `{name}` of a template with their values.
+///
+/// A placeholder starting with an uppercase letter gets a capitalized value,
+/// while `{{` & `}}` are escaped braces.
+///
+/// ```
+///# use rpg_tools_core::utils::template::fill_template;
+/// let lookup = |key: &str| (key == "subject").then(|| "she".to_string());
+///
+/// assert_eq!(fill_template("{Subject} says {{hi}}.", lookup).unwrap(), "She says {hi}.");
+/// assert!(fill_template("{object}", lookup).is_err());
+/// assert!(fill_template("{subject", lookup).is_err());
+/// ```
+pub fn fill_template<F: Fn(&str) -> Option<String>>(template: &str, lookup: F) -> Result<String> {
+    let mut result = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                result.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                result.push('}');
+            }
+            '{' => {
+                let mut key = String::new();
+
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => key.push(c),
+                        None => bail!("The placeholder '{{{}' is not closed!", key),
+                    }
+                }
+
+                result.push_str(&replace(&key, &lookup)?);
+            }
+            '}' => bail!("Unexpected '}}' in template!"),
+            c => result.push(c),
+        }
+    }
+
+    Ok(result)
+}
+
+fn replace<F: Fn(&str) -> Option<String>>(key: &str, lookup: &F) -> Result<String> {
+    let lowercase = key.to_lowercase();
+    let value = lookup(&lowercase).context(format!("Unknown placeholder '{{{}}}'!", key))?;
+
+    if key.starts_with(|c: char| c.is_uppercase()) {
+        Ok(capitalize(&value))
+    } else {
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(key: &str) -> Option<String> {
+        match key {
+            "name" => Some("Henry Armitage".to_string()),
+            "possessive" => Some("his".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn without_placeholders() {
+        assert_eq!(fill_template("", lookup).unwrap(), "");
+        assert_eq!(fill_template("A test", lookup).unwrap(), "A test");
+    }
+
+    #[test]
+    fn replace_placeholders() {
+        assert_eq!(
+            fill_template("{name} lost {possessive} book.", lookup).unwrap(),
+            "Henry Armitage lost his book."
+        );
+    }
+
+    #[test]
+    fn capitalize_placeholders() {
+        assert_eq!(
+            fill_template("{Possessive} book.", lookup).unwrap(),
+            "His book."
+        );
+    }
+
+    #[test]
+    fn escaped_braces() {
+        assert_eq!(fill_template("{{name}}", lookup).unwrap(), "{name}");
+    }
+
+    #[test]
+    fn invalid_templates() {
+        assert!(fill_template("{unknown}", lookup).is_err());
+        assert!(fill_template("{name", lookup).is_err());
+        assert!(fill_template("name}", lookup).is_err());
+    }
+}
@@ -0,0 +1,16 @@
+/// Returns the text with an uppercase first letter.
+///
+/// ```
+///# use rpg_tools_core::utils::text::capitalize;
+/// assert_eq!(capitalize("town"), "Town");
+/// assert_eq!(capitalize("ärger"), "Ärger");
+/// assert_eq!(capitalize(""), "");
+/// ```
+pub fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
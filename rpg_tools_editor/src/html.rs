@@ -1,5 +1,16 @@
+use crate::route::event::EventBuilder;
+use crate::route::util::link_element_details;
+use rpg_tools_core::model::element::ElementRef;
 use rpg_tools_core::model::math::size2d::Size2d;
+use rpg_tools_core::model::visibility::Audience;
+use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::get::backlink::get_backlinks;
+use rpg_tools_core::usecase::get::event::get_history;
+use rpg_tools_core::usecase::get::link::{replace_links, resolve_link};
+use rpg_tools_core::usecase::get::name::get_element_name;
 use rpg_tools_core::utils::storage::{Element, Id, Storage};
+use rpg_tools_core::utils::text::capitalize;
+use rpg_tools_html::escape::{escape_html, escape_markdown};
 use rpg_tools_html::HtmlBuilder;
 
 pub fn create_html() -> HtmlBuilder {
@@ -15,6 +26,13 @@ pub trait EditorBuilder {
         link: &str,
         storage: &Storage<ID, ELEMENT>,
     ) -> Self;
+
+    fn add_description(self, data: &RpgData, description: Option<&str>) -> Self;
+
+    fn add_backlinks(self, data: &RpgData, element: ElementRef) -> Self;
+
+    /// Adds the events of the element in chronological order.
+    fn add_history(self, data: &RpgData, element: ElementRef) -> Self;
 }
 
 impl EditorBuilder for HtmlBuilder {
@@ -36,4 +54,56 @@ impl EditorBuilder for HtmlBuilder {
                 .complex_link(link, |a| a.usize(storage.len()))
         })
     }
+
+    fn add_description(self, data: &RpgData, description: Option<&str>) -> Self {
+        if let Some(description) = description {
+            let mut broken: Vec<(String, String)> = Vec::new();
+            let markdown = replace_links(description, |link| match resolve_link(data, link) {
+                Ok(element) => format!(
+                    "[{}]({})",
+                    escape_markdown(&get_element_name(data, element).unwrap_or_default()),
+                    link_element_details(element)
+                ),
+                Err(e) => {
+                    broken.push((link.to_string(), e.to_string()));
+                    format!(r#"<span class="error">{}</span>"#, escape_markdown(link))
+                }
+            });
+
+            self.h2("Description").markdown(&markdown).option(
+                (!broken.is_empty()).then_some(broken),
+                |broken, b| {
+                    b.h3("Broken Links").list(&broken, |b, (link, error)| {
+                        b.text(&format!(
+                            r#"<span class="error">[[{}]]: {}</span>"#,
+                            escape_html(link),
+                            escape_html(error)
+                        ))
+                    })
+                },
+            )
+        } else {
+            self
+        }
+    }
+
+    fn add_backlinks(self, data: &RpgData, element: ElementRef) -> Self {
+        let backlinks = get_backlinks(data, element);
+
+        self.h2("Referenced by")
+            .field_usize("Count:", backlinks.len())
+            .list(&backlinks, |b, &source| {
+                let name = get_element_name(data, source).unwrap_or_default();
+                b.text(&format!("{}:", capitalize(source.storage())))
+                    .link(&link_element_details(source), &name)
+            })
+    }
+
+    fn add_history(self, data: &RpgData, element: ElementRef) -> Self {
+        let history = get_history(data, element, Audience::GameMaster);
+
+        self.h2("History")
+            .field_usize("Count:", history.len())
+            .add_events(data, &history)
+    }
 }
@@ -4,6 +4,9 @@ extern crate rocket;
 use crate::init::init;
 use crate::route::get_routes;
 use rocket::fs::FileServer;
+use rpg_tools_core::model::math::size2d::Size2d;
+use rpg_tools_core::model::visibility::Visibility;
+use rpg_tools_core::model::world::building::interior::InteriorTile;
 use rpg_tools_core::model::world::street::StreetId;
 use rpg_tools_core::model::RpgData;
 use rpg_tools_rendering::usecase::map::TileMapRenderer;
@@ -16,8 +19,11 @@ mod svg;
 
 pub struct ToolData {
     selected_street: StreetId,
+    street_visibility: Visibility,
     terrain: String,
     id: Option<usize>,
+    interior: InteriorTile,
+    building_size: Size2d,
 }
 
 pub struct EditorData {
@@ -34,8 +40,11 @@ fn rocket() -> _ {
             town_renderer: TileMapRenderer::new(100, 1),
             tools: Mutex::new(ToolData {
                 selected_street: StreetId::default(),
+                street_visibility: Visibility::default(),
                 terrain: "Plain".to_string(),
                 id: None,
+                interior: InteriorTile::Wall,
+                building_size: Size2d::square(1),
             }),
         })
         .mount("/static", FileServer::from("rpg_tools_editor/static/"))
@@ -0,0 +1,153 @@
+use crate::html::create_html;
+use crate::route::building::link_building_details;
+use crate::route::town::link_town_map;
+use crate::EditorData;
+use anyhow::{Context, Result};
+use rocket::form::Form;
+use rocket::response::content::RawHtml;
+use rocket::State;
+use rpg_tools_core::model::math::side2d::Side2d;
+use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::world::building::entrance::Entrance;
+use rpg_tools_core::model::world::building::{Building, BuildingId};
+use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::edit::entrance::{add_entrance, remove_entrance};
+use rpg_tools_core::usecase::get::entrance::get_entrance_street;
+use rpg_tools_core::utils::storage::Id;
+
+#[get("/building/<id>/entrance/editor")]
+pub fn get_entrance_editor(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let data = state.data.lock().expect("lock shared data");
+    get_entrance_editor_html(&data, BuildingId::new(id), "")
+}
+
+pub fn link_entrance_editor(id: BuildingId) -> String {
+    uri!(get_entrance_editor(id.id())).to_string()
+}
+
+#[derive(FromForm, Debug)]
+pub struct EntranceUpdate<'r> {
+    tile: usize,
+    side: &'r str,
+}
+
+#[post("/building/<id>/entrance/add", data = "<update>")]
+pub fn add_entrance_route(
+    state: &State<EditorData>,
+    id: usize,
+    update: Form<EntranceUpdate<'_>>,
+) -> Option<RawHtml<String>> {
+    println!("Add entrance to building {} with {:?}", id, update);
+    let mut data = state.data.lock().expect("lock shared data");
+    let building_id = BuildingId::new(id);
+
+    let error = match get_entrance(&data, building_id, update.tile, update.side)
+        .and_then(|entrance| add_entrance(&mut data, building_id, entrance))
+    {
+        Ok(()) => String::new(),
+        Err(e) => {
+            println!("Failed to add entrance to building {}: {}", id, e);
+            e.to_string()
+        }
+    };
+
+    get_entrance_editor_html(&data, building_id, &error)
+}
+
+#[get("/building/<id>/entrance/<index>/delete")]
+pub fn delete_entrance(
+    state: &State<EditorData>,
+    id: usize,
+    index: usize,
+) -> Option<RawHtml<String>> {
+    let mut data = state.data.lock().expect("lock shared data");
+    let building_id = BuildingId::new(id);
+
+    if let Err(e) = remove_entrance(&mut data, building_id, index) {
+        println!(
+            "Failed to delete entrance {} of building {}: {}",
+            index, id, e
+        );
+    } else {
+        println!("Deleted entrance {} of building {}", index, id);
+    }
+
+    get_entrance_editor_html(&data, building_id, "")
+}
+
+/// Returns the name of the street in front of the entrance or a warning.
+pub fn get_entrance_text(data: &RpgData, building: &Building, entrance: &Entrance) -> String {
+    let street = get_entrance_street(data, building, entrance)
+        .and_then(|id| data.street_manager.get(id))
+        .map(|street| street.name().to_string())
+        .unwrap_or_else(|| "No Street!".to_string());
+
+    format!(
+        "Tile {}, {} ({}): {}",
+        entrance.x, entrance.y, entrance.side, street
+    )
+}
+
+/// Creates an entrance from the index of a tile of the lot & a side.
+fn get_entrance(data: &RpgData, id: BuildingId, tile: usize, side: &str) -> Result<Entrance> {
+    let (x, y) = data
+        .building_manager
+        .get(id)
+        .context("Building doesn't exist")?
+        .lot
+        .get_local_positions()
+        .get(tile)
+        .copied()
+        .context("Unknown tile")?;
+
+    Ok(Entrance::new(x, y, Side2d::parse(side)?))
+}
+
+fn get_entrance_editor_html(
+    data: &RpgData,
+    id: BuildingId,
+    error: &str,
+) -> Option<RawHtml<String>> {
+    let add_uri = uri!(add_entrance_route(id.id())).to_string();
+    let sides: Vec<String> = Side2d::get_all()
+        .iter()
+        .map(|side| side.to_string())
+        .collect();
+    let sides: Vec<&str> = sides.iter().map(|side| side.as_str()).collect();
+
+    data.building_manager.get(id).map(|building| {
+        let tiles: Vec<String> = building
+            .lot
+            .get_local_positions()
+            .iter()
+            .map(|(x, y)| format!("{}, {}", x, y))
+            .collect();
+        let tiles: Vec<(usize, &str)> =
+            tiles.iter().map(|tile| tile.as_str()).enumerate().collect();
+        let mut builder = create_html()
+            .h1(&format!("Entrances of Building: {}", building.name().str()))
+            .field_usize("Entrances:", building.entrances.len());
+
+        for (index, entrance) in building.entrances.iter().enumerate() {
+            let delete_uri = uri!(delete_entrance(id.id(), index)).to_string();
+
+            builder = builder.p(|b| {
+                b.text(&get_entrance_text(data, building, entrance))
+                    .text(" ")
+                    .link(&delete_uri, "Delete")
+            });
+        }
+
+        let builder = builder
+            .h2("Add Entrance")
+            .form(&add_uri, |b| {
+                b.select_id("Tile", "tile", &tiles, 0)
+                    .select("Side", "side", &sides, &Side2d::Bottom.to_string())
+                    .error(error)
+            })
+            .center(|b| b.svg(&link_town_map(building.lot.town), "800"))
+            .p(|b| b.link(&link_building_details(id), "Back"));
+
+        RawHtml(builder.finish())
+    })
+}
@@ -0,0 +1,232 @@
+use crate::html::create_html;
+use crate::route::building::{get_building_details_html, link_building_details};
+use crate::svg::RawSvg;
+use crate::{EditorData, ToolData};
+use rocket::form::Form;
+use rocket::response::content::RawHtml;
+use rocket::State;
+use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::world::building::interior::InteriorTile;
+use rpg_tools_core::model::world::building::BuildingId;
+use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::edit::interior::{
+    add_floor, edit_interior, remove_floor, update_floor_name,
+};
+use rpg_tools_core::utils::storage::Id;
+use rpg_tools_rendering::usecase::map::interior::render_floor;
+
+#[get("/building/<id>/interior")]
+pub fn get_interior(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let data = state.data.lock().expect("lock shared data");
+    get_interior_html(&data, BuildingId::new(id))
+}
+
+pub fn link_interior(id: BuildingId) -> String {
+    uri!(get_interior(id.id())).to_string()
+}
+
+#[get("/building/<id>/floor/add")]
+pub fn add_floor_route(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let mut data = state.data.lock().expect("lock shared data");
+    let tools = state.tools.lock().expect("lock shared data");
+    let building_id = BuildingId::new(id);
+
+    match add_floor(&mut data, building_id) {
+        Ok(floor) => {
+            println!("Added floor {} to building {}", floor, id);
+            get_floor_editor_html(&data, &tools, building_id, floor, "")
+        }
+        Err(e) => {
+            println!("Failed to add a floor to building {}: {}", id, e);
+            get_building_details_html(&data, building_id)
+        }
+    }
+}
+
+#[get("/building/<id>/floor/<floor>/delete")]
+pub fn delete_floor(state: &State<EditorData>, id: usize, floor: usize) -> Option<RawHtml<String>> {
+    let mut data = state.data.lock().expect("lock shared data");
+    let building_id = BuildingId::new(id);
+
+    if let Err(e) = remove_floor(&mut data, building_id, floor) {
+        println!("Failed to delete floor {} of building {}: {}", floor, id, e);
+    } else {
+        println!("Deleted floor {} of building {}", floor, id);
+    }
+
+    get_interior_html(&data, building_id)
+}
+
+#[get("/building/<id>/floor/<floor>/map.svg")]
+pub fn get_floor_map(state: &State<EditorData>, id: usize, floor: usize) -> Option<RawSvg> {
+    let data = state.data.lock().expect("lock shared data");
+
+    data.building_manager
+        .get(BuildingId::new(id))
+        .and_then(|building| building.floors.get(floor))
+        .map(|floor| {
+            let svg = render_floor(&state.town_renderer, floor, |_| None);
+            RawSvg::new(svg.export())
+        })
+}
+
+#[get("/building/<id>/floor/<floor>/editor")]
+pub fn get_floor_editor(
+    state: &State<EditorData>,
+    id: usize,
+    floor: usize,
+) -> Option<RawHtml<String>> {
+    let data = state.data.lock().expect("lock shared data");
+    let tools = state.tools.lock().expect("lock shared data");
+
+    get_floor_editor_html(&data, &tools, BuildingId::new(id), floor, "")
+}
+
+pub fn link_floor_editor(id: BuildingId, floor: usize) -> String {
+    uri!(get_floor_editor(id.id(), floor)).to_string()
+}
+
+#[derive(FromForm, Debug)]
+pub struct FloorEditorUpdate<'r> {
+    name: &'r str,
+    tile: &'r str,
+}
+
+#[post("/building/<id>/floor/<floor>/update", data = "<update>")]
+pub fn update_floor_editor(
+    state: &State<EditorData>,
+    id: usize,
+    floor: usize,
+    update: Form<FloorEditorUpdate<'_>>,
+) -> Option<RawHtml<String>> {
+    println!(
+        "Update editor of floor {} of building {} with {:?}",
+        floor, id, update
+    );
+    let mut data = state.data.lock().expect("lock shared data");
+    let mut tools = state.tools.lock().expect("lock shared data");
+    let building_id = BuildingId::new(id);
+
+    if let Ok(tile) = InteriorTile::parse(update.tile) {
+        tools.interior = tile;
+    }
+
+    let error = update_floor_name(&mut data, building_id, floor, update.name)
+        .err()
+        .map(|e| e.to_string())
+        .unwrap_or_default();
+
+    get_floor_editor_html(&data, &tools, building_id, floor, &error)
+}
+
+#[get("/building/<id>/floor/<floor>/editor.svg")]
+pub fn get_floor_editor_map(state: &State<EditorData>, id: usize, floor: usize) -> Option<RawSvg> {
+    let data = state.data.lock().expect("lock shared data");
+    let building_id = BuildingId::new(id);
+
+    data.building_manager
+        .get(building_id)
+        .and_then(|building| building.floors.get(floor))
+        .map(|map| {
+            let svg = render_floor(&state.town_renderer, map, |index| {
+                Some(link_edit_interior(building_id, floor, index))
+            });
+            RawSvg::new(svg.export())
+        })
+}
+
+#[get("/building/<id>/floor/<floor>/edit/<tile>")]
+pub fn edit_interior_route(
+    state: &State<EditorData>,
+    id: usize,
+    floor: usize,
+    tile: usize,
+) -> Option<RawHtml<String>> {
+    let mut data = state.data.lock().expect("lock shared data");
+    let tools = state.tools.lock().expect("lock shared data");
+    let building_id = BuildingId::new(id);
+
+    if let Err(e) = edit_interior(&mut data, building_id, floor, tile, tools.interior) {
+        println!(
+            "Failed to change tile {} of floor {} of building {}: {}",
+            tile, floor, id, e
+        );
+    } else {
+        println!(
+            "Changed tile {} of floor {} of building {} to {}",
+            tile, floor, id, tools.interior
+        );
+    }
+
+    get_floor_editor_html(&data, &tools, building_id, floor, "")
+}
+
+pub fn link_edit_interior(id: BuildingId, floor: usize, tile: usize) -> String {
+    uri!(edit_interior_route(id.id(), floor, tile)).to_string()
+}
+
+fn get_interior_html(data: &RpgData, id: BuildingId) -> Option<RawHtml<String>> {
+    let add_uri = uri!(add_floor_route(id.id())).to_string();
+
+    data.building_manager.get(id).map(|building| {
+        let floors: Vec<(usize, &str)> = building
+            .floors
+            .iter()
+            .enumerate()
+            .map(|(index, floor)| (index, floor.name.as_str()))
+            .collect();
+        let mut builder = create_html()
+            .h1(&format!("Interior of Building: {}", building.name().str()))
+            .field_usize("Floors:", floors.len());
+
+        for (index, name) in floors {
+            let map_uri = uri!(get_floor_map(id.id(), index)).to_string();
+            let delete_uri = uri!(delete_floor(id.id(), index)).to_string();
+
+            builder = builder.h2(name).center(|b| b.svg(&map_uri, "400")).p(|b| {
+                b.link(&link_floor_editor(id, index), "Edit")
+                    .text(" ")
+                    .link(&delete_uri, "Delete")
+            });
+        }
+
+        RawHtml(
+            builder
+                .p(|b| b.link(&add_uri, "Add Floor"))
+                .p(|b| b.link(&link_building_details(id), "Back"))
+                .finish(),
+        )
+    })
+}
+
+fn get_floor_editor_html(
+    data: &RpgData,
+    tools: &ToolData,
+    id: BuildingId,
+    floor: usize,
+    name_error: &str,
+) -> Option<RawHtml<String>> {
+    let map_uri = uri!(get_floor_editor_map(id.id(), floor)).to_string();
+    let update_uri = uri!(update_floor_editor(id.id(), floor)).to_string();
+    let tiles: Vec<String> = InteriorTile::get_all()
+        .iter()
+        .map(|tile| tile.to_string())
+        .collect();
+    let tiles: Vec<&str> = tiles.iter().map(|tile| tile.as_str()).collect();
+
+    data.building_manager.get(id).and_then(|building| {
+        building.floors.get(floor).map(|map| {
+            let builder = create_html()
+                .h1(&format!("Edit {} of {}", map.name, building.name().str()))
+                .form_with_change(&update_uri, &update_uri, |b| {
+                    b.text_input("Name", "name", &map.name)
+                        .error(name_error)
+                        .select("Tile", "tile", &tiles, &tools.interior.to_string())
+                })
+                .center(|b| b.svg(&map_uri, "800"))
+                .p(|b| b.link(&link_interior(id), "Back"));
+
+            RawHtml(builder.finish())
+        })
+    })
+}
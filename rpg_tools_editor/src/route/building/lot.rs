@@ -0,0 +1,145 @@
+use crate::html::create_html;
+use crate::route::building::link_building_details;
+use crate::svg::RawSvg;
+use crate::EditorData;
+use rocket::response::content::RawHtml;
+use rocket::State;
+use rpg_tools_core::model::color::Color;
+use rpg_tools_core::model::math::point2d::Point2d;
+use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::visibility::Audience;
+use rpg_tools_core::model::world::building::{Building, BuildingId};
+use rpg_tools_core::model::world::town::construction::Construction;
+use rpg_tools_core::model::world::town::tile::TownTile;
+use rpg_tools_core::model::world::town::Town;
+use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::edit::resize::update_lot_tiles;
+use rpg_tools_core::utils::storage::{Element, Id};
+use rpg_tools_rendering::renderer::svg::builder::SvgBuilder;
+use rpg_tools_rendering::usecase::map::town::{
+    get_visible_buildings, render_building, render_streets,
+};
+use rpg_tools_rendering::usecase::map::TileMapRenderer;
+
+#[get("/building/<id>/lot/editor")]
+pub fn get_lot_editor(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let data = state.data.lock().expect("lock shared data");
+    get_lot_editor_html(&data, BuildingId::new(id), "")
+}
+
+pub fn link_lot_editor(id: BuildingId) -> String {
+    uri!(get_lot_editor(id.id())).to_string()
+}
+
+#[get("/building/<id>/lot/editor.svg")]
+pub fn get_lot_editor_map(state: &State<EditorData>, id: usize) -> Option<RawSvg> {
+    let data = state.data.lock().expect("lock shared data");
+
+    data.building_manager
+        .get(BuildingId::new(id))
+        .and_then(|building| {
+            data.town_manager
+                .get(building.lot.town)
+                .map(|town| render_lot_editor_map(&data, &state.town_renderer, town, building))
+        })
+}
+
+/// Adds a free tile to the lot or removes one of its tiles.
+#[get("/building/<id>/lot/toggle/<tile>")]
+pub fn toggle_lot_tile(
+    state: &State<EditorData>,
+    id: usize,
+    tile: usize,
+) -> Option<RawHtml<String>> {
+    let mut data = state.data.lock().expect("lock shared data");
+    let building_id = BuildingId::new(id);
+    let mut tiles = data
+        .building_manager
+        .get(building_id)
+        .and_then(|building| {
+            data.town_manager
+                .get(building.lot.town)
+                .and_then(|town| building.lot.get_tiles(town.map.get_size()))
+        })
+        .unwrap_or_default();
+
+    if tiles.contains(&tile) {
+        tiles.retain(|&index| index != tile);
+    } else {
+        tiles.push(tile);
+    }
+
+    let error = match update_lot_tiles(&mut data, building_id, &tiles) {
+        Ok(()) => {
+            println!("Changed the lot of building {} to {:?}", id, tiles);
+            String::new()
+        }
+        Err(e) => {
+            println!("Failed to change the lot of building {}: {}", id, e);
+            e.to_string()
+        }
+    };
+
+    get_lot_editor_html(&data, building_id, &error)
+}
+
+pub fn link_toggle_lot_tile(id: BuildingId, tile: usize) -> String {
+    uri!(toggle_lot_tile(id.id(), tile)).to_string()
+}
+
+fn get_lot_editor_html(data: &RpgData, id: BuildingId, error: &str) -> Option<RawHtml<String>> {
+    let map_uri = uri!(get_lot_editor_map(id.id())).to_string();
+
+    data.building_manager.get(id).map(|building| {
+        let builder = create_html()
+            .h1(&format!("Edit Lot of Building: {}", building.name().str()))
+            .p(|b| b.text("Click on a free tile to add it or on a red one to remove it."))
+            .p(|b| b.text(error))
+            .center(|b| b.svg(&map_uri, "800"))
+            .p(|b| b.link(&link_building_details(id), "Back"));
+
+        RawHtml(builder.finish())
+    })
+}
+
+fn render_lot_editor_map(
+    data: &RpgData,
+    renderer: &TileMapRenderer,
+    town: &Town,
+    building: &Building,
+) -> RawSvg {
+    let size = renderer.calculate_map_size(&town.map);
+    let mut builder = SvgBuilder::new(size);
+    let id = building.id();
+    let construction = Construction::Building { id };
+
+    renderer.render_links(
+        &mut builder,
+        &Point2d::default(),
+        &town.map,
+        |tile| {
+            if tile.construction == construction {
+                Color::Red
+            } else {
+                TownTile::get_color(tile)
+            }
+        },
+        |index, tile| {
+            if tile.construction == Construction::None || tile.construction == construction {
+                Some(link_toggle_lot_tile(id, index))
+            } else {
+                None
+            }
+        },
+    );
+
+    get_visible_buildings(data, town, Audience::GameMaster)
+        .filter(|other| other.id() != id)
+        .for_each(|other| {
+            render_building(&mut builder, renderer, &data.building_types, town, other)
+        });
+    render_streets(data, &mut builder, renderer, town, Audience::GameMaster);
+
+    let svg = builder.finish();
+    RawSvg::new(svg.export())
+}
@@ -1,18 +1,43 @@
 use crate::html::{create_html, EditorBuilder};
+use crate::route::building::entrance::{get_entrance_text, link_entrance_editor};
+use crate::route::building::interior::link_interior;
+use crate::route::building::lot::link_lot_editor;
+use crate::route::building::position::link_move_editor;
+use crate::route::building::subdivide::link_subdivide_editor;
+use crate::route::character::link_character_details;
 use crate::route::town::link_town_details;
 use crate::route::util::get_all_html;
 use crate::EditorData;
+use anyhow::{Context, Result};
 use rocket::form::Form;
 use rocket::response::content::RawHtml;
 use rocket::State;
+use rpg_tools_core::model::character::CharacterId;
+use rpg_tools_core::model::description::WithDescription;
+use rpg_tools_core::model::element::ElementRef;
 use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::visibility::{Visibility, WithVisibility};
 use rpg_tools_core::model::world::building::BuildingId;
 use rpg_tools_core::model::RpgData;
 use rpg_tools_core::usecase::delete::building::delete_building;
 use rpg_tools_core::usecase::delete::DeleteResult;
+use rpg_tools_core::usecase::edit::building::update_building_type;
+use rpg_tools_core::usecase::edit::description::update_description;
 use rpg_tools_core::usecase::edit::name::update_name;
 use rpg_tools_core::usecase::edit::resize::resize_building;
+use rpg_tools_core::usecase::edit::visibility::update_visibility;
+use rpg_tools_core::usecase::get::address::address;
+use rpg_tools_core::usecase::get::entrance::has_street_access;
+use rpg_tools_core::usecase::get::name::get_character_name;
+use rpg_tools_core::usecase::get::occupant::{get_residents, get_workers};
 use rpg_tools_core::utils::storage::{Element, Id};
+use rpg_tools_html::HtmlBuilder;
+
+pub mod entrance;
+pub mod interior;
+pub mod lot;
+pub mod position;
+pub mod subdivide;
 
 #[get("/building/all")]
 pub fn get_all_buildings(state: &State<EditorData>) -> RawHtml<String> {
@@ -63,8 +88,11 @@ pub fn link_delete_building(id: BuildingId) -> String {
 #[derive(FromForm, Debug)]
 pub struct BuildingUpdate<'r> {
     name: &'r str,
+    visibility: &'r str,
+    building_type: Option<usize>,
     width: u32,
     height: u32,
+    description: &'r str,
 }
 
 #[post("/building/<id>/update", data = "<update>")]
@@ -73,20 +101,49 @@ pub fn update_building(
     id: usize,
     update: Form<BuildingUpdate<'_>>,
 ) -> Option<RawHtml<String>> {
-    println!("Update mountain {} with {:?}", id, update);
+    println!("Update building {} with {:?}", id, update);
     let mut data = state.data.lock().expect("lock shared data");
 
     let building_id = BuildingId::new(id);
 
     if let Err(e) = update_name(&mut data.building_manager, building_id, update.name) {
         return get_edit_html(&data, building_id, &e.to_string(), "");
+    } else if let Err(e) = Visibility::parse(update.visibility).and_then(|visibility| {
+        update_visibility(&mut data.building_manager, building_id, visibility)
+    }) {
+        return get_edit_html(&data, building_id, &e.to_string(), "");
+    } else if let Err(e) = update_type(&mut data, building_id, update.building_type) {
+        return get_edit_html(&data, building_id, &e.to_string(), "");
     } else if let Err(e) = resize_building(&mut data, building_id, update.width, update.height) {
         return get_edit_html(&data, building_id, "", &e.to_string());
     }
 
+    if let Err(e) = update_description(&mut data.building_manager, building_id, update.description)
+    {
+        return get_edit_html(&data, building_id, &e.to_string(), "");
+    }
+
     get_building_details_html(&data, building_id)
 }
 
+/// Updates the type with its index in the
+/// [`catalog`](rpg_tools_core::model::world::building::types::BuildingTypeCatalog).
+fn update_type(data: &mut RpgData, id: BuildingId, index: Option<usize>) -> Result<()> {
+    let name = match index {
+        Some(index) => Some(
+            data.building_types
+                .types
+                .get(index)
+                .context("Unknown building type")?
+                .name
+                .clone(),
+        ),
+        None => None,
+    };
+
+    update_building_type(data, id, name.as_deref())
+}
+
 pub fn get_building_details_html(data: &RpgData, id: BuildingId) -> Option<RawHtml<String>> {
     data.building_manager.get(id).map(|building| {
         let builder = create_html()
@@ -94,6 +151,13 @@ pub fn get_building_details_html(data: &RpgData, id: BuildingId) -> Option<RawHt
             .h2("Data")
             .field_usize("Id:", id.id())
             .field("Name:", &building.name().to_string())
+            .field("Visibility:", &building.visibility().to_string())
+            .option(building.building_type.as_deref(), |building_type, b| {
+                b.field("Type:", building_type)
+            })
+            .option(address(data, id), |address, b| {
+                b.field("Address:", &address)
+            })
             .h3("Lot")
             .option(data.town_manager.get(building.lot.town), |town, b| {
                 b.complex_field("Town:", |b| {
@@ -102,6 +166,39 @@ pub fn get_building_details_html(data: &RpgData, id: BuildingId) -> Option<RawHt
             })
             .field_usize("Tile:", building.lot.tile)
             .field_size2d("Size:", &building.lot.size)
+            .field(
+                "Shape:",
+                if building.lot.is_rectangle() {
+                    "Rectangle"
+                } else {
+                    "Irregular"
+                },
+            )
+            .p(|b| b.link(&link_lot_editor(id), "Edit Lot"))
+            .p(|b| b.link(&link_move_editor(id), "Move"))
+            .p(|b| b.link(&link_subdivide_editor(id), "Split or Merge"))
+            .h3("Entrances")
+            .field_usize("Count:", building.entrances.len())
+            .list(&building.entrances, |b, entrance| {
+                b.text(&get_entrance_text(data, building, entrance))
+            })
+            .field(
+                "Street Access:",
+                if has_street_access(data, id) {
+                    "Yes"
+                } else {
+                    "No!"
+                },
+            )
+            .p(|b| b.link(&link_entrance_editor(id), "Edit Entrances"))
+            .h3("Interior")
+            .field_usize("Floors:", building.floors.len())
+            .p(|b| b.link(&link_interior(id), "Edit Interior"))
+            .add_occupants("Residents", data, &get_residents(data, id))
+            .add_occupants("Workers", data, &get_workers(data, id))
+            .add_description(data, building.description())
+            .add_history(data, ElementRef::Building(id))
+            .add_backlinks(data, ElementRef::Building(id))
             .p(|b| b.link(&link_edit_building(id), "Edit"))
             .p(|b| b.link(&link_delete_building(id), "Delete"))
             .p(|b| b.link(&link_all_buildings(), "Back"));
@@ -117,6 +214,12 @@ fn get_edit_html(
     size_error: &str,
 ) -> Option<RawHtml<String>> {
     let submit_uri = uri!(update_building(id.id())).to_string();
+    let types: Vec<(usize, &str)> = data
+        .building_types
+        .get_names()
+        .into_iter()
+        .enumerate()
+        .collect();
 
     data.building_manager.get(id).map(|building| {
         let builder = create_html()
@@ -125,6 +228,21 @@ fn get_edit_html(
             .form(&submit_uri, |b| {
                 b.text_input("Name", "name", building.name().str())
                     .error(name_error)
+                    .select(
+                        "Visibility",
+                        "visibility",
+                        &Visibility::get_all(),
+                        &building.visibility().to_string(),
+                    )
+                    .select_optional_id(
+                        "Type",
+                        "building_type",
+                        &types,
+                        building
+                            .building_type
+                            .as_deref()
+                            .and_then(|name| data.building_types.get_index(name)),
+                    )
                     .number_input("Width", "width", building.lot.size.width() as usize, 1, 100)
                     .number_input(
                         "Height",
@@ -134,9 +252,31 @@ fn get_edit_html(
                         100,
                     )
                     .error(size_error)
+                    .text_area(
+                        "Description",
+                        "description",
+                        building.description().unwrap_or(""),
+                    )
             })
             .p(|b| b.link(&link_building_details(id), "Back"));
 
         RawHtml(builder.finish())
     })
 }
+
+trait OccupantBuilder {
+    fn add_occupants(self, title: &str, data: &RpgData, occupants: &[CharacterId]) -> Self;
+}
+
+impl OccupantBuilder for HtmlBuilder {
+    fn add_occupants(self, title: &str, data: &RpgData, occupants: &[CharacterId]) -> Self {
+        self.h3(title)
+            .field_usize("Count:", occupants.len())
+            .list(occupants, |b, &id| {
+                b.link(
+                    &link_character_details(id),
+                    &get_character_name(data, id).unwrap_or_default(),
+                )
+            })
+    }
+}
@@ -0,0 +1,219 @@
+use crate::html::create_html;
+use crate::route::building::link_building_details;
+use crate::route::util::get_all_elements;
+use crate::svg::RawSvg;
+use crate::EditorData;
+use anyhow::{Context, Result};
+use rocket::form::Form;
+use rocket::response::content::RawHtml;
+use rocket::State;
+use rpg_tools_core::model::math::point2d::Point2d;
+use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::visibility::Audience;
+use rpg_tools_core::model::world::building::BuildingId;
+use rpg_tools_core::model::world::town::tile::TownTile;
+use rpg_tools_core::model::world::town::{Town, TownId};
+use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::edit::resize::{move_building, rotate_building};
+use rpg_tools_core::utils::storage::{Element, Id};
+use rpg_tools_rendering::renderer::svg::builder::SvgBuilder;
+use rpg_tools_rendering::renderer::LinkRenderer;
+use rpg_tools_rendering::usecase::map::town::{
+    get_visible_buildings, render_building, render_streets,
+};
+use rpg_tools_rendering::usecase::map::TileMapRenderer;
+
+#[get("/building/<id>/move/editor")]
+pub fn get_move_editor(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let data = state.data.lock().expect("lock shared data");
+    let building_id = BuildingId::new(id);
+    let town_id = data.building_manager.get(building_id)?.lot.town;
+
+    get_move_editor_html(&data, building_id, town_id, "")
+}
+
+pub fn link_move_editor(id: BuildingId) -> String {
+    uri!(get_move_editor(id.id())).to_string()
+}
+
+#[derive(FromForm, Debug)]
+pub struct MoveEditorUpdate {
+    town: usize,
+}
+
+#[post("/building/<id>/move/update", data = "<update>")]
+pub fn update_move_editor(
+    state: &State<EditorData>,
+    id: usize,
+    update: Form<MoveEditorUpdate>,
+) -> Option<RawHtml<String>> {
+    println!("Update move editor of building {} with {:?}", id, update);
+    let data = state.data.lock().expect("lock shared data");
+
+    get_move_editor_html(&data, BuildingId::new(id), TownId::new(update.town), "")
+}
+
+#[get("/building/<id>/move/<town>/editor.svg")]
+pub fn get_move_editor_map(state: &State<EditorData>, id: usize, town: usize) -> Option<RawSvg> {
+    let data = state.data.lock().expect("lock shared data");
+
+    data.town_manager
+        .get(TownId::new(town))
+        .map(|town| render_move_editor_map(&data, &state.town_renderer, town, BuildingId::new(id)))
+}
+
+/// Moves the building to the tile. If it was dragged, *grab* is the tile, where it was grabbed.
+#[get("/building/<id>/move/<town>/<tile>?<grab>")]
+pub fn move_building_route(
+    state: &State<EditorData>,
+    id: usize,
+    town: usize,
+    tile: usize,
+    grab: Option<usize>,
+) -> Option<RawHtml<String>> {
+    let mut data = state.data.lock().expect("lock shared data");
+    let building_id = BuildingId::new(id);
+    let town_id = TownId::new(town);
+
+    let error = match get_target_tile(&data, building_id, town_id, tile, grab)
+        .and_then(|target| move_building(&mut data, building_id, town_id, target))
+    {
+        Ok(()) => {
+            println!("Moved building {} to town {}", id, town);
+            String::new()
+        }
+        Err(e) => {
+            println!("Failed to move building {}: {}", id, e);
+            e.to_string()
+        }
+    };
+
+    get_move_editor_html(&data, building_id, town_id, &error)
+}
+
+pub fn link_move_building(id: BuildingId, town: TownId, tile: usize) -> String {
+    uri!(move_building_route(id.id(), town.id(), tile, None::<usize>)).to_string()
+}
+
+#[get("/building/<id>/rotate")]
+pub fn rotate_building_route(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let mut data = state.data.lock().expect("lock shared data");
+    let building_id = BuildingId::new(id);
+    let town_id = data.building_manager.get(building_id)?.lot.town;
+
+    let error = match rotate_building(&mut data, building_id) {
+        Ok(()) => {
+            println!("Rotated building {}", id);
+            String::new()
+        }
+        Err(e) => {
+            println!("Failed to rotate building {}: {}", id, e);
+            e.to_string()
+        }
+    };
+
+    get_move_editor_html(&data, building_id, town_id, &error)
+}
+
+pub fn link_rotate_building(id: BuildingId) -> String {
+    uri!(rotate_building_route(id.id())).to_string()
+}
+
+/// Returns the new top left tile of the lot, which keeps the grabbed tile under the cursor.
+///
+/// The grabbed tile is in the current town of the lot, which can differ from the target town.
+fn get_target_tile(
+    data: &RpgData,
+    id: BuildingId,
+    town: TownId,
+    tile: usize,
+    grab: Option<usize>,
+) -> Result<usize> {
+    let Some(grab) = grab else {
+        return Ok(tile);
+    };
+    let lot = &data
+        .building_manager
+        .get(id)
+        .context("Building doesn't exist")?
+        .lot;
+    let get_size = |town: TownId| {
+        data.town_manager
+            .get(town)
+            .map(|town| town.map.get_size())
+            .context("Town doesn't exist")
+    };
+    let current_size = get_size(lot.town)?;
+    let target_size = get_size(town)?;
+    let offset_x = current_size.to_x(grab) - current_size.to_x(lot.tile);
+    let offset_y = current_size.to_y(grab) - current_size.to_y(lot.tile);
+    let x = target_size.to_x(tile) - offset_x;
+    let y = target_size.to_y(tile) - offset_y;
+
+    target_size
+        .to_index(x, y)
+        .context("The new lot is outside the town or occupied!")
+}
+
+fn get_move_editor_html(
+    data: &RpgData,
+    id: BuildingId,
+    town_id: TownId,
+    error: &str,
+) -> Option<RawHtml<String>> {
+    let map_uri = uri!(get_move_editor_map(id.id(), town_id.id())).to_string();
+    let update_uri = uri!(update_move_editor(id.id())).to_string();
+    let towns = get_all_elements(&data.town_manager);
+
+    data.building_manager.get(id).map(|building| {
+        let builder = create_html()
+            .h1(&format!("Move Building: {}", building.name().str()))
+            .form_with_change(&update_uri, &update_uri, |b| {
+                b.select_id("Town", "town", &towns, town_id.id())
+            })
+            .p(|b| b.text("Drag the building or click on the tile for its top left corner."))
+            .p(|b| b.text(error))
+            .center(|b| b.svg(&map_uri, "800"))
+            .p(|b| b.link(&link_rotate_building(id), "Rotate"))
+            .p(|b| b.link(&link_building_details(id), "Back"));
+
+        RawHtml(builder.finish())
+    })
+}
+
+fn render_move_editor_map(
+    data: &RpgData,
+    renderer: &TileMapRenderer,
+    town: &Town,
+    id: BuildingId,
+) -> RawSvg {
+    let size = renderer.calculate_map_size(&town.map);
+    let mut builder = SvgBuilder::new(size);
+
+    renderer.render_links(
+        &mut builder,
+        &Point2d::default(),
+        &town.map,
+        TownTile::get_color,
+        |index, _tile| Some(link_move_building(id, town.id(), index)),
+    );
+
+    render_streets(data, &mut builder, renderer, town, Audience::GameMaster);
+
+    get_visible_buildings(data, town, Audience::GameMaster).for_each(|building| {
+        let is_moved = building.id() == id;
+
+        if is_moved {
+            builder.group("dragged");
+        }
+
+        render_building(&mut builder, renderer, &data.building_types, town, building);
+
+        if is_moved {
+            builder.close();
+        }
+    });
+
+    let svg = builder.finish();
+    RawSvg::new(svg.export())
+}
@@ -0,0 +1,116 @@
+use crate::html::create_html;
+use crate::route::building::{get_building_details_html, link_building_details};
+use crate::EditorData;
+use rocket::form::Form;
+use rocket::response::content::RawHtml;
+use rocket::State;
+use rpg_tools_core::model::math::axis2d::Axis2d;
+use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::world::building::BuildingId;
+use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::edit::subdivide::{merge_buildings, split_building};
+use rpg_tools_core::utils::storage::{Element, Id};
+
+#[get("/building/<id>/subdivide")]
+pub fn get_subdivide_editor(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let data = state.data.lock().expect("lock shared data");
+    get_subdivide_html(&data, BuildingId::new(id), "", "")
+}
+
+pub fn link_subdivide_editor(id: BuildingId) -> String {
+    uri!(get_subdivide_editor(id.id())).to_string()
+}
+
+#[derive(FromForm, Debug)]
+pub struct SplitUpdate<'r> {
+    parts: u32,
+    axis: &'r str,
+}
+
+#[post("/building/<id>/split", data = "<update>")]
+pub fn split_building_route(
+    state: &State<EditorData>,
+    id: usize,
+    update: Form<SplitUpdate<'_>>,
+) -> Option<RawHtml<String>> {
+    println!("Split building {} with {:?}", id, update);
+    let mut data = state.data.lock().expect("lock shared data");
+    let building_id = BuildingId::new(id);
+
+    match Axis2d::parse(update.axis)
+        .and_then(|axis| split_building(&mut data, building_id, update.parts, axis))
+    {
+        Ok(ids) => {
+            println!("Split building {} into {:?}", id, ids);
+            get_building_details_html(&data, building_id)
+        }
+        Err(e) => get_subdivide_html(&data, building_id, &e.to_string(), ""),
+    }
+}
+
+#[derive(FromForm, Debug)]
+pub struct MergeUpdate {
+    other: usize,
+}
+
+#[post("/building/<id>/merge", data = "<update>")]
+pub fn merge_building_route(
+    state: &State<EditorData>,
+    id: usize,
+    update: Form<MergeUpdate>,
+) -> Option<RawHtml<String>> {
+    println!("Merge building {} with {:?}", id, update);
+    let mut data = state.data.lock().expect("lock shared data");
+    let building_id = BuildingId::new(id);
+
+    match merge_buildings(&mut data, building_id, BuildingId::new(update.other)) {
+        Ok(result) => {
+            println!("Merged buildings into {:?}", result);
+            get_building_details_html(&data, result.building)
+        }
+        Err(e) => get_subdivide_html(&data, building_id, "", &e.to_string()),
+    }
+}
+
+fn get_subdivide_html(
+    data: &RpgData,
+    id: BuildingId,
+    split_error: &str,
+    merge_error: &str,
+) -> Option<RawHtml<String>> {
+    let split_uri = uri!(split_building_route(id.id())).to_string();
+    let merge_uri = uri!(merge_building_route(id.id())).to_string();
+    let axes: Vec<String> = Axis2d::get_all()
+        .iter()
+        .map(|axis| axis.to_string())
+        .collect();
+    let axes: Vec<&str> = axes.iter().map(|axis| axis.as_str()).collect();
+
+    data.building_manager.get(id).map(|building| {
+        let neighbours: Vec<(usize, &str)> = data
+            .building_manager
+            .get_all()
+            .iter()
+            .filter(|other| other.id() != id && other.lot.town == building.lot.town)
+            .map(|other| (other.id().id(), other.name().str()))
+            .collect();
+        let selected = neighbours.first().map(|(index, _)| *index).unwrap_or(0);
+
+        let builder = create_html()
+            .h1(&format!("Subdivide Building: {}", building.name().str()))
+            .h2("Split")
+            .form(&split_uri, |b| {
+                b.number_input("Parts", "parts", 2, 2, 100)
+                    .select("Axis", "axis", &axes, &Axis2d::Horizontal.to_string())
+                    .error(split_error)
+            })
+            .h2("Merge")
+            .form(&merge_uri, |b| {
+                b.select_id("Building", "other", &neighbours, selected)
+                    .error(merge_error)
+            })
+            .p(|b| b.link(&link_building_details(id), "Back"));
+
+        RawHtml(builder.finish())
+    })
+}
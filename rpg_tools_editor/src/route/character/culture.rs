@@ -1,15 +1,35 @@
-use crate::html::create_html;
+use crate::html::{create_html, EditorBuilder};
 use crate::route::character::link_character_details;
 use crate::route::util::get_all_html;
 use crate::EditorData;
+use anyhow::Result;
 use rocket::form::Form;
 use rocket::response::content::RawHtml;
 use rocket::State;
 use rpg_tools_core::model::character::culture::{Culture, CultureId};
+use rpg_tools_core::model::character::derivation::DerivationRule;
+use rpg_tools_core::model::character::gender::{Gender, GenderMap};
+use rpg_tools_core::model::character::generator::NameGenerator;
+use rpg_tools_core::model::character::naming::{HonorificPlacement, NameOrder, NamingConvention};
+use rpg_tools_core::model::description::WithDescription;
+use rpg_tools_core::model::element::ElementRef;
 use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::visibility::{Visibility, WithVisibility};
 use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::delete::culture::delete_culture;
+use rpg_tools_core::usecase::delete::DeleteResult;
+use rpg_tools_core::usecase::edit::description::update_description;
+use rpg_tools_core::usecase::edit::name::derive::update_derivation_rules;
+use rpg_tools_core::usecase::edit::name::generate::update_name_generators;
 use rpg_tools_core::usecase::edit::name::update_name;
+use rpg_tools_core::usecase::edit::naming::update_naming_convention;
+use rpg_tools_core::usecase::edit::visibility::update_visibility;
+use rpg_tools_core::usecase::get::name::get_character_name;
 use rpg_tools_core::utils::storage::{Element, Id};
+use rpg_tools_html::form::FormBuilder;
+use rpg_tools_html::HtmlBuilder;
+
+const GENDERS: [Gender; 3] = [Gender::Female, Gender::Genderless, Gender::Male];
 
 #[get("/culture/all")]
 pub fn get_all_cultures(state: &State<EditorData>) -> RawHtml<String> {
@@ -48,9 +68,53 @@ pub fn edit_culture(state: &State<EditorData>, id: usize) -> Option<RawHtml<Stri
     get_edit_html(&data, CultureId::new(id), "")
 }
 
+#[get("/culture/<id>/delete")]
+pub fn delete_culture_route(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let mut data = state.data.lock().expect("lock shared data");
+    let culture_id = CultureId::new(id);
+
+    match delete_culture(&mut data, culture_id) {
+        DeleteResult::Ok => {
+            println!("Deleted culture {}", id);
+            Some(get_all_html(&data.cultures, "Cultures"))
+        }
+        DeleteResult::Blocked(reason) => {
+            println!("Deleting culture {} is blocked by {:?}", id, reason);
+            get_details_html(&data, culture_id)
+        }
+        DeleteResult::NotFound => None,
+    }
+}
+
+pub fn link_delete_culture(id: CultureId) -> String {
+    uri!(delete_culture_route(id.id())).to_string()
+}
+
 #[derive(FromForm, Debug)]
 pub struct CultureUpdate<'r> {
     name: &'r str,
+    visibility: &'r str,
+    description: &'r str,
+    patronymic_female: &'r str,
+    patronymic_genderless: &'r str,
+    patronymic_male: &'r str,
+    matronymic_female: &'r str,
+    matronymic_genderless: &'r str,
+    matronymic_male: &'r str,
+    order: &'r str,
+    middle_names: &'r str,
+    honorific_female: &'r str,
+    honorific_genderless: &'r str,
+    honorific_male: &'r str,
+    honorific_placement: &'r str,
+    first_names_female_type: &'r str,
+    first_names_female: &'r str,
+    first_names_genderless_type: &'r str,
+    first_names_genderless: &'r str,
+    first_names_male_type: &'r str,
+    first_names_male: &'r str,
+    family_names_type: &'r str,
+    family_names: &'r str,
 }
 
 #[post("/culture/<id>/update", data = "<update>")]
@@ -66,11 +130,87 @@ pub fn update_culture(
 
     if let Err(e) = update_name(&mut data.cultures, culture_id, update.name) {
         return get_edit_html(&data, culture_id, &e.to_string());
+    } else if let Err(e) = Visibility::parse(update.visibility)
+        .and_then(|visibility| update_visibility(&mut data.cultures, culture_id, visibility))
+    {
+        return get_edit_html(&data, culture_id, &e.to_string());
+    }
+
+    if let Err(e) = update_description(&mut data.cultures, culture_id, update.description) {
+        return get_edit_html(&data, culture_id, &e.to_string());
+    }
+
+    if let Err(e) = parse_rules(
+        update.patronymic_female,
+        update.patronymic_genderless,
+        update.patronymic_male,
+    )
+    .and_then(|patronymic| {
+        parse_rules(
+            update.matronymic_female,
+            update.matronymic_genderless,
+            update.matronymic_male,
+        )
+        .map(|matronymic| (patronymic, matronymic))
+    })
+    .and_then(|(patronymic, matronymic)| {
+        update_derivation_rules(&mut data, culture_id, patronymic, matronymic)
+    }) {
+        return get_edit_html(&data, culture_id, &e.to_string());
+    }
+
+    if let Err(e) = parse_naming(&update)
+        .and_then(|naming| update_naming_convention(&mut data, culture_id, naming))
+    {
+        return get_edit_html(&data, culture_id, &e.to_string());
+    }
+
+    if let Err(e) = parse_generators(&update).and_then(|(first_names, family_names)| {
+        update_name_generators(&mut data, culture_id, first_names, family_names)
+    }) {
+        return get_edit_html(&data, culture_id, &e.to_string());
     }
 
     get_details_html(&data, culture_id)
 }
 
+fn parse_generators(
+    update: &CultureUpdate<'_>,
+) -> Result<(GenderMap<NameGenerator>, NameGenerator)> {
+    Ok((
+        GenderMap::new(
+            NameGenerator::parse(update.first_names_female_type, update.first_names_female)?,
+            NameGenerator::parse(
+                update.first_names_genderless_type,
+                update.first_names_genderless,
+            )?,
+            NameGenerator::parse(update.first_names_male_type, update.first_names_male)?,
+        ),
+        NameGenerator::parse(update.family_names_type, update.family_names)?,
+    ))
+}
+
+fn parse_naming(update: &CultureUpdate<'_>) -> Result<NamingConvention> {
+    Ok(NamingConvention {
+        order: NameOrder::parse(update.order)?,
+        use_middle_names: update.middle_names.eq("Yes"),
+        honorifics: GenderMap::new(
+            update.honorific_female.trim().to_string(),
+            update.honorific_genderless.trim().to_string(),
+            update.honorific_male.trim().to_string(),
+        ),
+        honorific_placement: HonorificPlacement::parse(update.honorific_placement)?,
+    })
+}
+
+fn parse_rules(female: &str, genderless: &str, male: &str) -> Result<GenderMap<DerivationRule>> {
+    Ok(GenderMap::new(
+        DerivationRule::parse(female)?,
+        DerivationRule::parse(genderless)?,
+        DerivationRule::parse(male)?,
+    ))
+}
+
 fn get_details_html(data: &RpgData, id: CultureId) -> Option<RawHtml<String>> {
     let edit_uri = uri!(edit_culture(id = id.id())).to_string();
 
@@ -80,18 +220,36 @@ fn get_details_html(data: &RpgData, id: CultureId) -> Option<RawHtml<String>> {
             .get_all()
             .iter()
             .filter(|c| c.culture.eq(&id))
-            .map(|c| (c.id(), c.name.to_string()))
+            .map(|c| (c.id(), get_character_name(data, c.id()).unwrap_or_default()))
             .collect();
 
         let builder = create_html()
             .h1(&format!("Culture: {}", culture.name().str()))
             .h2("Data")
             .field_usize("Id:", id.id())
+            .field("Visibility:", &culture.visibility().to_string())
             .field_usize("Characters:", characters.len())
             .list(&characters, |b, character| {
                 b.link(&link_character_details(character.0), &character.1)
             })
+            .h3("Last Names")
+            .add_rules("Patronymic", &culture.patronymic)
+            .add_rules("Matronymic", &culture.matronymic)
+            .h3("Naming Convention")
+            .field("Order:", &culture.naming.order.to_string())
+            .field("Middle Names:", get_yes_no(culture.naming.use_middle_names))
+            .add_honorifics(&culture.naming.honorifics)
+            .field(
+                "Honorific Placement:",
+                &culture.naming.honorific_placement.to_string(),
+            )
+            .h3("Name Generation")
+            .add_first_name_generators(&culture.first_names)
+            .add_generator("Family Names:", &culture.family_names)
+            .add_description(data, culture.description())
+            .add_backlinks(data, ElementRef::Culture(id))
             .p(|b| b.link(&edit_uri, "Edit"))
+            .p(|b| b.link(&link_delete_culture(id), "Delete"))
             .p(|b| b.link(&link_all_cultures(), "Back"));
 
         RawHtml(builder.finish())
@@ -108,9 +266,161 @@ fn get_edit_html(data: &RpgData, id: CultureId, name_error: &str) -> Option<RawH
             .form(&submit_uri, |b| {
                 b.text_input("Name", "name", culture.name().str())
                     .error(name_error)
+                    .select(
+                        "Visibility",
+                        "visibility",
+                        &Visibility::get_all(),
+                        &culture.visibility().to_string(),
+                    )
+                    .add_rule_inputs("Patronymic", "patronymic", &culture.patronymic)
+                    .add_rule_inputs("Matronymic", "matronymic", &culture.matronymic)
+                    .select(
+                        "Name Order",
+                        "order",
+                        &NameOrder::get_all(),
+                        &culture.naming.order.to_string(),
+                    )
+                    .select(
+                        "Middle Names",
+                        "middle_names",
+                        &["Yes", "No"],
+                        get_yes_no(culture.naming.use_middle_names),
+                    )
+                    .add_honorific_inputs(&culture.naming.honorifics)
+                    .select(
+                        "Honorific Placement",
+                        "honorific_placement",
+                        &HonorificPlacement::get_all(),
+                        &culture.naming.honorific_placement.to_string(),
+                    )
+                    .add_first_name_generator_inputs(&culture.first_names)
+                    .add_generator_inputs("Family Names", "family_names", &culture.family_names)
+                    .text_area(
+                        "Description",
+                        "description",
+                        culture.description().unwrap_or(""),
+                    )
             })
             .p(|b| b.link(&link_culture_details(id), "Back"));
 
         RawHtml(builder.finish())
     })
 }
+
+fn get_yes_no(value: bool) -> &'static str {
+    if value {
+        "Yes"
+    } else {
+        "No"
+    }
+}
+
+trait RuleBuilder {
+    fn add_rules(self, title: &str, rules: &GenderMap<DerivationRule>) -> Self;
+
+    fn add_honorifics(self, honorifics: &GenderMap<String>) -> Self;
+
+    fn add_first_name_generators(self, generators: &GenderMap<NameGenerator>) -> Self;
+
+    fn add_generator(self, title: &str, generator: &NameGenerator) -> Self;
+}
+
+impl RuleBuilder for HtmlBuilder {
+    fn add_rules(self, title: &str, rules: &GenderMap<DerivationRule>) -> Self {
+        GENDERS.iter().fold(self, |b, &gender| {
+            b.field(
+                &format!("{} ({}):", title, gender),
+                &rules.get(gender).to_string(),
+            )
+        })
+    }
+
+    fn add_honorifics(self, honorifics: &GenderMap<String>) -> Self {
+        GENDERS.iter().fold(self, |b, &gender| {
+            b.field(&format!("Honorific ({}):", gender), honorifics.get(gender))
+        })
+    }
+
+    fn add_first_name_generators(self, generators: &GenderMap<NameGenerator>) -> Self {
+        GENDERS.iter().fold(self, |b, &gender| {
+            b.add_generator(
+                &format!("First Names ({}):", gender),
+                generators.get(gender),
+            )
+        })
+    }
+
+    fn add_generator(self, title: &str, generator: &NameGenerator) -> Self {
+        let names = generator.names();
+
+        if names.is_empty() {
+            self.field(title, &generator.to_string())
+        } else {
+            self.field(
+                title,
+                &format!(
+                    "{} with {} names: {}",
+                    generator,
+                    names.len(),
+                    names.join(", ")
+                ),
+            )
+        }
+    }
+}
+
+trait RuleFormBuilder {
+    fn add_rule_inputs(self, title: &str, name: &str, rules: &GenderMap<DerivationRule>) -> Self;
+
+    fn add_honorific_inputs(self, honorifics: &GenderMap<String>) -> Self;
+
+    fn add_first_name_generator_inputs(self, generators: &GenderMap<NameGenerator>) -> Self;
+
+    fn add_generator_inputs(self, title: &str, name: &str, generator: &NameGenerator) -> Self;
+}
+
+impl RuleFormBuilder for FormBuilder {
+    fn add_rule_inputs(self, title: &str, name: &str, rules: &GenderMap<DerivationRule>) -> Self {
+        GENDERS.iter().fold(self, |b, &gender| {
+            b.text_input(
+                &format!("{} ({})", title, gender),
+                &format!("{}_{}", name, gender.to_string().to_lowercase()),
+                &rules.get(gender).to_string(),
+            )
+        })
+    }
+
+    fn add_honorific_inputs(self, honorifics: &GenderMap<String>) -> Self {
+        GENDERS.iter().fold(self, |b, &gender| {
+            b.text_input(
+                &format!("Honorific ({})", gender),
+                &format!("honorific_{}", gender.to_string().to_lowercase()),
+                honorifics.get(gender),
+            )
+        })
+    }
+
+    fn add_first_name_generator_inputs(self, generators: &GenderMap<NameGenerator>) -> Self {
+        GENDERS.iter().fold(self, |b, &gender| {
+            b.add_generator_inputs(
+                &format!("First Names ({})", gender),
+                &format!("first_names_{}", gender.to_string().to_lowercase()),
+                generators.get(gender),
+            )
+        })
+    }
+
+    fn add_generator_inputs(self, title: &str, name: &str, generator: &NameGenerator) -> Self {
+        self.select(
+            &format!("{} Generator", title),
+            &format!("{}_type", name),
+            &NameGenerator::get_all(),
+            &generator.to_string(),
+        )
+        .text_area(
+            &format!("{} (one per line)", title),
+            name,
+            &generator.names().join("\n"),
+        )
+    }
+}
@@ -0,0 +1,205 @@
+use crate::html::create_html;
+use crate::route::character::link_character_details;
+use crate::EditorData;
+use anyhow::Result;
+use rocket::form::Form;
+use rocket::response::content::RawHtml;
+use rocket::State;
+use rpg_tools_core::model::character::CharacterId;
+use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::edit::character::family::{
+    add_parent, add_spouse, remove_parent, remove_spouse,
+};
+use rpg_tools_core::usecase::get::family::{get_children, get_parents, get_siblings, get_spouses};
+use rpg_tools_core::usecase::get::name::get_character_name;
+use rpg_tools_core::utils::storage::{Element, Id};
+use rpg_tools_html::HtmlBuilder;
+
+const RELATIONS: [&str; 3] = ["Parent", "Child", "Spouse"];
+
+#[get("/character/<id>/family")]
+pub fn get_family_tree(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let data = state.data.lock().expect("lock shared data");
+    get_family_html(&data, CharacterId::new(id), "")
+}
+
+pub fn link_family_tree(id: CharacterId) -> String {
+    uri!(get_family_tree(id.id())).to_string()
+}
+
+#[derive(FromForm, Debug)]
+pub struct RelationUpdate<'r> {
+    relation: &'r str,
+    character: usize,
+}
+
+#[post("/character/<id>/family/add", data = "<update>")]
+pub fn add_relation(
+    state: &State<EditorData>,
+    id: usize,
+    update: Form<RelationUpdate<'_>>,
+) -> Option<RawHtml<String>> {
+    println!("Add relation to character {} with {:?}", id, update);
+    let mut data = state.data.lock().expect("lock shared data");
+    let character_id = CharacterId::new(id);
+    let other = CharacterId::new(update.character);
+
+    let result = match update.relation {
+        "Parent" => add_parent(&mut data, character_id, other),
+        "Child" => add_parent(&mut data, other, character_id),
+        "Spouse" => add_spouse(&mut data, character_id, other),
+        relation => Err(anyhow::anyhow!("Unknown relation '{}'!", relation)),
+    };
+
+    get_family_html(&data, character_id, &get_error(result))
+}
+
+#[get("/character/<id>/family/remove/<relation>/<other>")]
+pub fn remove_relation(
+    state: &State<EditorData>,
+    id: usize,
+    relation: &str,
+    other: usize,
+) -> Option<RawHtml<String>> {
+    println!("Remove {} {} from character {}", relation, other, id);
+    let mut data = state.data.lock().expect("lock shared data");
+    let character_id = CharacterId::new(id);
+    let other = CharacterId::new(other);
+
+    let result = match relation {
+        "Parent" => remove_parent(&mut data, character_id, other),
+        "Child" => remove_parent(&mut data, other, character_id),
+        "Spouse" => remove_spouse(&mut data, character_id, other),
+        relation => Err(anyhow::anyhow!("Unknown relation '{}'!", relation)),
+    };
+
+    get_family_html(&data, character_id, &get_error(result))
+}
+
+fn link_remove_relation(id: CharacterId, relation: &str, other: CharacterId) -> String {
+    uri!(remove_relation(id.id(), relation, other.id())).to_string()
+}
+
+fn get_error(result: Result<()>) -> String {
+    result.err().map(|e| e.to_string()).unwrap_or_default()
+}
+
+/// Adds the direct relatives of a character to its details page.
+pub fn add_family(builder: HtmlBuilder, data: &RpgData, id: CharacterId) -> HtmlBuilder {
+    builder
+        .h3("Family")
+        .add_relatives(data, "Parents:", &get_parents(data, id))
+        .add_relatives(data, "Spouses:", &get_spouses(data, id))
+        .add_relatives(data, "Children:", &get_children(data, id))
+        .add_relatives(data, "Siblings:", &get_siblings(data, id))
+        .p(|b| b.link(&link_family_tree(id), "Family Tree"))
+}
+
+fn get_family_html(data: &RpgData, id: CharacterId, error: &str) -> Option<RawHtml<String>> {
+    let submit_uri = uri!(add_relation(id.id())).to_string();
+    let characters: Vec<(usize, String)> = data
+        .characters
+        .get_all()
+        .iter()
+        .filter(|character| character.id() != id)
+        .map(|character| {
+            (
+                character.id().id(),
+                get_character_name(data, character.id()).unwrap_or_default(),
+            )
+        })
+        .collect();
+    let options: Vec<(usize, &str)> = characters
+        .iter()
+        .map(|(id, name)| (*id, name.as_str()))
+        .collect();
+
+    data.characters.get(id).map(|character| {
+        let builder = create_html()
+            .h1(&format!(
+                "Family Tree: {}",
+                get_character_name(data, character.id()).unwrap_or_default()
+            ))
+            .h2("Ancestors")
+            .add_tree(data, id, get_parents)
+            .h2("Descendants")
+            .add_tree(data, id, get_children)
+            .h2("Relations")
+            .add_removable_relatives(data, id, "Parents", "Parent", &get_parents(data, id))
+            .add_removable_relatives(data, id, "Spouses", "Spouse", &get_spouses(data, id))
+            .add_removable_relatives(data, id, "Children", "Child", &get_children(data, id))
+            .h3("Add Relation")
+            .form(&submit_uri, |b| {
+                b.select("Relation", "relation", &RELATIONS, RELATIONS[0])
+                    .select_id("Character", "character", &options, 0)
+                    .error(error)
+            })
+            .p(|b| b.link(&link_character_details(id), "Back"));
+
+        RawHtml(builder.finish())
+    })
+}
+
+trait FamilyBuilder {
+    fn add_relatives(self, data: &RpgData, title: &str, relatives: &[CharacterId]) -> Self;
+
+    fn add_removable_relatives(
+        self,
+        data: &RpgData,
+        id: CharacterId,
+        title: &str,
+        relation: &str,
+        relatives: &[CharacterId],
+    ) -> Self;
+
+    fn add_tree<F: Fn(&RpgData, CharacterId) -> Vec<CharacterId> + Copy>(
+        self,
+        data: &RpgData,
+        id: CharacterId,
+        get_next: F,
+    ) -> Self;
+}
+
+impl FamilyBuilder for HtmlBuilder {
+    fn add_relatives(self, data: &RpgData, title: &str, relatives: &[CharacterId]) -> Self {
+        self.field_usize(title, relatives.len())
+            .list(relatives, |b, &relative| {
+                add_character_link(b, data, relative)
+            })
+    }
+
+    fn add_removable_relatives(
+        self,
+        data: &RpgData,
+        id: CharacterId,
+        title: &str,
+        relation: &str,
+        relatives: &[CharacterId],
+    ) -> Self {
+        self.h3(title).list(relatives, |b, &relative| {
+            add_character_link(b, data, relative)
+                .text(" ")
+                .link(&link_remove_relation(id, relation, relative), "Remove")
+        })
+    }
+
+    fn add_tree<F: Fn(&RpgData, CharacterId) -> Vec<CharacterId> + Copy>(
+        self,
+        data: &RpgData,
+        id: CharacterId,
+        get_next: F,
+    ) -> Self {
+        self.list(&get_next(data, id), |b, &relative| {
+            add_character_link(b, data, relative).add_tree(data, relative, get_next)
+        })
+    }
+}
+
+fn add_character_link(builder: HtmlBuilder, data: &RpgData, id: CharacterId) -> HtmlBuilder {
+    builder.option(data.characters.get(id), |character, b| {
+        b.link(
+            &link_character_details(id),
+            &get_character_name(data, character.id()).unwrap_or_default(),
+        )
+    })
+}
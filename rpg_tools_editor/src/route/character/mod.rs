@@ -1,24 +1,64 @@
 pub mod culture;
+pub mod family;
+pub mod npc;
+pub mod stats;
 
-use crate::html::create_html;
+use crate::html::{create_html, EditorBuilder};
 use crate::route::character::culture::link_culture_details;
+use crate::route::character::family::add_family;
+use crate::route::character::npc::link_generate_npcs;
+use crate::route::character::stats::add_stats;
 use crate::route::link_home;
+use crate::route::organization::add_memberships;
+use crate::route::util::link_element_details;
+use crate::svg::RawSvg;
 use crate::EditorData;
+use anyhow::Result;
 use rocket::form::Form;
 use rocket::response::content::RawHtml;
 use rocket::State;
+use rpg_tools_core::model::character::appearance::{Appearance, Beard, HairStyle, HeadShape};
 use rpg_tools_core::model::character::name::CharacterName;
+use rpg_tools_core::model::character::naming::NameFormat;
 use rpg_tools_core::model::character::{Character, CharacterId};
+use rpg_tools_core::model::color::Color;
+use rpg_tools_core::model::description::WithDescription;
+use rpg_tools_core::model::element::ElementRef;
 use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::time::date::Date;
+use rpg_tools_core::model::visibility::{Visibility, WithVisibility};
+use rpg_tools_core::model::world::building::BuildingId;
 use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::edit::character::appearance::update_appearance;
+use rpg_tools_core::usecase::edit::character::building::{update_home, update_workplace};
+use rpg_tools_core::usecase::edit::character::date::update_life_dates;
 use rpg_tools_core::usecase::edit::character::gender::update_gender;
+use rpg_tools_core::usecase::edit::description::update_description;
 use rpg_tools_core::usecase::edit::name::character::update_character_name;
+use rpg_tools_core::usecase::edit::name::derive::derive_lastname;
+use rpg_tools_core::usecase::edit::name::generate::generate_character_name;
+use rpg_tools_core::usecase::edit::visibility::update_visibility;
+use rpg_tools_core::usecase::get::age::get_age;
+use rpg_tools_core::usecase::get::name::{
+    format_character_name, get_character_name, get_element_name,
+};
 use rpg_tools_core::usecase::get::name::{get_first_name, get_last_name, get_middle_name};
 use rpg_tools_core::utils::storage::{Element, Id};
+use rpg_tools_html::form::FormBuilder;
+use rpg_tools_html::HtmlBuilder;
+use rpg_tools_rendering::usecase::portrait::render_portrait_svg;
 
 #[get("/character/all")]
 pub fn get_all_characters(state: &State<EditorData>) -> RawHtml<String> {
     let data = state.data.lock().expect("lock shared data");
+    get_all_characters_html(&data)
+}
+
+pub fn link_all_characters() -> String {
+    uri!(get_all_characters()).to_string()
+}
+
+pub fn get_all_characters_html(data: &RpgData) -> RawHtml<String> {
     let new_uri = uri!(add_character()).to_string();
 
     RawHtml(
@@ -26,23 +66,28 @@ pub fn get_all_characters(state: &State<EditorData>) -> RawHtml<String> {
             .h1("Characters")
             .field("Count:", &data.characters.len().to_string())
             .list(data.characters.get_all(), |b, e| {
-                b.link(&link_character_details(e.id()), &e.name.to_string())
+                b.link(
+                    &link_character_details(e.id()),
+                    &get_character_name(data, e.id()).unwrap_or_default(),
+                )
             })
             .p(|b| b.link(&new_uri, "Add"))
+            .p(|b| b.link(&link_generate_npcs(), "Generate NPCs"))
             .p(|b| b.link(&link_home(), "Back"))
             .finish(),
     )
 }
 
-pub fn link_all_characters() -> String {
-    uri!(get_all_characters()).to_string()
-}
-
 #[get("/character/new")]
 pub fn add_character(data: &State<EditorData>) -> Option<RawHtml<String>> {
     let mut data = data.data.lock().expect("lock shared data");
 
-    let id = data.characters.create(Character::new);
+    let gender = data.genders.genders[0].name.clone();
+    let id = data.characters.create(|id| {
+        let mut character = Character::new(id);
+        character.gender = gender;
+        character
+    });
 
     println!("Create character {}", id.id());
 
@@ -65,6 +110,24 @@ pub fn edit_character(state: &State<EditorData>, id: usize) -> Option<RawHtml<St
     get_edit_html(&data, CharacterId::new(id), "")
 }
 
+#[get("/character/<id>/generate_name")]
+pub fn generate_name(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let mut data = state.data.lock().expect("lock shared data");
+    let character_id = CharacterId::new(id);
+    let seed = rand::random::<u64>();
+
+    match generate_character_name(&mut data, character_id, seed) {
+        Ok(name) => {
+            println!(
+                "Generated name '{}' for character {} with seed {}",
+                name, id, seed
+            );
+            get_edit_html(&data, character_id, "")
+        }
+        Err(e) => get_edit_html(&data, character_id, &e.to_string()),
+    }
+}
+
 #[derive(FromForm, Debug)]
 pub struct CharacterUpdate<'r> {
     first_name: &'r str,
@@ -72,6 +135,18 @@ pub struct CharacterUpdate<'r> {
     last_type: &'r str,
     last_name: &'r str,
     gender: &'r str,
+    visibility: &'r str,
+    birth_date: &'r str,
+    death_date: &'r str,
+    home: Option<usize>,
+    workplace: Option<usize>,
+    head_shape: &'r str,
+    skin_color: &'r str,
+    eye_color: &'r str,
+    hair_style: &'r str,
+    hair_color: &'r str,
+    beard: &'r str,
+    description: &'r str,
 }
 
 #[post("/character/<id>/update", data = "<update>")]
@@ -94,41 +169,159 @@ pub fn update_character(
         Ok(name) => {
             if let Err(e) = update_character_name(&mut data, character_id, name) {
                 return get_edit_html(&data, character_id, &e.to_string());
-            } else if let Err(e) = update_gender(&mut data, character_id, update.gender.into()) {
+            } else if let Err(e) = update_gender(&mut data, character_id, update.gender) {
+                return get_edit_html(&data, character_id, &e.to_string());
+            } else if let Err(e) = Visibility::parse(update.visibility).and_then(|visibility| {
+                update_visibility(&mut data.characters, character_id, visibility)
+            }) {
                 return get_edit_html(&data, character_id, &e.to_string());
             }
         }
         Err(e) => return get_edit_html(&data, character_id, &e.to_string()),
     }
 
+    if let Err(e) = parse_life_dates(&data, &update)
+        .and_then(|(birth, death)| update_life_dates(&mut data, character_id, birth, death))
+    {
+        return get_edit_html(&data, character_id, &e.to_string());
+    }
+
+    if let Err(e) = update_home(&mut data, character_id, update.home.map(BuildingId::new)) {
+        return get_edit_html(&data, character_id, &e.to_string());
+    } else if let Err(e) = update_workplace(
+        &mut data,
+        character_id,
+        update.workplace.map(BuildingId::new),
+    ) {
+        return get_edit_html(&data, character_id, &e.to_string());
+    }
+
+    if let Err(e) = parse_appearance(&update)
+        .and_then(|appearance| update_appearance(&mut data, character_id, appearance))
+    {
+        return get_edit_html(&data, character_id, &e.to_string());
+    }
+
+    if let Err(e) = update_description(&mut data.characters, character_id, update.description) {
+        return get_edit_html(&data, character_id, &e.to_string());
+    }
+
     get_details_html(&data, character_id)
 }
 
+fn parse_life_dates(
+    data: &RpgData,
+    update: &CharacterUpdate<'_>,
+) -> Result<(Option<Date>, Option<Date>)> {
+    let calendar = &data.time.calendar;
+
+    Ok((
+        calendar.parse_optional_date(update.birth_date)?,
+        calendar.parse_optional_date(update.death_date)?,
+    ))
+}
+
+fn parse_appearance(update: &CharacterUpdate<'_>) -> Result<Appearance> {
+    Ok(Appearance {
+        head_shape: HeadShape::parse(update.head_shape)?,
+        skin_color: Color::parse(update.skin_color)?,
+        eye_color: Color::parse(update.eye_color)?,
+        hair_style: HairStyle::parse(update.hair_style)?,
+        hair_color: Color::parse(update.hair_color)?,
+        beard: Beard::parse(update.beard)?,
+    })
+}
+
+#[get("/character/<id>/portrait.svg")]
+pub fn get_portrait(state: &State<EditorData>, id: usize) -> Option<RawSvg> {
+    let data = state.data.lock().expect("lock shared data");
+
+    data.characters
+        .get(CharacterId::new(id))
+        .map(|character| RawSvg::new(render_portrait_svg(&character.appearance, 200).export()))
+}
+
+pub fn link_portrait(id: CharacterId) -> String {
+    uri!(get_portrait(id = id.id())).to_string()
+}
+
 fn get_details_html(data: &RpgData, id: CharacterId) -> Option<RawHtml<String>> {
     let edit_uri = uri!(edit_character(id = id.id())).to_string();
+    let portrait_uri = link_portrait(id);
 
     data.characters.get(id).map(|character| {
         let builder = create_html()
-            .h1(&format!("Character: {}", character.name))
+            .h1(&format!(
+                "Character: {}",
+                get_character_name(data, character.id()).unwrap_or_default()
+            ))
+            .center(|b| b.svg(&portrait_uri, "200"))
             .h2("Data")
             .field_usize("Id:", id.id())
+            .field("Visibility:", &character.visibility().to_string())
             .h3("Name")
             .field("First Name:", get_first_name(character))
             .option(character.name.middle(), |middle, b| {
                 b.field("Middle Name:", middle.str())
             })
             .option(character.name.last().name(), |last, b| {
+                let last = if matches!(derive_lastname(data, id), Ok(Some(_))) {
+                    format!("{} (derived from parent)", last)
+                } else {
+                    last.to_string()
+                };
+                b.field(&format!("{}:", character.name.last().get_type()), &last)
+            })
+            .field(
+                "Full Name:",
+                &format_character_name(data, character, NameFormat::Full)
+                    .unwrap_or_else(|e| e.to_string()),
+            )
+            .field(
+                "Formal Name:",
+                &format_character_name(data, character, NameFormat::Formal)
+                    .unwrap_or_else(|e| e.to_string()),
+            )
+            .field(
+                "Short Name:",
+                &format_character_name(data, character, NameFormat::Short)
+                    .unwrap_or_else(|e| e.to_string()),
+            )
+            .h3("Other")
+            .field("Gender:", &character.gender)
+            .option(data.genders.get(&character.gender), |gender, b| {
+                let pronouns = &gender.pronouns;
                 b.field(
-                    &format!("{}:", character.name.last().get_type()),
-                    last.str(),
+                    "Pronouns:",
+                    &format!(
+                        "{}/{}/{}",
+                        pronouns.subject, pronouns.object, pronouns.possessive
+                    ),
                 )
             })
-            .h3("Other")
             .option(data.cultures.get(character.culture), |culture, b| {
                 b.complex_field("Culture:", |b| {
                     b.link(&link_culture_details(culture.id()), culture.name().str())
                 })
             })
+            .option(character.birth_date, |date, b| {
+                b.field("Born:", &data.time.calendar.format(&date))
+            })
+            .option(character.death_date, |date, b| {
+                b.field("Died:", &data.time.calendar.format(&date))
+            })
+            .option(get_age(data, id), |age, b| {
+                b.field_usize("Age:", age as usize)
+            })
+            .add_building_field("Home:", data, character.home)
+            .add_building_field("Workplace:", data, character.workplace);
+
+        let builder = add_family(builder, data, id);
+        let builder = add_memberships(builder, data, id);
+        let builder = add_stats(builder, data, id)
+            .add_description(data, character.description())
+            .add_history(data, ElementRef::Character(id))
+            .add_backlinks(data, ElementRef::Character(id))
             .p(|b| b.link(&edit_uri, "Edit"))
             .p(|b| b.link(&link_all_characters(), "Back"));
 
@@ -138,10 +331,21 @@ fn get_details_html(data: &RpgData, id: CharacterId) -> Option<RawHtml<String>>
 
 fn get_edit_html(data: &RpgData, id: CharacterId, name_error: &str) -> Option<RawHtml<String>> {
     let submit_uri = uri!(update_character(id.id())).to_string();
+    let generate_uri = uri!(generate_name(id.id())).to_string();
+
+    let buildings: Vec<_> = data
+        .building_manager
+        .get_all()
+        .iter()
+        .map(|building| (building.id().id(), building.name().str()))
+        .collect();
 
     data.characters.get(id).map(|character| {
         let builder = create_html()
-            .h1(&format!("Edit Character: {}", character.name))
+            .h1(&format!(
+                "Edit Character: {}",
+                get_character_name(data, character.id()).unwrap_or_default()
+            ))
             .field_usize("Id:", id.id())
             .form(&submit_uri, |b| {
                 b.text_input("First Name:", "first_name", get_first_name(character))
@@ -157,12 +361,114 @@ fn get_edit_html(data: &RpgData, id: CharacterId, name_error: &str) -> Option<Ra
                     .select(
                         "Gender:",
                         "gender",
-                        &["Female", "Genderless", "Male"],
-                        &character.gender.to_string(),
+                        &data.genders.get_names(),
+                        &character.gender,
+                    )
+                    .select(
+                        "Visibility",
+                        "visibility",
+                        &Visibility::get_all(),
+                        &character.visibility().to_string(),
+                    )
+                    .text_input(
+                        "Birth Date (year-month-day):",
+                        "birth_date",
+                        &format_optional_date(character.birth_date),
+                    )
+                    .text_input(
+                        "Death Date (year-month-day):",
+                        "death_date",
+                        &format_optional_date(character.death_date),
+                    )
+                    .select_optional_id(
+                        "Home:",
+                        "home",
+                        &buildings,
+                        character.home.map(|id| id.id()),
+                    )
+                    .select_optional_id(
+                        "Workplace:",
+                        "workplace",
+                        &buildings,
+                        character.workplace.map(|id| id.id()),
+                    )
+                    .add_appearance_inputs(&character.appearance)
+                    .text_area(
+                        "Description:",
+                        "description",
+                        character.description().unwrap_or(""),
                     )
             })
+            .p(|b| b.link(&generate_uri, "Generate Name"))
             .p(|b| b.link(&link_character_details(id), "Back"));
 
         RawHtml(builder.finish())
     })
 }
+
+fn format_optional_date(date: Option<Date>) -> String {
+    date.map(|date| date.to_string()).unwrap_or_default()
+}
+
+trait BuildingFieldBuilder {
+    fn add_building_field(self, title: &str, data: &RpgData, building: Option<BuildingId>) -> Self;
+}
+
+impl BuildingFieldBuilder for HtmlBuilder {
+    fn add_building_field(self, title: &str, data: &RpgData, building: Option<BuildingId>) -> Self {
+        self.option(building, |building, b| {
+            let building = ElementRef::Building(building);
+            b.complex_field(title, |b| {
+                b.link(
+                    &link_element_details(building),
+                    &get_element_name(data, building).unwrap_or_default(),
+                )
+            })
+        })
+    }
+}
+
+trait AppearanceFormBuilder {
+    fn add_appearance_inputs(self, appearance: &Appearance) -> Self;
+}
+
+impl AppearanceFormBuilder for FormBuilder {
+    fn add_appearance_inputs(self, appearance: &Appearance) -> Self {
+        self.select(
+            "Head Shape:",
+            "head_shape",
+            &HeadShape::get_all(),
+            &appearance.head_shape.to_string(),
+        )
+        .select(
+            "Skin Color:",
+            "skin_color",
+            &Color::get_all(),
+            &appearance.skin_color.to_string(),
+        )
+        .select(
+            "Eye Color:",
+            "eye_color",
+            &Color::get_all(),
+            &appearance.eye_color.to_string(),
+        )
+        .select(
+            "Hair Style:",
+            "hair_style",
+            &HairStyle::get_all(),
+            &appearance.hair_style.to_string(),
+        )
+        .select(
+            "Hair Color:",
+            "hair_color",
+            &Color::get_all(),
+            &appearance.hair_color.to_string(),
+        )
+        .select(
+            "Beard:",
+            "beard",
+            &Beard::get_all(),
+            &appearance.beard.to_string(),
+        )
+    }
+}
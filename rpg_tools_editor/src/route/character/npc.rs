@@ -0,0 +1,212 @@
+use crate::html::create_html;
+use crate::route::character::{get_all_characters_html, link_all_characters};
+use crate::route::util::link_element_details;
+use crate::EditorData;
+use anyhow::{Context, Result};
+use rocket::form::Form;
+use rocket::response::content::RawHtml;
+use rocket::State;
+use rpg_tools_core::model::character::culture::CultureId;
+use rpg_tools_core::model::character::gender::GenderMap;
+use rpg_tools_core::model::character::name::LastnameType;
+use rpg_tools_core::model::character::naming::NameFormat;
+use rpg_tools_core::model::character::Character;
+use rpg_tools_core::model::element::ElementRef;
+use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::world::town::TownId;
+use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::create::character::{create_npcs, generate_npcs, NpcSettings};
+use rpg_tools_core::usecase::get::name::{format_character_name, get_element_name};
+use rpg_tools_core::utils::storage::{Element, Id};
+use rpg_tools_html::HtmlBuilder;
+
+const MAX_COUNT: usize = 100;
+
+#[get("/character/generate")]
+pub fn get_npc_generator(state: &State<EditorData>) -> RawHtml<String> {
+    let data = state.data.lock().expect("lock shared data");
+    let settings = NpcSettings::new(CultureId::default(), 10, rand::random::<u64>());
+
+    get_generator_html(&data, &settings, &[], "")
+}
+
+pub fn link_generate_npcs() -> String {
+    uri!(get_npc_generator()).to_string()
+}
+
+#[derive(FromForm, Debug)]
+pub struct NpcUpdate<'r> {
+    culture: usize,
+    female: usize,
+    genderless: usize,
+    male: usize,
+    lastname: &'r str,
+    town: Option<usize>,
+    count: usize,
+    seed: &'r str,
+}
+
+#[post("/character/generate/preview", data = "<update>")]
+pub fn preview_npcs(state: &State<EditorData>, update: Form<NpcUpdate<'_>>) -> RawHtml<String> {
+    println!("Preview npcs with {:?}", update);
+    let data = state.data.lock().expect("lock shared data");
+
+    match parse_settings(&update) {
+        Ok(settings) => match generate_npcs(&data, &settings) {
+            Ok(npcs) => get_generator_html(&data, &settings, &npcs, ""),
+            Err(e) => get_generator_html(&data, &settings, &[], &e.to_string()),
+        },
+        Err(e) => get_generator_html(
+            &data,
+            &NpcSettings::new(CultureId::new(update.culture), update.count, 0),
+            &[],
+            &e.to_string(),
+        ),
+    }
+}
+
+#[post("/character/generate/accept", data = "<update>")]
+pub fn accept_npcs(state: &State<EditorData>, update: Form<NpcUpdate<'_>>) -> RawHtml<String> {
+    println!("Accept npcs with {:?}", update);
+    let mut data = state.data.lock().expect("lock shared data");
+
+    match parse_settings(&update)
+        .and_then(|settings| create_npcs(&mut data, &settings).map(|ids| (settings, ids)))
+    {
+        Ok((settings, ids)) => {
+            println!("Created {} npcs with seed {}", ids.len(), settings.seed);
+            get_all_characters_html(&data)
+        }
+        Err(e) => get_generator_html(
+            &data,
+            &NpcSettings::new(CultureId::new(update.culture), update.count, 0),
+            &[],
+            &e.to_string(),
+        ),
+    }
+}
+
+fn parse_settings(update: &NpcUpdate<'_>) -> Result<NpcSettings> {
+    Ok(NpcSettings {
+        culture: CultureId::new(update.culture),
+        genders: GenderMap::new(
+            update.female as u32,
+            update.genderless as u32,
+            update.male as u32,
+        ),
+        lastname: LastnameType::parse(update.lastname)?,
+        town: update.town.map(TownId::new),
+        count: update.count.min(MAX_COUNT),
+        seed: update.seed.trim().parse().context("The seed is invalid!")?,
+    })
+}
+
+fn get_generator_html(
+    data: &RpgData,
+    settings: &NpcSettings,
+    npcs: &[Character],
+    error: &str,
+) -> RawHtml<String> {
+    let preview_uri = uri!(preview_npcs()).to_string();
+    let accept_uri = uri!(accept_npcs()).to_string();
+    let cultures: Vec<_> = data
+        .cultures
+        .get_all()
+        .iter()
+        .map(|culture| (culture.id().id(), culture.name().str()))
+        .collect();
+    let towns: Vec<_> = data
+        .town_manager
+        .get_all()
+        .iter()
+        .map(|town| (town.id().id(), town.name().str()))
+        .collect();
+    let town = settings.town.map(|town| town.id());
+
+    let builder = create_html().h1("Generate NPCs").form(&preview_uri, |b| {
+        b.select_id("Culture", "culture", &cultures, settings.culture.id())
+            .number_input(
+                "Female Weight",
+                "female",
+                settings.genders.female as usize,
+                0,
+                100,
+            )
+            .number_input(
+                "Genderless Weight",
+                "genderless",
+                settings.genders.genderless as usize,
+                0,
+                100,
+            )
+            .number_input(
+                "Male Weight",
+                "male",
+                settings.genders.male as usize,
+                0,
+                100,
+            )
+            .select(
+                "Last Name Type",
+                "lastname",
+                &LastnameType::get_all(),
+                &settings.lastname.to_string(),
+            )
+            .select_optional_id("Homes in Town", "town", &towns, town)
+            .number_input("Count", "count", settings.count, 1, MAX_COUNT)
+            .text_input("Seed", "seed", &settings.seed.to_string())
+            .error(error)
+    });
+
+    let builder = if npcs.is_empty() {
+        builder
+    } else {
+        builder
+            .h2("Preview")
+            .add_preview(data, npcs)
+            .h2("Accept")
+            .form(&accept_uri, |b| {
+                b.hidden("culture", &settings.culture.id().to_string())
+                    .hidden("female", &settings.genders.female.to_string())
+                    .hidden("genderless", &settings.genders.genderless.to_string())
+                    .hidden("male", &settings.genders.male.to_string())
+                    .hidden("lastname", &settings.lastname.to_string())
+                    .hidden(
+                        "town",
+                        &town.map(|town| town.to_string()).unwrap_or_default(),
+                    )
+                    .hidden("count", &settings.count.to_string())
+                    .hidden("seed", &settings.seed.to_string())
+            })
+    };
+
+    RawHtml(
+        builder
+            .p(|b| b.link(&link_all_characters(), "Back"))
+            .finish(),
+    )
+}
+
+trait PreviewBuilder {
+    fn add_preview(self, data: &RpgData, npcs: &[Character]) -> Self;
+}
+
+impl PreviewBuilder for HtmlBuilder {
+    fn add_preview(self, data: &RpgData, npcs: &[Character]) -> Self {
+        self.list(npcs, |b, npc| {
+            b.text(&format!(
+                "{} ({})",
+                format_character_name(data, npc, NameFormat::Full)
+                    .unwrap_or_else(|_| npc.name.to_string()),
+                npc.gender
+            ))
+            .option(npc.home, |home, b| {
+                let home = ElementRef::Building(home);
+                b.text(" lives in ").link(
+                    &link_element_details(home),
+                    &get_element_name(data, home).unwrap_or_default(),
+                )
+            })
+        })
+    }
+}
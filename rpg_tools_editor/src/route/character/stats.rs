@@ -0,0 +1,189 @@
+use crate::html::create_html;
+use crate::route::character::{link_character_details, link_portrait};
+use crate::EditorData;
+use rocket::form::Form;
+use rocket::response::content::RawHtml;
+use rocket::State;
+use rpg_tools_core::model::character::CharacterId;
+use rpg_tools_core::model::stats::{StatBlock, StatSchema, StatSheet};
+use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::edit::character::stats::update_stats;
+use rpg_tools_core::usecase::get::name::get_character_name;
+use rpg_tools_core::utils::storage::Id;
+use rpg_tools_html::HtmlBuilder;
+
+/// The upper limit of the inputs for skills without a maximum.
+const MAX_SKILL: usize = 999;
+
+#[get("/character/<id>/stats/edit")]
+pub fn edit_stats(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let data = state.data.lock().expect("lock shared data");
+    get_edit_html(&data, CharacterId::new(id), "")
+}
+
+pub fn link_edit_stats(id: CharacterId) -> String {
+    uri!(edit_stats(id.id())).to_string()
+}
+
+#[get("/character/<id>/sheet")]
+pub fn get_sheet(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let data = state.data.lock().expect("lock shared data");
+    get_sheet_html(&data, CharacterId::new(id))
+}
+
+pub fn link_sheet(id: CharacterId) -> String {
+    uri!(get_sheet(id.id())).to_string()
+}
+
+/// The values of the attributes & skills in the order of the [`StatSchema`].
+#[derive(FromForm, Debug)]
+pub struct StatsUpdate {
+    attributes: Vec<i32>,
+    skills: Vec<i32>,
+}
+
+#[post("/character/<id>/stats/update", data = "<update>")]
+pub fn update_stats_route(
+    state: &State<EditorData>,
+    id: usize,
+    update: Form<StatsUpdate>,
+) -> Option<RawHtml<String>> {
+    println!("Update stats of character {} with {:?}", id, update);
+    let mut data = state.data.lock().expect("lock shared data");
+    let id = CharacterId::new(id);
+
+    let result =
+        parse_stats(&data.stats, &update).and_then(|stats| update_stats(&mut data, id, stats));
+
+    match result {
+        Ok(()) => get_sheet_html(&data, id),
+        Err(e) => get_edit_html(&data, id, &e.to_string()),
+    }
+}
+
+/// Only stores skills, which differ from their base value.
+///
+/// The attributes are validated before calculating the base values from them.
+fn parse_stats(schema: &StatSchema, update: &StatsUpdate) -> anyhow::Result<StatBlock> {
+    let mut stats = StatBlock::default();
+
+    for (attribute, value) in schema.attributes.iter().zip(&update.attributes) {
+        if *value != attribute.default {
+            stats.attributes.insert(attribute.name.clone(), *value);
+        }
+    }
+
+    schema.validate(&stats)?;
+
+    for (skill, value) in schema.skills.iter().zip(&update.skills) {
+        if *value != schema.calculate_base(&stats, &skill.name)? {
+            stats.skills.insert(skill.name.clone(), *value);
+        }
+    }
+
+    Ok(stats)
+}
+
+/// Adds the statistics of a character to its details page.
+pub fn add_stats(builder: HtmlBuilder, data: &RpgData, id: CharacterId) -> HtmlBuilder {
+    if data.stats.is_empty() {
+        return builder;
+    }
+
+    builder
+        .h3("Statistics")
+        .p(|b| b.link(&link_sheet(id), "Character Sheet"))
+        .p(|b| b.link(&link_edit_stats(id), "Edit Statistics"))
+}
+
+fn get_sheet_html(data: &RpgData, id: CharacterId) -> Option<RawHtml<String>> {
+    let portrait_uri = link_portrait(id);
+
+    data.characters.get(id).map(|character| {
+        let builder = create_html()
+            .h1(&get_character_name(data, id).unwrap_or_default())
+            .center(|b| b.svg(&portrait_uri, "200"));
+
+        let builder = match data.stats.calculate(&character.stats) {
+            Ok(sheet) => add_sheet(builder, &sheet),
+            Err(e) => builder.p(|b| b.text(&e.to_string())),
+        };
+
+        RawHtml(
+            builder
+                .p(|b| b.link(&link_edit_stats(id), "Edit"))
+                .p(|b| b.link(&link_character_details(id), "Back"))
+                .finish(),
+        )
+    })
+}
+
+fn add_sheet(builder: HtmlBuilder, sheet: &StatSheet) -> HtmlBuilder {
+    builder
+        .h2("Attributes")
+        .add_values(&sheet.attributes)
+        .h2("Derived Values")
+        .add_values(&sheet.derived)
+        .h2("Skills")
+        .add_values(&sheet.skills)
+}
+
+fn get_edit_html(data: &RpgData, id: CharacterId, error: &str) -> Option<RawHtml<String>> {
+    let submit_uri = uri!(update_stats_route(id.id())).to_string();
+
+    data.characters.get(id).map(|character| {
+        let sheet = data.stats.calculate(&character.stats).unwrap_or_default();
+
+        let builder = create_html()
+            .h1(&format!(
+                "Edit Statistics: {}",
+                get_character_name(data, id).unwrap_or_default()
+            ))
+            .form(&submit_uri, |mut b| {
+                b = b.error(error);
+
+                for (attribute, (name, value)) in
+                    data.stats.attributes.iter().zip(&sheet.attributes)
+                {
+                    b = b.number_input(
+                        &format!("{}:", name),
+                        "attributes",
+                        to_input(*value),
+                        to_input(attribute.min),
+                        to_input(attribute.max),
+                    );
+                }
+
+                for (skill, (name, value)) in data.stats.skills.iter().zip(&sheet.skills) {
+                    b = b.number_input(
+                        &format!("{}:", name),
+                        "skills",
+                        to_input(*value),
+                        0,
+                        skill.max.map(to_input).unwrap_or(MAX_SKILL),
+                    );
+                }
+
+                b
+            })
+            .p(|b| b.link(&link_sheet(id), "Back"));
+
+        RawHtml(builder.finish())
+    })
+}
+
+fn to_input(value: i32) -> usize {
+    value.max(0) as usize
+}
+
+trait StatsBuilder {
+    fn add_values(self, values: &[(String, i32)]) -> Self;
+}
+
+impl StatsBuilder for HtmlBuilder {
+    fn add_values(self, values: &[(String, i32)]) -> Self {
+        values.iter().fold(self, |b, (name, value)| {
+            b.field(&format!("{}:", name), &value.to_string())
+        })
+    }
+}
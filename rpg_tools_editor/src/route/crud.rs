@@ -0,0 +1,355 @@
+//! Generic routes to list, show, create, edit & delete any named element.
+//!
+//! A new kind of element gets a working editor section by implementing [`CrudElement`]
+//! & registering its routes with [`get_crud_routes`].
+
+use crate::html::{create_html, EditorBuilder};
+use crate::route::util::get_all_html;
+use crate::EditorData;
+use anyhow::Result;
+use rocket::data::FromData;
+use rocket::form::Form;
+use rocket::http::{Method, Status};
+use rocket::response::content::RawHtml;
+use rocket::route::{Handler, Outcome};
+use rocket::{Data, Request, Route};
+use rpg_tools_core::model::description::WithDescription;
+use rpg_tools_core::model::element::ElementRef;
+use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::visibility::{Visibility, WithVisibility};
+use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::delete::element::delete_element;
+use rpg_tools_core::usecase::delete::DeleteResult;
+use rpg_tools_core::usecase::edit::description::update_description;
+use rpg_tools_core::usecase::edit::name::check_name;
+use rpg_tools_core::utils::storage::{Element, Id, Storage};
+use rpg_tools_html::HtmlBuilder;
+use std::marker::PhantomData;
+
+/// An element, which can be edited with the generic routes.
+pub trait CrudElement<I: Id + Send + Sync + 'static>:
+    Element<I> + WithName + WithDescription + Sized + Send + Sync + 'static
+{
+    /// The name of the element type used in titles.
+    const TITLE: &'static str;
+    /// The title of the list of all elements.
+    const PLURAL: &'static str;
+
+    fn create(id: I) -> Self;
+
+    fn to_ref(id: I) -> ElementRef;
+
+    fn get_storage(data: &RpgData) -> &Storage<I, Self>;
+
+    fn get_storage_mut(data: &mut RpgData) -> &mut Storage<I, Self>;
+
+    /// Adds the type specific data to the details page.
+    fn add_details(&self, _data: &RpgData, builder: HtmlBuilder) -> HtmlBuilder {
+        builder
+    }
+
+    /// Returns the access to the visibility, if the element type supports it.
+    fn visibility_support() -> Option<VisibilitySupport<Self>> {
+        None
+    }
+}
+
+/// Accesses the [`visibility`](Visibility) of an element type implementing [`WithVisibility`].
+pub struct VisibilitySupport<T> {
+    get: fn(&T) -> Visibility,
+    set: fn(&mut T, Visibility),
+}
+
+impl<T: WithVisibility> VisibilitySupport<T> {
+    pub fn new() -> Self {
+        Self {
+            get: T::visibility,
+            set: T::set_visibility,
+        }
+    }
+}
+
+/// Returns the name of the storage, which is also the first segment of the routes.
+fn get_storage_name<I: Id + Send + Sync + 'static, T: CrudElement<I>>() -> &'static str {
+    T::to_ref(I::new(0)).storage()
+}
+
+// Links
+
+pub fn link_all<I: Id + Send + Sync + 'static, T: CrudElement<I>>() -> String {
+    format!("/{}/all", get_storage_name::<I, T>())
+}
+
+pub fn link_details<I: Id + Send + Sync + 'static, T: CrudElement<I>>(id: I) -> String {
+    format!("/{}/{}/details", get_storage_name::<I, T>(), id.id())
+}
+
+fn link_edit<I: Id + Send + Sync + 'static, T: CrudElement<I>>(id: I) -> String {
+    format!("/{}/{}/edit", get_storage_name::<I, T>(), id.id())
+}
+
+fn link_update<I: Id + Send + Sync + 'static, T: CrudElement<I>>(id: I) -> String {
+    format!("/{}/{}/update", get_storage_name::<I, T>(), id.id())
+}
+
+fn link_delete<I: Id + Send + Sync + 'static, T: CrudElement<I>>(id: I) -> String {
+    format!("/{}/{}/delete", get_storage_name::<I, T>(), id.id())
+}
+
+// Routes
+
+#[derive(Copy, Clone, Debug)]
+enum Action {
+    All,
+    New,
+    Details,
+    Edit,
+    Update,
+    Delete,
+}
+
+/// Handles all routes of an element type.
+struct CrudHandler<I, T> {
+    action: Action,
+    phantom: PhantomData<fn() -> (I, T)>,
+}
+
+impl<I, T> Clone for CrudHandler<I, T> {
+    fn clone(&self) -> Self {
+        Self {
+            action: self.action,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[derive(FromForm, Debug)]
+pub struct CrudUpdate<'r> {
+    name: &'r str,
+    description: &'r str,
+    visibility: Option<&'r str>,
+}
+
+/// Returns the routes of an element type, which need to be mounted.
+pub fn get_crud_routes<I: Id + Send + Sync + 'static, T: CrudElement<I>>() -> Vec<Route> {
+    let route = |method: Method, path: &str, action: Action| {
+        Route::new(
+            method,
+            &format!("/{}{}", get_storage_name::<I, T>(), path),
+            CrudHandler::<I, T> {
+                action,
+                phantom: PhantomData,
+            },
+        )
+    };
+
+    vec![
+        route(Method::Get, "/all", Action::All),
+        route(Method::Get, "/new", Action::New),
+        route(Method::Get, "/<id>/details", Action::Details),
+        route(Method::Get, "/<id>/edit", Action::Edit),
+        route(Method::Post, "/<id>/update", Action::Update),
+        route(Method::Get, "/<id>/delete", Action::Delete),
+    ]
+}
+
+#[rocket::async_trait]
+impl<I: Id + Send + Sync + 'static, T: CrudElement<I>> Handler for CrudHandler<I, T> {
+    async fn handle<'r>(&self, request: &'r Request<'_>, data: Data<'r>) -> Outcome<'r> {
+        let state = match request.rocket().state::<EditorData>() {
+            Some(state) => state,
+            None => return Outcome::error(Status::InternalServerError),
+        };
+        let id = match self.action {
+            Action::All | Action::New => None,
+            _ => match request.param::<usize>(1) {
+                Some(Ok(id)) => Some(I::new(id)),
+                _ => return Outcome::forward(data, Status::NotFound),
+            },
+        };
+
+        let html = match (self.action, id) {
+            (Action::All, _) => {
+                let data = state.data.lock().expect("lock shared data");
+                Some(get_all_html(T::get_storage(&data), T::PLURAL))
+            }
+            (Action::New, _) => {
+                let mut data = state.data.lock().expect("lock shared data");
+                let id = T::get_storage_mut(&mut data).create(T::create);
+
+                println!("Create {} {}", get_storage_name::<I, T>(), id.id());
+
+                get_edit_html::<I, T>(&data, id, &CrudErrors::default())
+            }
+            (Action::Details, Some(id)) => {
+                let data = state.data.lock().expect("lock shared data");
+                get_details_html::<I, T>(&data, id)
+            }
+            (Action::Edit, Some(id)) => {
+                let data = state.data.lock().expect("lock shared data");
+                get_edit_html::<I, T>(&data, id, &CrudErrors::default())
+            }
+            (Action::Update, Some(id)) => {
+                let form = match Form::<CrudUpdate<'r>>::from_data(request, data).await {
+                    rocket::outcome::Outcome::Success(form) => form,
+                    _ => return Outcome::error(Status::UnprocessableEntity),
+                };
+                println!(
+                    "Update {} {} with {:?}",
+                    get_storage_name::<I, T>(),
+                    id.id(),
+                    form
+                );
+                let mut data = state.data.lock().expect("lock shared data");
+
+                if let Err(errors) = update::<I, T>(&mut data, id, &form) {
+                    get_edit_html::<I, T>(&data, id, &errors)
+                } else {
+                    get_details_html::<I, T>(&data, id)
+                }
+            }
+            (Action::Delete, Some(id)) => {
+                let mut data = state.data.lock().expect("lock shared data");
+
+                match delete_element(&mut data, id, T::get_storage_mut, T::to_ref) {
+                    DeleteResult::Ok => {
+                        println!("Deleted {} {}", get_storage_name::<I, T>(), id.id());
+                        Some(get_all_html(T::get_storage(&data), T::PLURAL))
+                    }
+                    DeleteResult::Blocked(reason) => {
+                        println!(
+                            "Deleting {} {} is blocked by {:?}",
+                            get_storage_name::<I, T>(),
+                            id.id(),
+                            reason
+                        );
+                        get_details_html::<I, T>(&data, id)
+                    }
+                    DeleteResult::NotFound => None,
+                }
+            }
+            _ => None,
+        };
+
+        Outcome::from(request, html)
+    }
+}
+
+/// The errors of the fields of the edit form.
+#[derive(Default)]
+struct CrudErrors {
+    name: String,
+    visibility: String,
+    description: String,
+}
+
+/// Validates all fields first & only updates the element, if all are valid.
+fn update<I: Id + Send + Sync + 'static, T: CrudElement<I>>(
+    data: &mut RpgData,
+    id: I,
+    update: &CrudUpdate<'_>,
+) -> Result<(), CrudErrors> {
+    let storage = T::get_storage_mut(data);
+    let name = check_name(storage, id, update.name);
+    let visibility = match (update.visibility, T::visibility_support()) {
+        (Some(visibility), Some(support)) => {
+            Visibility::parse(visibility).map(|visibility| Some((support.set, visibility)))
+        }
+        _ => Ok(None),
+    };
+
+    match (name, visibility) {
+        (Ok(name), Ok(visibility)) => {
+            if let Some(element) = storage.get_mut(id) {
+                element.set_name(name);
+
+                if let Some((set, visibility)) = visibility {
+                    set(element, visibility);
+                }
+            }
+
+            update_description(storage, id, update.description).map_err(|e| CrudErrors {
+                description: e.to_string(),
+                ..CrudErrors::default()
+            })
+        }
+        (name, visibility) => Err(CrudErrors {
+            name: get_error(name),
+            visibility: get_error(visibility),
+            description: String::new(),
+        }),
+    }
+}
+
+fn get_error<T>(result: Result<T>) -> String {
+    result.err().map(|e| e.to_string()).unwrap_or_default()
+}
+
+fn get_visibility<I: Id + Send + Sync + 'static, T: CrudElement<I>>(
+    element: &T,
+) -> Option<Visibility> {
+    T::visibility_support().map(|support| (support.get)(element))
+}
+
+// Pages
+
+fn get_details_html<I: Id + Send + Sync + 'static, T: CrudElement<I>>(
+    data: &RpgData,
+    id: I,
+) -> Option<RawHtml<String>> {
+    T::get_storage(data).get(id).map(|element| {
+        let builder = create_html()
+            .h1(&format!("{}: {}", T::TITLE, element.name()))
+            .h2("Data")
+            .field_usize("Id:", id.id())
+            .option(get_visibility(element), |visibility, b| {
+                b.field("Visibility:", &visibility.to_string())
+            });
+
+        let builder = element
+            .add_details(data, builder)
+            .add_description(data, element.description())
+            .add_backlinks(data, T::to_ref(id))
+            .p(|b| b.link(&link_edit::<I, T>(id), "Edit"))
+            .p(|b| b.link(&link_delete::<I, T>(id), "Delete"))
+            .p(|b| b.link(&link_all::<I, T>(), "Back"));
+
+        RawHtml(builder.finish())
+    })
+}
+
+fn get_edit_html<I: Id + Send + Sync + 'static, T: CrudElement<I>>(
+    data: &RpgData,
+    id: I,
+    errors: &CrudErrors,
+) -> Option<RawHtml<String>> {
+    let submit_uri = link_update::<I, T>(id);
+
+    T::get_storage(data).get(id).map(|element| {
+        let builder = create_html()
+            .h1(&format!("Edit {}: {}", T::TITLE, element.name()))
+            .field_usize("Id:", id.id())
+            .form(&submit_uri, |b| {
+                b.text_input("Name", "name", element.name().str())
+                    .error(&errors.name)
+                    .option(get_visibility(element), |visibility, b| {
+                        b.select(
+                            "Visibility",
+                            "visibility",
+                            &Visibility::get_all(),
+                            &visibility.to_string(),
+                        )
+                        .error(&errors.visibility)
+                    })
+                    .text_area(
+                        "Description",
+                        "description",
+                        element.description().unwrap_or(""),
+                    )
+                    .error(&errors.description)
+            })
+            .p(|b| b.link(&link_details::<I, T>(id), "Back"));
+
+        RawHtml(builder.finish())
+    })
+}
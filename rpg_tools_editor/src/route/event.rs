@@ -0,0 +1,343 @@
+use crate::html::{create_html, EditorBuilder};
+use crate::route::link_home;
+use crate::route::util::{get_all_elements, link_element_details};
+use crate::EditorData;
+use anyhow::Result;
+use rocket::form::Form;
+use rocket::response::content::RawHtml;
+use rocket::State;
+use rpg_tools_core::model::description::WithDescription;
+use rpg_tools_core::model::element::ElementRef;
+use rpg_tools_core::model::event::{Event, EventId};
+use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::visibility::{Audience, Visibility, WithVisibility};
+use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::create::event::create_event;
+use rpg_tools_core::usecase::delete::element::delete_element;
+use rpg_tools_core::usecase::delete::DeleteResult;
+use rpg_tools_core::usecase::edit::description::update_description;
+use rpg_tools_core::usecase::edit::event::{
+    add_participant, remove_participant, update_event_date,
+};
+use rpg_tools_core::usecase::edit::name::update_name;
+use rpg_tools_core::usecase::edit::visibility::update_visibility;
+use rpg_tools_core::usecase::get::event::get_timeline;
+use rpg_tools_core::usecase::get::name::{get_character_name, get_element_name};
+use rpg_tools_core::utils::storage::{Element, Id};
+use rpg_tools_html::HtmlBuilder;
+
+#[get("/event/all")]
+pub fn get_timeline_route(state: &State<EditorData>) -> RawHtml<String> {
+    let data = state.data.lock().expect("lock shared data");
+    get_timeline_html(&data)
+}
+
+pub fn link_timeline() -> String {
+    uri!(get_timeline_route()).to_string()
+}
+
+#[get("/event/new")]
+pub fn add_event(state: &State<EditorData>) -> Option<RawHtml<String>> {
+    let mut data = state.data.lock().expect("lock shared data");
+    let id = create_event(&mut data);
+    println!("Create event {}", id.id());
+    get_edit_html(&data, id, "")
+}
+
+#[get("/event/<id>/details")]
+pub fn get_event_details(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let data = state.data.lock().expect("lock shared data");
+    get_details_html(&data, EventId::new(id))
+}
+
+pub fn link_event_details(id: EventId) -> String {
+    uri!(get_event_details(id.id())).to_string()
+}
+
+#[get("/event/<id>/edit")]
+pub fn edit_event(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let data = state.data.lock().expect("lock shared data");
+    get_edit_html(&data, EventId::new(id), "")
+}
+
+#[get("/event/<id>/delete")]
+pub fn delete_event(state: &State<EditorData>, id: usize) -> RawHtml<String> {
+    let mut data = state.data.lock().expect("lock shared data");
+
+    if delete_element(
+        &mut data,
+        EventId::new(id),
+        |data| &mut data.events,
+        ElementRef::Event,
+    ) == DeleteResult::Ok
+    {
+        println!("Deleted event {}", id);
+    }
+
+    get_timeline_html(&data)
+}
+
+#[derive(FromForm, Debug)]
+pub struct EventUpdate<'r> {
+    name: &'r str,
+    date: &'r str,
+    visibility: &'r str,
+    description: &'r str,
+}
+
+#[post("/event/<id>/update", data = "<update>")]
+pub fn update_event(
+    state: &State<EditorData>,
+    id: usize,
+    update: Form<EventUpdate<'_>>,
+) -> Option<RawHtml<String>> {
+    println!("Update event {} with {:?}", id, update);
+    let mut data = state.data.lock().expect("lock shared data");
+    let id = EventId::new(id);
+
+    if let Err(e) = update_all(&mut data, id, &update) {
+        return get_edit_html(&data, id, &e.to_string());
+    }
+
+    get_details_html(&data, id)
+}
+
+fn update_all(data: &mut RpgData, id: EventId, update: &EventUpdate<'_>) -> Result<()> {
+    update_name(&mut data.events, id, update.name)?;
+    let date = data.time.calendar.parse_date(update.date)?;
+    update_event_date(data, id, date)?;
+    let visibility = Visibility::parse(update.visibility)?;
+    update_visibility(&mut data.events, id, visibility)?;
+    update_description(&mut data.events, id, update.description)
+}
+
+#[get("/event/<id>/participants")]
+pub fn get_participants(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let data = state.data.lock().expect("lock shared data");
+    get_participants_html(&data, EventId::new(id), "")
+}
+
+#[derive(FromForm, Debug)]
+pub struct ParticipantUpdate {
+    participant: usize,
+}
+
+#[post("/event/<id>/participants/add/<storage>", data = "<update>")]
+pub fn add_participant_route(
+    state: &State<EditorData>,
+    id: usize,
+    storage: &str,
+    update: Form<ParticipantUpdate>,
+) -> Option<RawHtml<String>> {
+    println!("Add {} {} to event {}", storage, update.participant, id);
+    let mut data = state.data.lock().expect("lock shared data");
+    let id = EventId::new(id);
+
+    let result = ElementRef::parse(storage, update.participant)
+        .and_then(|participant| add_participant(&mut data, id, participant));
+
+    get_participants_html(&data, id, &get_error(result))
+}
+
+#[get("/event/<id>/participants/remove/<storage>/<participant>")]
+pub fn remove_participant_route(
+    state: &State<EditorData>,
+    id: usize,
+    storage: &str,
+    participant: usize,
+) -> Option<RawHtml<String>> {
+    println!("Remove {} {} from event {}", storage, participant, id);
+    let mut data = state.data.lock().expect("lock shared data");
+    let id = EventId::new(id);
+
+    let result = ElementRef::parse(storage, participant)
+        .and_then(|participant| remove_participant(&mut data, id, participant));
+
+    get_participants_html(&data, id, &get_error(result))
+}
+
+fn get_error(result: Result<()>) -> String {
+    result.err().map(|e| e.to_string()).unwrap_or_default()
+}
+
+fn get_timeline_html(data: &RpgData) -> RawHtml<String> {
+    let timeline = get_timeline(data, Audience::GameMaster);
+
+    RawHtml(
+        create_html()
+            .h1("Timeline")
+            .field_usize("Count:", timeline.len())
+            .add_events(data, &timeline)
+            .p(|b| b.link(&uri!(add_event()).to_string(), "Add"))
+            .p(|b| b.link(&link_home(), "Back"))
+            .finish(),
+    )
+}
+
+fn get_details_html(data: &RpgData, id: EventId) -> Option<RawHtml<String>> {
+    let edit_uri = uri!(edit_event(id.id())).to_string();
+    let delete_uri = uri!(delete_event(id.id())).to_string();
+    let participants_uri = uri!(get_participants(id.id())).to_string();
+
+    data.events.get(id).map(|event| {
+        let builder = create_html()
+            .h1(&format!("Event: {}", event.name()))
+            .h2("Data")
+            .field_usize("Id:", id.id())
+            .field("Date:", &format_date(data, event))
+            .field("Visibility:", &event.visibility().to_string())
+            .h3("Participants")
+            .add_participants(data, event, None)
+            .p(|b| b.link(&participants_uri, "Edit Participants"))
+            .add_description(data, event.description())
+            .add_backlinks(data, ElementRef::Event(id))
+            .p(|b| b.link(&edit_uri, "Edit"))
+            .p(|b| b.link(&delete_uri, "Delete"))
+            .p(|b| b.link(&link_timeline(), "Back"));
+
+        RawHtml(builder.finish())
+    })
+}
+
+fn get_edit_html(data: &RpgData, id: EventId, error: &str) -> Option<RawHtml<String>> {
+    let submit_uri = uri!(update_event(id.id())).to_string();
+
+    data.events.get(id).map(|event| {
+        let builder = create_html()
+            .h1(&format!("Edit Event: {}", event.name()))
+            .field_usize("Id:", id.id())
+            .form(&submit_uri, |b| {
+                b.error(error)
+                    .text_input("Name", "name", event.name().str())
+                    .text_input("Date (year-month-day)", "date", &event.date.to_string())
+                    .select(
+                        "Visibility",
+                        "visibility",
+                        &Visibility::get_all(),
+                        &event.visibility().to_string(),
+                    )
+                    .text_area(
+                        "Description",
+                        "description",
+                        event.description().unwrap_or(""),
+                    )
+            })
+            .p(|b| b.link(&link_event_details(id), "Back"));
+
+        RawHtml(builder.finish())
+    })
+}
+
+fn get_participants_html(data: &RpgData, id: EventId, error: &str) -> Option<RawHtml<String>> {
+    data.events.get(id).map(|event| {
+        let builder = create_html()
+            .h1(&format!("Participants: {}", event.name()))
+            .add_participants(data, event, Some(id))
+            .h2("Add Participant")
+            .p(|b| b.text(error))
+            .add_participant_form(id, "Character", "character", &get_characters(data))
+            .add_participant_form(
+                id,
+                "Building",
+                "building",
+                &get_all_elements(&data.building_manager),
+            )
+            .add_participant_form(
+                id,
+                "Street",
+                "street",
+                &get_all_elements(&data.street_manager),
+            )
+            .add_participant_form(id, "Town", "town", &get_all_elements(&data.town_manager))
+            .p(|b| b.link(&link_event_details(id), "Back"));
+
+        RawHtml(builder.finish())
+    })
+}
+
+fn get_characters(data: &RpgData) -> Vec<(usize, String)> {
+    data.characters
+        .get_all()
+        .iter()
+        .map(|character| {
+            (
+                character.id().id(),
+                get_character_name(data, character.id()).unwrap_or_default(),
+            )
+        })
+        .collect()
+}
+
+fn format_date(data: &RpgData, event: &Event) -> String {
+    data.time.calendar.format(&event.date)
+}
+
+pub trait EventBuilder {
+    /// Adds a list of events with their dates.
+    fn add_events(self, data: &RpgData, events: &[EventId]) -> Self;
+
+    /// Adds the participants & links to remove them, if the id of the event is given.
+    fn add_participants(self, data: &RpgData, event: &Event, removable: Option<EventId>) -> Self;
+
+    fn add_participant_form<S: AsRef<str>>(
+        self,
+        id: EventId,
+        title: &str,
+        storage: &str,
+        options: &[(usize, S)],
+    ) -> Self;
+}
+
+impl EventBuilder for HtmlBuilder {
+    fn add_events(self, data: &RpgData, events: &[EventId]) -> Self {
+        self.list(events, |b, &id| {
+            b.option(data.events.get(id), |event, b| {
+                b.text(&format!("{}:", format_date(data, event)))
+                    .link(&link_event_details(id), event.name().str())
+            })
+        })
+    }
+
+    fn add_participants(self, data: &RpgData, event: &Event, removable: Option<EventId>) -> Self {
+        let participants = event.participants();
+
+        self.field_usize("Count:", participants.len())
+            .list(&participants, |b, &participant| {
+                let name = get_element_name(data, participant).unwrap_or_default();
+                let b = b
+                    .text(&format!("{}:", participant.storage()))
+                    .link(&link_element_details(participant), &name);
+
+                match removable {
+                    Some(id) => b.text(" ").link(
+                        &uri!(remove_participant_route(
+                            id.id(),
+                            participant.storage(),
+                            participant.id()
+                        ))
+                        .to_string(),
+                        "Remove",
+                    ),
+                    None => b,
+                }
+            })
+    }
+
+    fn add_participant_form<S: AsRef<str>>(
+        self,
+        id: EventId,
+        title: &str,
+        storage: &str,
+        options: &[(usize, S)],
+    ) -> Self {
+        let submit_uri = uri!(add_participant_route(id.id(), storage)).to_string();
+        let options: Vec<(usize, &str)> = options
+            .iter()
+            .map(|(id, name)| (*id, name.as_ref()))
+            .collect();
+
+        self.h3(title).form(&submit_uri, |b| {
+            b.select_id("Participant", "participant", &options, 0)
+        })
+    }
+}
@@ -1,27 +1,46 @@
 use crate::html::{create_html, EditorBuilder};
+use crate::route::building::entrance::{add_entrance_route, delete_entrance, get_entrance_editor};
+use crate::route::building::interior::{
+    add_floor_route, delete_floor, edit_interior_route, get_floor_editor, get_floor_editor_map,
+    get_floor_map, get_interior, update_floor_editor,
+};
+use crate::route::building::lot::{get_lot_editor, get_lot_editor_map, toggle_lot_tile};
+use crate::route::building::position::{
+    get_move_editor, get_move_editor_map, move_building_route, rotate_building_route,
+    update_move_editor,
+};
+use crate::route::building::subdivide::{
+    get_subdivide_editor, merge_building_route, split_building_route,
+};
 use crate::route::building::{delete_building_route, link_all_buildings};
 use crate::route::building::{
     edit_building, get_all_buildings, get_building_details, update_building,
 };
 use crate::route::character::culture::{
-    add_culture, edit_culture, get_all_cultures, get_culture_details, link_all_cultures,
-    update_culture,
+    add_culture, delete_culture_route, edit_culture, get_all_cultures, get_culture_details,
+    link_all_cultures, update_culture,
 };
+use crate::route::character::family::{add_relation, get_family_tree, remove_relation};
+use crate::route::character::npc::{accept_npcs, get_npc_generator, preview_npcs};
+use crate::route::character::stats::{edit_stats, get_sheet, update_stats_route};
 use crate::route::character::{
-    add_character, edit_character, get_all_characters, get_character_details, link_all_characters,
-    update_character,
+    add_character, edit_character, generate_name, get_all_characters, get_character_details,
+    get_portrait, link_all_characters, update_character,
 };
-use crate::route::mountain::{
-    add_mountain, edit_mountain, get_all_mountains, get_mountain_details, link_all_mountains,
-    update_mountain,
+use crate::route::crud::{get_crud_routes, link_all};
+use crate::route::event::{
+    add_event, add_participant_route, delete_event, edit_event, get_event_details,
+    get_participants, get_timeline_route, link_timeline, remove_participant_route, update_event,
 };
-use crate::route::river::{
-    add_river, edit_river, get_all_rivers, get_river_details, link_all_rivers, update_river,
+use crate::route::organization::{
+    add_building_route, add_member_route, manage_organization, remove_building_route,
+    remove_member_route, remove_relation_route, update_relation_route, update_structure,
 };
-use crate::route::street::{
-    add_street, edit_street, get_all_streets, get_street_details, link_all_streets, update_street,
+use crate::route::time::{edit_time, get_time, link_time, update_time};
+use crate::route::town::address::{get_address_editor, number_houses_route};
+use crate::route::town::building::{
+    add_building, get_building_creator, get_building_creator_map, update_building_creator,
 };
-use crate::route::town::building::{add_building, get_building_creator, get_building_creator_map};
 use crate::route::town::link_all_towns;
 use crate::route::town::street::{
     add_street_to_town, get_street_editor, get_street_editor_map, remove_street_from_town,
@@ -31,18 +50,31 @@ use crate::route::town::terrain::{
     edit_terrain_route, get_terrain_editor, get_terrain_editor_map, update_terrain_editor,
 };
 use crate::route::town::{
-    add_town, edit_town, get_all_towns, get_town_details, get_town_map, update_town,
+    add_town, edit_town, get_all_towns, get_town_details, get_town_map, get_town_player_map,
+    get_town_player_view, update_town,
 };
+use crate::route::util::link_element_details;
 use crate::EditorData;
 use rocket::http::Status;
 use rocket::response::content::RawHtml;
 use rocket::{Route, State};
+use rpg_tools_core::model::organization::{Organization, OrganizationId};
+use rpg_tools_core::model::world::mountain::{Mountain, MountainId};
+use rpg_tools_core::model::world::river::{River, RiverId};
+use rpg_tools_core::model::world::street::{Street, StreetId};
+use rpg_tools_core::usecase::get::link::get_broken_links;
+use rpg_tools_core::usecase::get::name::get_element_name;
+use rpg_tools_html::escape::escape_html;
 
 pub mod building;
 pub mod character;
+pub mod crud;
+pub mod event;
 pub mod mountain;
+pub mod organization;
 pub mod river;
 pub mod street;
+pub mod time;
 pub mod town;
 pub mod util;
 
@@ -58,10 +90,22 @@ pub fn home(state: &State<EditorData>) -> RawHtml<String> {
             .add_storage_link("Buildings:", &link_all_buildings(), &data.building_manager)
             .add_storage_link("Characters:", &link_all_characters(), &data.characters)
             .add_storage_link("Cultures:", &link_all_cultures(), &data.cultures)
-            .add_storage_link("Mountains:", &link_all_mountains(), &data.mountain_manager)
-            .add_storage_link("Rivers:", &link_all_rivers(), &data.river_manager)
-            .add_storage_link("Streets:", &link_all_streets(), &data.street_manager)
+            .add_storage_link("Events:", &link_timeline(), &data.events)
+            .add_storage_link(
+                "Mountains:",
+                &link_all::<_, Mountain>(),
+                &data.mountain_manager,
+            )
+            .add_storage_link(
+                "Organizations:",
+                &link_all::<_, Organization>(),
+                &data.organizations,
+            )
+            .add_storage_link("Rivers:", &link_all::<_, River>(), &data.river_manager)
+            .add_storage_link("Streets:", &link_all::<_, Street>(), &data.street_manager)
             .add_storage_link("Towns:", &link_all_towns(), &data.town_manager)
+            .p(|b| b.link(&link_time(), "Time"))
+            .p(|b| b.link(&link_broken_links(), "Broken Links"))
             .p(|b| b.link(&save_uri, "Save"))
             .finish(),
     )
@@ -71,6 +115,34 @@ pub fn link_home() -> String {
     uri!(home()).to_string()
 }
 
+#[get("/broken_links")]
+pub fn get_broken_links_route(state: &State<EditorData>) -> RawHtml<String> {
+    let data = state.data.lock().expect("lock shared data");
+    let broken_links = get_broken_links(&data);
+
+    RawHtml(
+        create_html()
+            .h1("Broken Links")
+            .field_usize("Count:", broken_links.len())
+            .list(&broken_links, |b, broken| {
+                let name = get_element_name(&data, broken.element)
+                    .unwrap_or_else(|| broken.element.to_string());
+                b.link(&link_element_details(broken.element), &escape_html(&name))
+                    .text(&format!(
+                        ": [[{}]] - {}",
+                        escape_html(&broken.link),
+                        escape_html(&broken.error)
+                    ))
+            })
+            .p(|b| b.link(&link_home(), "Back"))
+            .finish(),
+    )
+}
+
+pub fn link_broken_links() -> String {
+    uri!(get_broken_links_route()).to_string()
+}
+
 #[get("/save")]
 pub fn save(state: &State<EditorData>) -> Status {
     let data = state.data.lock().expect("lock shared data");
@@ -87,27 +159,15 @@ pub fn get_routes() -> Vec<Route> {
     let mut routes = routes![
         home,
         save,
-        get_all_mountains,
-        get_mountain_details,
-        add_mountain,
-        edit_mountain,
-        update_mountain,
-        get_all_rivers,
-        get_river_details,
-        add_river,
-        edit_river,
-        update_river,
-        get_all_streets,
-        get_street_details,
-        add_street,
-        edit_street,
-        update_street,
+        get_broken_links_route,
         get_all_towns,
         get_town_details,
         add_town,
         edit_town,
         update_town,
         get_town_map,
+        get_town_player_view,
+        get_town_player_map,
         get_terrain_editor,
         get_terrain_editor_map,
         update_terrain_editor,
@@ -118,26 +178,88 @@ pub fn get_routes() -> Vec<Route> {
         get_building_details,
         get_building_creator,
         get_building_creator_map,
+        update_building_creator,
         add_building,
         edit_building,
         delete_building_route,
         update_building,
+        get_interior,
+        add_floor_route,
+        delete_floor,
+        get_floor_map,
+        get_floor_editor,
+        update_floor_editor,
+        get_floor_editor_map,
+        edit_interior_route,
+        get_lot_editor,
+        get_lot_editor_map,
+        toggle_lot_tile,
+        get_move_editor,
+        update_move_editor,
+        get_move_editor_map,
+        move_building_route,
+        rotate_building_route,
+        get_entrance_editor,
+        add_entrance_route,
+        delete_entrance,
+        get_subdivide_editor,
+        split_building_route,
+        merge_building_route,
+        get_address_editor,
+        number_houses_route,
         get_street_editor,
         get_street_editor_map,
         update_street_editor,
         add_street_to_town,
         remove_street_from_town,
+        get_time,
+        edit_time,
+        update_time,
         get_all_characters,
         get_character_details,
         add_character,
         edit_character,
+        generate_name,
+        get_portrait,
         update_character,
+        get_npc_generator,
+        preview_npcs,
+        accept_npcs,
+        get_sheet,
+        edit_stats,
+        update_stats_route,
+        get_family_tree,
+        add_relation,
+        remove_relation,
+        get_timeline_route,
+        add_event,
+        get_event_details,
+        edit_event,
+        delete_event,
+        update_event,
+        get_participants,
+        add_participant_route,
+        remove_participant_route,
+        manage_organization,
+        update_structure,
+        add_member_route,
+        remove_member_route,
+        add_building_route,
+        remove_building_route,
+        update_relation_route,
+        remove_relation_route,
         get_all_cultures,
         get_culture_details,
         add_culture,
         edit_culture,
+        delete_culture_route,
         update_culture,
     ]);
 
+    routes.extend(get_crud_routes::<MountainId, Mountain>());
+    routes.extend(get_crud_routes::<OrganizationId, Organization>());
+    routes.extend(get_crud_routes::<RiverId, River>());
+    routes.extend(get_crud_routes::<StreetId, Street>());
+
     routes
 }
@@ -0,0 +1,472 @@
+use crate::html::create_html;
+use crate::route::crud::{link_details, CrudElement, VisibilitySupport};
+use crate::route::town::link_town_organization;
+use crate::route::util::{get_all_elements, link_element_details};
+use crate::EditorData;
+use anyhow::Result;
+use rocket::form::Form;
+use rocket::response::content::RawHtml;
+use rocket::State;
+use rpg_tools_core::model::character::CharacterId;
+use rpg_tools_core::model::element::ElementRef;
+use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::organization::{Organization, OrganizationId, Relation};
+use rpg_tools_core::model::world::building::BuildingId;
+use rpg_tools_core::model::world::town::TownId;
+use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::edit::organization::{
+    add_building, add_member, remove_building, remove_member, update_headquarters, update_parent,
+    update_relation,
+};
+use rpg_tools_core::usecase::get::name::{get_character_name, get_element_name};
+use rpg_tools_core::usecase::get::organization::{get_children, get_memberships};
+use rpg_tools_core::utils::storage::{Element, Id, Storage};
+use rpg_tools_html::HtmlBuilder;
+
+#[get("/organization/<id>/manage")]
+pub fn manage_organization(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let data = state.data.lock().expect("lock shared data");
+    get_manage_html(&data, OrganizationId::new(id), "")
+}
+
+pub fn link_manage_organization(id: OrganizationId) -> String {
+    uri!(manage_organization(id.id())).to_string()
+}
+
+#[derive(FromForm, Debug)]
+pub struct StructureUpdate {
+    headquarters: Option<usize>,
+    parent: Option<usize>,
+}
+
+#[post("/organization/<id>/structure/update", data = "<update>")]
+pub fn update_structure(
+    state: &State<EditorData>,
+    id: usize,
+    update: Form<StructureUpdate>,
+) -> Option<RawHtml<String>> {
+    println!("Update structure of organization {} with {:?}", id, update);
+    let mut data = state.data.lock().expect("lock shared data");
+    let id = OrganizationId::new(id);
+
+    let result = update_headquarters(&mut data, id, update.headquarters.map(BuildingId::new))
+        .and_then(|_| update_parent(&mut data, id, update.parent.map(OrganizationId::new)));
+
+    get_manage_html(&data, id, &get_error(result))
+}
+
+#[derive(FromForm, Debug)]
+pub struct MemberUpdate<'r> {
+    character: usize,
+    role: &'r str,
+}
+
+#[post("/organization/<id>/members/add", data = "<update>")]
+pub fn add_member_route(
+    state: &State<EditorData>,
+    id: usize,
+    update: Form<MemberUpdate<'_>>,
+) -> Option<RawHtml<String>> {
+    println!("Add member to organization {} with {:?}", id, update);
+    let mut data = state.data.lock().expect("lock shared data");
+    let id = OrganizationId::new(id);
+
+    let result = add_member(
+        &mut data,
+        id,
+        CharacterId::new(update.character),
+        update.role,
+    );
+
+    get_manage_html(&data, id, &get_error(result))
+}
+
+#[get("/organization/<id>/members/remove/<character>")]
+pub fn remove_member_route(
+    state: &State<EditorData>,
+    id: usize,
+    character: usize,
+) -> Option<RawHtml<String>> {
+    println!("Remove member {} from organization {}", character, id);
+    let mut data = state.data.lock().expect("lock shared data");
+    let id = OrganizationId::new(id);
+
+    let result = remove_member(&mut data, id, CharacterId::new(character));
+
+    get_manage_html(&data, id, &get_error(result))
+}
+
+#[derive(FromForm, Debug)]
+pub struct BuildingUpdate {
+    building: usize,
+}
+
+#[post("/organization/<id>/buildings/add", data = "<update>")]
+pub fn add_building_route(
+    state: &State<EditorData>,
+    id: usize,
+    update: Form<BuildingUpdate>,
+) -> Option<RawHtml<String>> {
+    println!("Add building to organization {} with {:?}", id, update);
+    let mut data = state.data.lock().expect("lock shared data");
+    let id = OrganizationId::new(id);
+
+    let result = add_building(&mut data, id, BuildingId::new(update.building));
+
+    get_manage_html(&data, id, &get_error(result))
+}
+
+#[get("/organization/<id>/buildings/remove/<building>")]
+pub fn remove_building_route(
+    state: &State<EditorData>,
+    id: usize,
+    building: usize,
+) -> Option<RawHtml<String>> {
+    println!("Remove building {} from organization {}", building, id);
+    let mut data = state.data.lock().expect("lock shared data");
+    let id = OrganizationId::new(id);
+
+    let result = remove_building(&mut data, id, BuildingId::new(building));
+
+    get_manage_html(&data, id, &get_error(result))
+}
+
+#[derive(FromForm, Debug)]
+pub struct RelationUpdate<'r> {
+    organization: usize,
+    relation: &'r str,
+}
+
+#[post("/organization/<id>/relations/update", data = "<update>")]
+pub fn update_relation_route(
+    state: &State<EditorData>,
+    id: usize,
+    update: Form<RelationUpdate<'_>>,
+) -> Option<RawHtml<String>> {
+    println!("Update relation of organization {} with {:?}", id, update);
+    let mut data = state.data.lock().expect("lock shared data");
+    let id = OrganizationId::new(id);
+    let other = OrganizationId::new(update.organization);
+
+    let result = Relation::parse(update.relation)
+        .and_then(|relation| update_relation(&mut data, id, other, Some(relation)));
+
+    get_manage_html(&data, id, &get_error(result))
+}
+
+#[get("/organization/<id>/relations/remove/<other>")]
+pub fn remove_relation_route(
+    state: &State<EditorData>,
+    id: usize,
+    other: usize,
+) -> Option<RawHtml<String>> {
+    println!("Remove relation between organizations {} & {}", id, other);
+    let mut data = state.data.lock().expect("lock shared data");
+    let id = OrganizationId::new(id);
+
+    let result = update_relation(&mut data, id, OrganizationId::new(other), None);
+
+    get_manage_html(&data, id, &get_error(result))
+}
+
+fn get_error(result: Result<()>) -> String {
+    result.err().map(|e| e.to_string()).unwrap_or_default()
+}
+
+impl CrudElement<OrganizationId> for Organization {
+    const TITLE: &'static str = "Organization";
+    const PLURAL: &'static str = "Organizations";
+
+    fn create(id: OrganizationId) -> Self {
+        Organization::new(id)
+    }
+
+    fn to_ref(id: OrganizationId) -> ElementRef {
+        ElementRef::Organization(id)
+    }
+
+    fn get_storage(data: &RpgData) -> &Storage<OrganizationId, Self> {
+        &data.organizations
+    }
+
+    fn get_storage_mut(data: &mut RpgData) -> &mut Storage<OrganizationId, Self> {
+        &mut data.organizations
+    }
+
+    fn add_details(&self, data: &RpgData, builder: HtmlBuilder) -> HtmlBuilder {
+        add_organization_details(builder, data, self)
+    }
+
+    fn visibility_support() -> Option<VisibilitySupport<Self>> {
+        Some(VisibilitySupport::new())
+    }
+}
+
+/// Adds the structure, members, buildings & relations to the details page of an organization.
+fn add_organization_details(
+    builder: HtmlBuilder,
+    data: &RpgData,
+    organization: &Organization,
+) -> HtmlBuilder {
+    let id = organization.id();
+    let towns = get_towns(data, organization);
+
+    builder
+        .h3("Structure")
+        .add_element_field(
+            data,
+            "Parent:",
+            organization.parent.map(ElementRef::Organization),
+        )
+        .add_organizations(data, "Children:", &get_children(data, id))
+        .add_element_field(
+            data,
+            "Headquarters:",
+            organization.headquarters.map(ElementRef::Building),
+        )
+        .h3("Members")
+        .add_members(data, organization, None)
+        .h3("Buildings")
+        .add_buildings(data, organization, None)
+        .h3("Relations")
+        .add_relations(data, organization, None)
+        .p(|b| b.link(&link_manage_organization(id), "Manage"))
+        .h3("Maps")
+        .list(&towns, |b, &(town, name)| {
+            b.link(&link_town_organization(town, id), name)
+        })
+}
+
+/// Adds the organizations of a character to its details page.
+pub fn add_memberships(builder: HtmlBuilder, data: &RpgData, id: CharacterId) -> HtmlBuilder {
+    let memberships = get_memberships(data, id);
+
+    builder
+        .h3("Organizations")
+        .list(&memberships, |b, &(organization, role)| {
+            add_role(
+                b.add_element_link(data, ElementRef::Organization(organization)),
+                role,
+            )
+        })
+}
+
+/// Returns the towns with buildings of the organization.
+fn get_towns<'a>(data: &'a RpgData, organization: &Organization) -> Vec<(TownId, &'a str)> {
+    let mut towns = Vec::new();
+
+    for building in organization
+        .get_all_buildings()
+        .into_iter()
+        .filter_map(|id| data.building_manager.get(id))
+    {
+        if let Some(town) = data.town_manager.get(building.lot.town) {
+            if !towns.iter().any(|(id, _)| *id == town.id()) {
+                towns.push((town.id(), town.name().str()));
+            }
+        }
+    }
+
+    towns
+}
+
+fn get_manage_html(data: &RpgData, id: OrganizationId, error: &str) -> Option<RawHtml<String>> {
+    let structure_uri = uri!(update_structure(id.id())).to_string();
+    let member_uri = uri!(add_member_route(id.id())).to_string();
+    let building_uri = uri!(add_building_route(id.id())).to_string();
+    let relation_uri = uri!(update_relation_route(id.id())).to_string();
+
+    let characters: Vec<(usize, String)> = data
+        .characters
+        .get_all()
+        .iter()
+        .map(|character| {
+            (
+                character.id().id(),
+                get_character_name(data, character.id()).unwrap_or_default(),
+            )
+        })
+        .collect();
+    let characters: Vec<(usize, &str)> = characters
+        .iter()
+        .map(|(id, name)| (*id, name.as_str()))
+        .collect();
+    let buildings = get_all_elements(&data.building_manager);
+    let organizations: Vec<(usize, &str)> = get_all_elements(&data.organizations)
+        .into_iter()
+        .filter(|(other, _)| *other != id.id())
+        .collect();
+    let relations: Vec<String> = Relation::get_all().iter().map(|r| r.to_string()).collect();
+    let relations: Vec<&str> = relations.iter().map(|r| r.as_str()).collect();
+
+    data.organizations.get(id).map(|organization| {
+        let builder = create_html()
+            .h1(&format!("Manage Organization: {}", organization.name()))
+            .p(|b| b.text(error))
+            .h2("Structure")
+            .form(&structure_uri, |b| {
+                b.select_optional_id(
+                    "Headquarters",
+                    "headquarters",
+                    &buildings,
+                    organization.headquarters.map(|id| id.id()),
+                )
+                .select_optional_id(
+                    "Parent",
+                    "parent",
+                    &organizations,
+                    organization.parent.map(|id| id.id()),
+                )
+            })
+            .h2("Members")
+            .add_members(data, organization, Some(id))
+            .form(&member_uri, |b| {
+                b.select_id("Character", "character", &characters, 0)
+                    .text_input("Role", "role", "")
+            })
+            .h2("Buildings")
+            .add_buildings(data, organization, Some(id))
+            .form(&building_uri, |b| {
+                b.select_id("Building", "building", &buildings, 0)
+            })
+            .h2("Relations")
+            .add_relations(data, organization, Some(id))
+            .form(&relation_uri, |b| {
+                b.select_id("Organization", "organization", &organizations, 0)
+                    .select("Relation", "relation", &relations, relations[0])
+            })
+            .p(|b| b.link(&link_details::<_, Organization>(id), "Back"));
+
+        RawHtml(builder.finish())
+    })
+}
+
+fn add_role(builder: HtmlBuilder, role: &str) -> HtmlBuilder {
+    if role.is_empty() {
+        builder
+    } else {
+        builder.text(&format!(" ({})", role))
+    }
+}
+
+trait OrganizationBuilder {
+    fn add_element_link(self, data: &RpgData, element: ElementRef) -> Self;
+
+    fn add_element_field(self, data: &RpgData, title: &str, element: Option<ElementRef>) -> Self;
+
+    fn add_organizations(self, data: &RpgData, title: &str, ids: &[OrganizationId]) -> Self;
+
+    /// Adds the members & links to remove them, if the id of the organization is given.
+    fn add_members(
+        self,
+        data: &RpgData,
+        organization: &Organization,
+        removable: Option<OrganizationId>,
+    ) -> Self;
+
+    /// Adds the owned buildings & links to remove them, if the id of the organization is given.
+    fn add_buildings(
+        self,
+        data: &RpgData,
+        organization: &Organization,
+        removable: Option<OrganizationId>,
+    ) -> Self;
+
+    /// Adds the relations & links to remove them, if the id of the organization is given.
+    fn add_relations(
+        self,
+        data: &RpgData,
+        organization: &Organization,
+        removable: Option<OrganizationId>,
+    ) -> Self;
+}
+
+impl OrganizationBuilder for HtmlBuilder {
+    fn add_element_link(self, data: &RpgData, element: ElementRef) -> Self {
+        self.link(
+            &link_element_details(element),
+            &get_element_name(data, element).unwrap_or_default(),
+        )
+    }
+
+    fn add_element_field(self, data: &RpgData, title: &str, element: Option<ElementRef>) -> Self {
+        self.option(element, |element, b| {
+            b.complex_field(title, |b| b.add_element_link(data, element))
+        })
+    }
+
+    fn add_organizations(self, data: &RpgData, title: &str, ids: &[OrganizationId]) -> Self {
+        self.field_usize(title, ids.len()).list(ids, |b, &id| {
+            b.add_element_link(data, ElementRef::Organization(id))
+        })
+    }
+
+    fn add_members(
+        self,
+        data: &RpgData,
+        organization: &Organization,
+        removable: Option<OrganizationId>,
+    ) -> Self {
+        self.field_usize("Count:", organization.members.len()).list(
+            &organization.members,
+            |b, member| {
+                let b = add_role(
+                    b.add_element_link(data, ElementRef::Character(member.character)),
+                    &member.role,
+                );
+
+                match removable {
+                    Some(id) => b.text(" ").link(
+                        &uri!(remove_member_route(id.id(), member.character.id())).to_string(),
+                        "Remove",
+                    ),
+                    None => b,
+                }
+            },
+        )
+    }
+
+    fn add_buildings(
+        self,
+        data: &RpgData,
+        organization: &Organization,
+        removable: Option<OrganizationId>,
+    ) -> Self {
+        self.field_usize("Count:", organization.buildings.len())
+            .list(&organization.buildings, |b, &building| {
+                let b = b.add_element_link(data, ElementRef::Building(building));
+
+                match removable {
+                    Some(id) => b.text(" ").link(
+                        &uri!(remove_building_route(id.id(), building.id())).to_string(),
+                        "Remove",
+                    ),
+                    None => b,
+                }
+            })
+    }
+
+    fn add_relations(
+        self,
+        data: &RpgData,
+        organization: &Organization,
+        removable: Option<OrganizationId>,
+    ) -> Self {
+        let relations: Vec<_> = organization.relations.iter().collect();
+
+        self.field_usize("Count:", relations.len())
+            .list(&relations, |b, &(&other, relation)| {
+                let b = b
+                    .text(&format!("{}:", relation))
+                    .add_element_link(data, ElementRef::Organization(other));
+
+                match removable {
+                    Some(id) => b.text(" ").link(
+                        &uri!(remove_relation_route(id.id(), other.id())).to_string(),
+                        "Remove",
+                    ),
+                    None => b,
+                }
+            })
+    }
+}
@@ -1,111 +1,37 @@
-use crate::html::create_html;
-use crate::route::town::link_town_details;
-use crate::route::util::{get_all_html, get_elements};
-use crate::EditorData;
-use rocket::form::Form;
-use rocket::response::content::RawHtml;
-use rocket::State;
-use rpg_tools_core::model::name::WithName;
+use crate::html::EditorBuilder;
+use crate::route::crud::{CrudElement, VisibilitySupport};
+use crate::route::town::add_towns;
+use rpg_tools_core::model::element::ElementRef;
 use rpg_tools_core::model::world::street::{Street, StreetId};
-use rpg_tools_core::model::world::town::towns::WithTowns;
 use rpg_tools_core::model::RpgData;
-use rpg_tools_core::usecase::edit::name::update_name;
-use rpg_tools_core::utils::storage::{Element, Id};
+use rpg_tools_core::utils::storage::{Element, Storage};
+use rpg_tools_html::HtmlBuilder;
 
-#[get("/street/all")]
-pub fn get_all_streets(state: &State<EditorData>) -> RawHtml<String> {
-    let data = state.data.lock().expect("lock shared data");
-    get_all_html(&data.street_manager, "Streets")
-}
-
-pub fn link_all_streets() -> String {
-    uri!(get_all_streets()).to_string()
-}
-
-#[get("/street/new")]
-pub fn add_street(data: &State<EditorData>) -> Option<RawHtml<String>> {
-    let mut data = data.data.lock().expect("lock shared data");
-
-    let id = data.street_manager.create(Street::new);
-
-    println!("Create street {}", id.id());
-
-    get_edit_html(&data, id, "")
-}
-
-#[get("/street/<id>/details")]
-pub fn get_street_details(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
-    let data = state.data.lock().expect("lock shared data");
-    get_details_html(&data, StreetId::new(id))
-}
-
-pub fn link_street_details(id: StreetId) -> String {
-    uri!(get_street_details(id = id.id())).to_string()
-}
-
-#[get("/street/<id>/edit")]
-pub fn edit_street(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
-    let data = state.data.lock().expect("lock shared data");
-    get_edit_html(&data, StreetId::new(id), "")
-}
+impl CrudElement<StreetId> for Street {
+    const TITLE: &'static str = "Street";
+    const PLURAL: &'static str = "Streets";
 
-#[derive(FromForm, Debug)]
-pub struct StreetUpdate<'r> {
-    name: &'r str,
-}
-
-#[post("/street/<id>/update", data = "<update>")]
-pub fn update_street(
-    state: &State<EditorData>,
-    id: usize,
-    update: Form<StreetUpdate<'_>>,
-) -> Option<RawHtml<String>> {
-    println!("Update street {} with {:?}", id, update);
-    let mut data = state.data.lock().expect("lock shared data");
-
-    let street_id = StreetId::new(id);
-
-    if let Err(e) = update_name(&mut data.street_manager, street_id, update.name) {
-        return get_edit_html(&data, street_id, &e.to_string());
+    fn create(id: StreetId) -> Self {
+        Street::new(id)
     }
 
-    get_details_html(&data, street_id)
-}
-
-fn get_details_html(data: &RpgData, id: StreetId) -> Option<RawHtml<String>> {
-    let edit_uri = uri!(edit_street(id = id.id())).to_string();
-
-    data.street_manager.get(id).map(|street| {
-        let towns = get_elements(&data.town_manager, street.towns());
-
-        let builder = create_html()
-            .h1(&format!("Street: {}", street.name()))
-            .h2("Data")
-            .field_usize("Id:", id.id())
-            .field_usize("Towns:", towns.len())
-            .list(&towns, |b, &town| {
-                b.link(&link_town_details(town.id()), town.name().str())
-            })
-            .p(|b| b.link(&edit_uri, "Edit"))
-            .p(|b| b.link(&link_all_streets(), "Back"));
+    fn to_ref(id: StreetId) -> ElementRef {
+        ElementRef::Street(id)
+    }
 
-        RawHtml(builder.finish())
-    })
-}
+    fn get_storage(data: &RpgData) -> &Storage<StreetId, Self> {
+        &data.street_manager
+    }
 
-fn get_edit_html(data: &RpgData, id: StreetId, name_error: &str) -> Option<RawHtml<String>> {
-    let submit_uri = uri!(update_street(id.id())).to_string();
+    fn get_storage_mut(data: &mut RpgData) -> &mut Storage<StreetId, Self> {
+        &mut data.street_manager
+    }
 
-    data.street_manager.get(id).map(|street| {
-        let builder = create_html()
-            .h1(&format!("Edit Street: {}", street.name()))
-            .field_usize("Id:", id.id())
-            .form(&submit_uri, |b| {
-                b.text_input("Name", "name", street.name().str())
-                    .error(name_error)
-            })
-            .p(|b| b.link(&link_street_details(id), "Back"));
+    fn add_details(&self, data: &RpgData, builder: HtmlBuilder) -> HtmlBuilder {
+        add_towns(data, builder, self).add_history(data, ElementRef::Street(self.id()))
+    }
 
-        RawHtml(builder.finish())
-    })
+    fn visibility_support() -> Option<VisibilitySupport<Self>> {
+        Some(VisibilitySupport::new())
+    }
 }
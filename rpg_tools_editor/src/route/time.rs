@@ -0,0 +1,101 @@
+use crate::html::create_html;
+use crate::route::link_home;
+use crate::EditorData;
+use rocket::form::Form;
+use rocket::response::content::RawHtml;
+use rocket::State;
+use rpg_tools_core::model::time::calendar::Calendar;
+use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::edit::time::update_now;
+use rpg_tools_html::HtmlBuilder;
+
+#[get("/time")]
+pub fn get_time(state: &State<EditorData>) -> RawHtml<String> {
+    let data = state.data.lock().expect("lock shared data");
+    get_details_html(&data)
+}
+
+pub fn link_time() -> String {
+    uri!(get_time()).to_string()
+}
+
+#[get("/time/edit")]
+pub fn edit_time(state: &State<EditorData>) -> RawHtml<String> {
+    let data = state.data.lock().expect("lock shared data");
+    get_edit_html(&data, "")
+}
+
+#[derive(FromForm, Debug)]
+pub struct TimeUpdate<'r> {
+    now: &'r str,
+}
+
+#[post("/time/update", data = "<update>")]
+pub fn update_time(state: &State<EditorData>, update: Form<TimeUpdate<'_>>) -> RawHtml<String> {
+    println!("Update time with {:?}", update);
+    let mut data = state.data.lock().expect("lock shared data");
+
+    let result = data
+        .time
+        .calendar
+        .parse_optional_date(update.now)
+        .and_then(|now| update_now(&mut data, now));
+
+    match result {
+        Ok(()) => get_details_html(&data),
+        Err(e) => get_edit_html(&data, &e.to_string()),
+    }
+}
+
+fn get_details_html(data: &RpgData) -> RawHtml<String> {
+    let edit_uri = uri!(edit_time()).to_string();
+    let calendar = &data.time.calendar;
+
+    let builder = create_html()
+        .h1("Time")
+        .option(data.time.now, |now, b| {
+            let text = match calendar.get_weekday(&now) {
+                Some(weekday) => format!("{}, {}", weekday, calendar.format(&now)),
+                None => calendar.format(&now),
+            };
+            b.field("Now:", &text)
+        })
+        .h2("Calendar");
+
+    RawHtml(
+        add_calendar(builder, calendar)
+            .p(|b| b.link(&edit_uri, "Edit"))
+            .p(|b| b.link(&link_home(), "Back"))
+            .finish(),
+    )
+}
+
+fn add_calendar(builder: HtmlBuilder, calendar: &Calendar) -> HtmlBuilder {
+    match calendar {
+        Calendar::Gregorian => builder.field("Type:", "Gregorian"),
+        Calendar::Custom { months, weekdays } => builder
+            .field("Type:", "Custom")
+            .h3("Months")
+            .list(months, |b, month| {
+                b.text(&format!("{} ({} days)", month.name, month.days))
+            })
+            .h3("Weekdays")
+            .list(weekdays, |b, weekday| b.text(weekday)),
+    }
+}
+
+fn get_edit_html(data: &RpgData, error: &str) -> RawHtml<String> {
+    let submit_uri = uri!(update_time()).to_string();
+    let now = data.time.now.map(|now| now.to_string()).unwrap_or_default();
+
+    RawHtml(
+        create_html()
+            .h1("Edit Time")
+            .form(&submit_uri, |b| {
+                b.text_input("Now (year-month-day):", "now", &now)
+                    .error(error)
+            })
+            .p(|b| b.link(&link_time(), "Back"))
+            .finish(),
+    )
+}
@@ -0,0 +1,93 @@
+use crate::html::create_html;
+use crate::route::building::link_building_details;
+use crate::route::town::{link_town_details, link_town_map};
+use crate::EditorData;
+use rocket::form::Form;
+use rocket::response::content::RawHtml;
+use rocket::State;
+use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::world::town::TownId;
+use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::edit::address::{number_houses, HouseNumbering};
+use rpg_tools_core::usecase::get::address::address;
+use rpg_tools_core::utils::storage::{Element, Id};
+
+const FORWARD: &str = "Forward";
+const BACKWARD: &str = "Backward";
+const ALTERNATING: &str = "Alternating";
+const CONSECUTIVE: &str = "Consecutive";
+
+#[get("/town/<id>/address/editor")]
+pub fn get_address_editor(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let data = state.data.lock().expect("lock shared data");
+    get_address_editor_html(&data, TownId::new(id), "")
+}
+
+pub fn link_address_editor(id: TownId) -> String {
+    uri!(get_address_editor(id.id())).to_string()
+}
+
+#[derive(FromForm, Debug)]
+pub struct NumberingUpdate<'r> {
+    start: u32,
+    direction: &'r str,
+    sides: &'r str,
+}
+
+#[post("/town/<id>/address/number", data = "<update>")]
+pub fn number_houses_route(
+    state: &State<EditorData>,
+    id: usize,
+    update: Form<NumberingUpdate<'_>>,
+) -> Option<RawHtml<String>> {
+    println!("Number houses of town {} with {:?}", id, update);
+    let mut data = state.data.lock().expect("lock shared data");
+    let town_id = TownId::new(id);
+    let numbering = HouseNumbering {
+        start: update.start,
+        reverse: update.direction == BACKWARD,
+        alternate: update.sides == ALTERNATING,
+    };
+
+    let error = number_houses(&mut data, town_id, &numbering)
+        .err()
+        .map(|e| e.to_string())
+        .unwrap_or_default();
+
+    get_address_editor_html(&data, town_id, &error)
+}
+
+fn get_address_editor_html(data: &RpgData, id: TownId, error: &str) -> Option<RawHtml<String>> {
+    let number_uri = uri!(number_houses_route(id.id())).to_string();
+    let mut addresses: Vec<_> = data
+        .building_manager
+        .get_all()
+        .iter()
+        .filter(|building| building.lot.town == id)
+        .filter_map(|building| address(data, building.id()).map(|address| (building, address)))
+        .collect();
+    addresses.sort_by_key(|(building, _)| {
+        building
+            .address
+            .map(|address| (address.street.id(), address.number))
+    });
+
+    data.town_manager.get(id).map(|town| {
+        let builder = create_html()
+            .h1(&format!("Addresses of Town: {}", town.name()))
+            .form(&number_uri, |b| {
+                b.number_input("Start", "start", 1, 1, 1000)
+                    .select("Direction", "direction", &[FORWARD, BACKWARD], FORWARD)
+                    .select("Sides", "sides", &[ALTERNATING, CONSECUTIVE], ALTERNATING)
+                    .error(error)
+            })
+            .list(&addresses, |b, (building, address)| {
+                b.text(&format!("{}: ", address))
+                    .link(&link_building_details(building.id()), building.name().str())
+            })
+            .center(|b| b.svg(&link_town_map(id), "800"))
+            .p(|b| b.link(&link_town_details(id), "Back"));
+
+        RawHtml(builder.finish())
+    })
+}
@@ -1,46 +1,79 @@
 use crate::html::create_html;
 use crate::route::town::link_town_details;
 use crate::svg::RawSvg;
-use crate::EditorData;
+use crate::{EditorData, ToolData};
+use rocket::form::Form;
 use rocket::response::content::RawHtml;
 use rocket::State;
+use rpg_tools_core::model::color::Color;
+use rpg_tools_core::model::math::aabb2d::AABB;
 use rpg_tools_core::model::math::point2d::Point2d;
+use rpg_tools_core::model::math::size2d::Size2d;
 use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::visibility::Audience;
 use rpg_tools_core::model::world::building::lot::BuildingLot;
-use rpg_tools_core::model::world::town::construction::Construction;
 use rpg_tools_core::model::world::town::tile::TownTile;
 use rpg_tools_core::model::world::town::{Town, TownId};
 use rpg_tools_core::model::RpgData;
 use rpg_tools_core::usecase::create::building::create_building;
+use rpg_tools_core::usecase::get::town::is_lot_free;
 use rpg_tools_core::utils::storage::{Element, Id};
+use rpg_tools_rendering::renderer::style::RenderStyle;
 use rpg_tools_rendering::renderer::svg::builder::SvgBuilder;
+use rpg_tools_rendering::renderer::{LinkRenderer, Renderer};
 use rpg_tools_rendering::usecase::map::town::render_constructs;
 use rpg_tools_rendering::usecase::map::TileMapRenderer;
 
 #[get("/town/<id>/building/creator")]
 pub fn get_building_creator(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
     let data = state.data.lock().expect("lock shared data");
-    get_building_creator_html(&data, TownId::new(id))
+    let tools = state.tools.lock().expect("lock shared data");
+    get_building_creator_html(&data, &tools, TownId::new(id))
 }
 
 pub fn link_building_creator(id: TownId) -> String {
     uri!(get_building_creator(id.id())).to_string()
 }
 
+#[derive(FromForm, Debug)]
+pub struct BuildingCreatorUpdate {
+    width: u32,
+    height: u32,
+}
+
+#[post("/town/<id>/building/creator/update", data = "<update>")]
+pub fn update_building_creator(
+    state: &State<EditorData>,
+    id: usize,
+    update: Form<BuildingCreatorUpdate>,
+) -> Option<RawHtml<String>> {
+    println!("Update building creator of town {} with {:?}", id, update);
+    let data = state.data.lock().expect("lock shared data");
+    let mut tools = state.tools.lock().expect("lock shared data");
+
+    tools.building_size = Size2d::new(update.width.max(1), update.height.max(1));
+
+    get_building_creator_html(&data, &tools, TownId::new(id))
+}
+
 #[get("/town/<id>/building/creator.svg")]
 pub fn get_building_creator_map(state: &State<EditorData>, id: usize) -> Option<RawSvg> {
     let data = state.data.lock().expect("lock shared data");
-    data.town_manager
-        .get(TownId::new(id))
-        .map(|town| render_building_creator_map(&data, &state.town_renderer, town))
+    let tools = state.tools.lock().expect("lock shared data");
+
+    data.town_manager.get(TownId::new(id)).map(|town| {
+        render_building_creator_map(&data, &state.town_renderer, town, tools.building_size)
+    })
 }
 
 #[get("/town/<id>/building/add/<tile>")]
 pub fn add_building(state: &State<EditorData>, id: usize, tile: usize) -> Option<RawHtml<String>> {
     let mut data = state.data.lock().expect("lock shared data");
+    let tools = state.tools.lock().expect("lock shared data");
     let town_id = TownId::new(id);
+    let lot = BuildingLot::big(town_id, tile, tools.building_size);
 
-    if let Ok(building_id) = create_building(&mut data, BuildingLot::new(town_id, tile)) {
+    if let Ok(building_id) = create_building(&mut data, lot) {
         println!(
             "Added building {} to tile {} of town {}",
             building_id.id(),
@@ -51,20 +84,31 @@ pub fn add_building(state: &State<EditorData>, id: usize, tile: usize) -> Option
         println!("Failed to add a building to tile {} of town {}", tile, id);
     }
 
-    get_building_creator_html(&data, town_id)
+    get_building_creator_html(&data, &tools, town_id)
 }
 
 pub fn link_add_building(id: TownId, tile: usize) -> String {
     uri!(add_building(id.id(), tile)).to_string()
 }
 
-fn get_building_creator_html(data: &RpgData, id: TownId) -> Option<RawHtml<String>> {
+fn get_building_creator_html(
+    data: &RpgData,
+    tools: &ToolData,
+    id: TownId,
+) -> Option<RawHtml<String>> {
     let map_uri = uri!(get_building_creator_map(id.id())).to_string();
+    let update_uri = uri!(update_building_creator(id.id())).to_string();
     let back_uri = link_town_details(id);
+    let size = tools.building_size;
 
     data.town_manager.get(id).map(|town| {
         let builder = create_html()
             .h1(&format!("Add a Building to Town {}", town.name()))
+            .form_with_change(&update_uri, &update_uri, |b| {
+                b.number_input("Width", "width", size.width() as usize, 1, 100)
+                    .number_input("Height", "height", size.height() as usize, 1, 100)
+            })
+            .p(|b| b.text("Hover over a tile to preview a lot starting there. Click, if the preview is green."))
             .center(|b| b.svg(&map_uri, "800"))
             .p(|b| b.link(&back_uri, "Back"));
 
@@ -72,25 +116,41 @@ fn get_building_creator_html(data: &RpgData, id: TownId) -> Option<RawHtml<Strin
     })
 }
 
-fn render_building_creator_map(data: &RpgData, renderer: &TileMapRenderer, town: &Town) -> RawSvg {
+/// Renders the town with links for all valid top left tiles of a lot of the given size
+/// & a preview of the lot, which the editor's script moves to the hovered tile.
+fn render_building_creator_map(
+    data: &RpgData,
+    renderer: &TileMapRenderer,
+    town: &Town,
+    lot_size: Size2d,
+) -> RawSvg {
     let size = renderer.calculate_map_size(&town.map);
     let mut builder = SvgBuilder::new(size);
 
+    builder.group("tiles");
     renderer.render_links(
         &mut builder,
         &Point2d::default(),
         &town.map,
         TownTile::get_color,
-        |index, tile| {
-            if tile.construction == Construction::None {
+        |index, _tile| {
+            if is_lot_free(data, &BuildingLot::big(town.id(), index, lot_size)) {
                 Some(link_add_building(town.id(), index))
             } else {
                 None
             }
         },
     );
+    builder.close();
 
-    render_constructs(data, &mut builder, renderer, town);
+    render_constructs(data, &mut builder, renderer, town, Audience::GameMaster);
+
+    builder.group("preview");
+    builder.render_rectangle(
+        &AABB::new(Point2d::default(), renderer.calculate_size(lot_size)),
+        &RenderStyle::only_border(Color::Black, renderer.tile_size / 10),
+    );
+    builder.close();
 
     let svg = builder.finish();
     RawSvg::new(svg.export())
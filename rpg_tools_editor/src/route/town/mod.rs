@@ -1,34 +1,38 @@
+pub mod address;
 pub mod building;
 pub mod street;
 pub mod terrain;
 
-use crate::html::create_html;
+use crate::html::{create_html, EditorBuilder};
 use crate::route::building::link_building_details;
+use crate::route::crud::link_details;
+use crate::route::town::address::link_address_editor;
 use crate::route::town::building::link_building_creator;
 use crate::route::town::street::link_street_editor;
 use crate::route::town::terrain::link_terrain_editor;
-use crate::route::util::get_all_html;
+use crate::route::util::{get_all_html, get_elements};
 use crate::svg::RawSvg;
 use crate::EditorData;
 use rocket::form::Form;
 use rocket::response::content::RawHtml;
 use rocket::State;
-use rpg_tools_core::model::math::point2d::Point2d;
+use rpg_tools_core::model::description::WithDescription;
+use rpg_tools_core::model::element::ElementRef;
 use rpg_tools_core::model::name::WithName;
-use rpg_tools_core::model::world::building::BuildingId;
-use rpg_tools_core::model::world::town::terrain::Terrain;
-use rpg_tools_core::model::world::town::tile::TownTile;
+use rpg_tools_core::model::organization::{Organization, OrganizationId};
+use rpg_tools_core::model::visibility::{Audience, Visibility, WithVisibility};
+use rpg_tools_core::model::world::town::towns::WithTowns;
 use rpg_tools_core::model::world::town::{Town, TownId};
 use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::edit::description::update_description;
 use rpg_tools_core::usecase::edit::name::update_name;
 use rpg_tools_core::usecase::edit::resize::resize_town;
+use rpg_tools_core::usecase::edit::visibility::update_visibility;
+use rpg_tools_core::usecase::get::entrance::get_buildings_without_street_access;
+use rpg_tools_core::usecase::get::name::get_element_name;
 use rpg_tools_core::utils::storage::{Element, Id};
-use rpg_tools_rendering::renderer::svg::builder::SvgBuilder;
-use rpg_tools_rendering::renderer::{LinkRenderer, Tooltip};
-use rpg_tools_rendering::usecase::map::town::{
-    render_building, render_street, render_streets_complex,
-};
-use rpg_tools_rendering::usecase::map::TileMapRenderer;
+use rpg_tools_html::HtmlBuilder;
+use rpg_tools_rendering::usecase::map::town::{render_town, TownMapOptions};
 
 #[get("/town/all")]
 pub fn get_all_towns(state: &State<EditorData>) -> RawHtml<String> {
@@ -51,14 +55,39 @@ pub fn add_town(data: &State<EditorData>) -> Option<RawHtml<String>> {
     get_edit_html(&data, id, "")
 }
 
-#[get("/town/<id>/details")]
-pub fn get_town_details(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+#[get("/town/<id>/details?<occupancy>&<organization>")]
+pub fn get_town_details(
+    state: &State<EditorData>,
+    id: usize,
+    occupancy: Option<bool>,
+    organization: Option<usize>,
+) -> Option<RawHtml<String>> {
     let data = state.data.lock().expect("lock shared data");
-    get_details_html(&data, TownId::new(id))
+    get_details_html(
+        &data,
+        TownId::new(id),
+        occupancy.unwrap_or(false),
+        organization.map(OrganizationId::new),
+    )
 }
 
 pub fn link_town_details(id: TownId) -> String {
-    uri!(get_town_details(id = id.id())).to_string()
+    uri!(get_town_details(
+        id = id.id(),
+        occupancy = _,
+        organization = _
+    ))
+    .to_string()
+}
+
+/// Links to the details of a town, whose map highlights the buildings of an organization.
+pub fn link_town_organization(id: TownId, organization: OrganizationId) -> String {
+    uri!(get_town_details(
+        id = id.id(),
+        occupancy = _,
+        organization = Some(organization.id())
+    ))
+    .to_string()
 }
 
 #[get("/town/<id>/edit")]
@@ -70,8 +99,10 @@ pub fn edit_town(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>
 #[derive(FromForm, Debug)]
 pub struct TownUpdate<'r> {
     name: &'r str,
+    visibility: &'r str,
     width: u32,
     height: u32,
+    description: &'r str,
 }
 
 #[post("/town/<id>/update", data = "<update>")]
@@ -91,93 +122,168 @@ pub fn update_town(
     if let Err(e) = resize_town(&mut data, town_id, update.width, update.height) {
         return get_edit_html(&data, town_id, &e.to_string());
     }
+    if let Err(e) = Visibility::parse(update.visibility)
+        .and_then(|visibility| update_visibility(&mut data.town_manager, town_id, visibility))
+    {
+        return get_edit_html(&data, town_id, &e.to_string());
+    }
+
+    if let Err(e) = update_description(&mut data.town_manager, town_id, update.description) {
+        return get_edit_html(&data, town_id, &e.to_string());
+    }
+
+    get_details_html(&data, town_id, false, None)
+}
+
+#[get("/town/<id>/map.svg?<occupancy>&<organization>")]
+pub fn get_town_map(
+    state: &State<EditorData>,
+    id: usize,
+    occupancy: Option<bool>,
+    organization: Option<usize>,
+) -> Option<RawSvg> {
+    let data = state.data.lock().expect("lock shared data");
+    let options = TownMapOptions {
+        show_occupancy: occupancy.unwrap_or(false),
+        highlighted: organization
+            .and_then(|id| data.organizations.get(OrganizationId::new(id)))
+            .map(|organization| organization.get_all_buildings())
+            .unwrap_or_default(),
+    };
 
-    get_details_html(&data, town_id)
+    data.town_manager.get(TownId::new(id)).map(|town| {
+        let svg = render_town(
+            &data,
+            &state.town_renderer,
+            town,
+            Audience::GameMaster,
+            &options,
+            |id| Some(link_building_details(id)),
+        );
+        RawSvg::new(svg.export())
+    })
 }
 
-#[get("/town/<id>/map.svg")]
-pub fn get_town_map(state: &State<EditorData>, id: usize) -> Option<RawSvg> {
+pub fn link_town_map(id: TownId) -> String {
+    uri!(get_town_map(id.id(), None::<bool>, None::<usize>)).to_string()
+}
+
+#[get("/town/<id>/player")]
+pub fn get_town_player_view(state: &State<EditorData>, id: usize) -> Option<RawHtml<String>> {
+    let data = state.data.lock().expect("lock shared data");
+    let town_id = TownId::new(id);
+    let map_uri = uri!(get_town_player_map(id)).to_string();
+
+    data.town_manager
+        .get(town_id)
+        .filter(|town| town.is_visible_to(Audience::Players))
+        .map(|town| {
+            let builder = create_html()
+                .h1(&format!("Town: {}", town.name()))
+                .center(|b| b.svg(&map_uri, "800"))
+                .p(|b| b.link(&link_town_details(town_id), "Back"));
+            RawHtml(builder.finish())
+        })
+}
+
+#[get("/town/<id>/player/map.svg")]
+pub fn get_town_player_map(state: &State<EditorData>, id: usize) -> Option<RawSvg> {
     let data = state.data.lock().expect("lock shared data");
     data.town_manager
         .get(TownId::new(id))
-        .map(|town| render_town(&data, &state.town_renderer, town, link_building_details))
+        .filter(|town| town.is_visible_to(Audience::Players))
+        .map(|town| {
+            let svg = render_town(
+                &data,
+                &state.town_renderer,
+                town,
+                Audience::Players,
+                &TownMapOptions::default(),
+                |_| None,
+            );
+            RawSvg::new(svg.export())
+        })
 }
 
-fn get_details_html(data: &RpgData, id: TownId) -> Option<RawHtml<String>> {
+fn get_details_html(
+    data: &RpgData,
+    id: TownId,
+    occupancy: bool,
+    organization: Option<OrganizationId>,
+) -> Option<RawHtml<String>> {
     let buildings = data
         .building_manager
         .get_all()
         .iter()
         .filter(|&building| building.lot.town.eq(&id))
         .count();
-    let map_uri = uri!(get_town_map(id.id())).to_string();
+    let organization_id = organization.map(|id| id.id());
+    let map_uri = uri!(get_town_map(id.id(), Some(occupancy), organization_id)).to_string();
+    let occupancy_uri =
+        uri!(get_town_details(id.id(), Some(!occupancy), organization_id)).to_string();
+    let highlighted = organization.and_then(|id| data.organizations.get(id));
+    let occupancy_text = if occupancy {
+        "Hide Occupancy"
+    } else {
+        "Show Occupancy"
+    };
     let edit_uri = uri!(edit_town(id = id.id())).to_string();
+    let player_uri = uri!(get_town_player_view(id = id.id())).to_string();
 
     data.town_manager.get(id).map(|town| {
         let builder = create_html()
             .h1(&format!("Town: {}", town.name()))
             .h2("Data")
             .field_usize("Id:", id.id())
+            .field("Visibility:", &town.visibility().to_string())
             .field_usize("Buildings:", buildings)
+            .add_description(data, town.description())
+            .add_history(data, ElementRef::Town(id))
+            .add_backlinks(data, ElementRef::Town(id))
             .p(|b| b.link(&edit_uri, "Edit"))
             .p(|b| b.link(&link_terrain_editor(id), "Edit Terrain"))
             .p(|b| b.link(&link_building_creator(id), "Add Buildings"))
             .p(|b| b.link(&link_street_editor(id), "Edit Streets"))
+            .p(|b| b.link(&link_address_editor(id), "Number Houses"))
+            .p(|b| b.link(&player_uri, "Player View"))
             .p(|b| b.link(&link_all_towns(), "Back"))
             .h2("Map")
-            .center(|b| b.svg(&map_uri, "800"));
+            .p(|b| b.link(&occupancy_uri, occupancy_text))
+            .option(highlighted, |organization, b| {
+                b.complex_field("Highlighted:", |b| {
+                    b.link(
+                        &link_details::<_, Organization>(organization.id()),
+                        organization.name().str(),
+                    )
+                    .text(" ")
+                    .link(&link_town_details(id), "Clear")
+                })
+            })
+            .center(|b| b.svg(&map_uri, "800"))
+            .h3("Building Types")
+            .list(&data.building_types.types, |b, building_type| {
+                b.text(&format!(
+                    "{}: {}{}",
+                    building_type.name,
+                    building_type.color,
+                    building_type
+                        .icon
+                        .as_ref()
+                        .map(|icon| format!(" ({})", icon))
+                        .unwrap_or_default()
+                ))
+            })
+            .h3("Without Street Access")
+            .list(&get_buildings_without_street_access(data, id), |b, &id| {
+                b.link(
+                    &link_building_details(id),
+                    &get_element_name(data, ElementRef::Building(id)).unwrap_or_default(),
+                )
+            });
         RawHtml(builder.finish())
     })
 }
 
-fn render_town<F: FnMut(BuildingId) -> String>(
-    data: &RpgData,
-    renderer: &TileMapRenderer,
-    town: &Town,
-    mut get_link: F,
-) -> RawSvg {
-    let size = renderer.calculate_map_size(&town.map);
-    let mut builder = SvgBuilder::new(size);
-
-    renderer.render_tooltips(
-        &mut builder,
-        &Point2d::default(),
-        &town.map,
-        TownTile::get_color,
-        |tile| match tile.terrain {
-            Terrain::Hill { id } | Terrain::Mountain { id } => {
-                data.mountain_manager.get(id).map(|m| m.name().to_string())
-            }
-            Terrain::Plain => None,
-            Terrain::River { id } => data.river_manager.get(id).map(|r| r.name().to_string()),
-        },
-    );
-
-    data.building_manager
-        .get_all()
-        .iter()
-        .filter(|&building| building.lot.town.eq(&town.id()))
-        .for_each(|building| {
-            builder.tooltip(building.name().str());
-            builder.link(&get_link(building.id()));
-            render_building(&mut builder, renderer, town, building);
-            builder.close();
-            builder.clear_tooltip();
-        });
-
-    render_streets_complex(renderer, town, |aabb, id, _index| {
-        if let Some(street) = data.street_manager.get(id) {
-            builder.tooltip(street.name().str())
-        }
-
-        render_street(&mut builder, &aabb);
-        builder.clear_tooltip();
-    });
-
-    let svg = builder.finish();
-    RawSvg::new(svg.export())
-}
-
 fn get_edit_html(data: &RpgData, id: TownId, name_error: &str) -> Option<RawHtml<String>> {
     let submit_uri = uri!(update_town(id.id())).to_string();
 
@@ -202,9 +308,31 @@ fn get_edit_html(data: &RpgData, id: TownId, name_error: &str) -> Option<RawHtml
                         1,
                         100,
                     )
+                    .select(
+                        "Visibility",
+                        "visibility",
+                        &Visibility::get_all(),
+                        &town.visibility().to_string(),
+                    )
+                    .text_area(
+                        "Description",
+                        "description",
+                        town.description().unwrap_or(""),
+                    )
             })
             .p(|b| b.link(&link_town_details(id), "Back"));
 
         RawHtml(builder.finish())
     })
 }
+
+/// Adds the towns of an element to its details page.
+pub fn add_towns<T: WithTowns>(data: &RpgData, builder: HtmlBuilder, element: &T) -> HtmlBuilder {
+    let towns = get_elements(&data.town_manager, element.towns());
+
+    builder
+        .field_usize("Towns:", towns.len())
+        .list(&towns, |b, &town| {
+            b.link(&link_town_details(town.id()), town.name().str())
+        })
+}
@@ -2,13 +2,14 @@ use crate::html::create_html;
 use crate::route::town::link_town_details;
 use crate::route::util::get_all_elements;
 use crate::svg::RawSvg;
-use crate::EditorData;
+use crate::{EditorData, ToolData};
 use rocket::form::Form;
 use rocket::response::content::RawHtml;
 use rocket::State;
 use rpg_tools_core::model::color::Color;
 use rpg_tools_core::model::math::point2d::Point2d;
 use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::visibility::{Audience, Visibility};
 use rpg_tools_core::model::world::street::StreetId;
 use rpg_tools_core::model::world::town::construction::Construction;
 use rpg_tools_core::model::world::town::tile::TownTile;
@@ -16,6 +17,7 @@ use rpg_tools_core::model::world::town::{Town, TownId};
 use rpg_tools_core::model::RpgData;
 use rpg_tools_core::usecase::edit::town::add_street::add_street_to_tile;
 use rpg_tools_core::usecase::edit::town::remove_street::remove_street_from_tile;
+use rpg_tools_core::usecase::edit::visibility::update_construction_visibility;
 use rpg_tools_core::utils::storage::{Element, Id};
 use rpg_tools_rendering::renderer::svg::builder::SvgBuilder;
 use rpg_tools_rendering::renderer::LinkRenderer;
@@ -29,7 +31,7 @@ pub fn get_street_editor(state: &State<EditorData>, id: usize) -> Option<RawHtml
     let data = state.data.lock().expect("lock shared data");
     let tools = state.tools.lock().expect("lock shared data");
 
-    get_street_creator_html(&data, TownId::new(id), tools.selected_street)
+    get_street_creator_html(&data, &tools, TownId::new(id))
 }
 
 pub fn link_street_editor(id: TownId) -> String {
@@ -37,23 +39,25 @@ pub fn link_street_editor(id: TownId) -> String {
 }
 
 #[derive(FromForm, Debug)]
-pub struct StreetEditorUpdate {
+pub struct StreetEditorUpdate<'r> {
     street: usize,
+    visibility: &'r str,
 }
 
 #[post("/town/<id>/street/update", data = "<update>")]
 pub fn update_street_editor(
     state: &State<EditorData>,
     id: usize,
-    update: Form<StreetEditorUpdate>,
+    update: Form<StreetEditorUpdate<'_>>,
 ) -> Option<RawHtml<String>> {
     println!("Update street editor {} with {:?}", id, update);
     let data = state.data.lock().expect("lock shared data");
     let mut tools = state.tools.lock().expect("lock shared data");
 
     tools.selected_street = StreetId::new(update.street);
+    tools.street_visibility = Visibility::parse(update.visibility).unwrap_or_default();
 
-    get_street_creator_html(&data, TownId::new(id), tools.selected_street)
+    get_street_creator_html(&data, &tools, TownId::new(id))
 }
 
 #[get("/town/<id>/street/editor.svg")]
@@ -76,7 +80,12 @@ pub fn add_street_to_town(
     let tools = state.tools.lock().expect("lock shared data");
     let town_id = TownId::new(id);
 
-    if add_street_to_tile(&mut data, town_id, tile, tools.selected_street).is_ok() {
+    if add_street_to_tile(&mut data, town_id, tile, tools.selected_street)
+        .and_then(|_| {
+            update_construction_visibility(&mut data, town_id, tile, tools.street_visibility)
+        })
+        .is_ok()
+    {
         println!(
             "Added street {} to tile {} of town {}",
             tools.selected_street.id(),
@@ -87,7 +96,7 @@ pub fn add_street_to_town(
         println!("Failed to add a street to tile {} of town {}", tile, id);
     }
 
-    get_street_creator_html(&data, town_id, tools.selected_street)
+    get_street_creator_html(&data, &tools, town_id)
 }
 
 pub fn link_add_street_to_town(id: TownId, tile: usize) -> String {
@@ -115,7 +124,7 @@ pub fn remove_street_from_town(
         println!("Failed to remove a street on tile {} of town {}", tile, id);
     }
 
-    get_street_creator_html(&data, town_id, tools.selected_street)
+    get_street_creator_html(&data, &tools, town_id)
 }
 
 pub fn link_remove_street_from_town(id: TownId, tile: usize) -> String {
@@ -124,8 +133,8 @@ pub fn link_remove_street_from_town(id: TownId, tile: usize) -> String {
 
 fn get_street_creator_html(
     data: &RpgData,
+    tools: &ToolData,
     id: TownId,
-    street_id: StreetId,
 ) -> Option<RawHtml<String>> {
     let map_uri = uri!(get_street_editor_map(id.id())).to_string();
     let back_uri = link_town_details(id);
@@ -136,7 +145,13 @@ fn get_street_creator_html(
         let builder = create_html()
             .h1(&format!("Add Streets to Town {}", town.name()))
             .form(&update_uri, |b| {
-                b.select_id("Street", "street", &streets, street_id.id())
+                b.select_id("Street", "street", &streets, tools.selected_street.id())
+                    .select(
+                        "Visibility",
+                        "visibility",
+                        &Visibility::get_all(),
+                        &tools.street_visibility.to_string(),
+                    )
             })
             .center(|b| b.svg(&map_uri, "800"))
             .p(|b| b.link(&back_uri, "Back"));
@@ -168,18 +183,24 @@ fn render_street_editor_map(
         },
     );
 
-    render_buildings(data, &mut builder, renderer, town);
-    render_streets_complex(renderer, town, |aabb, id, index| {
-        builder.link(&link_remove_street_from_town(town.id(), index));
-
-        if id.eq(&selected) {
-            render_street_color(&mut builder, &aabb, Color::Yellow);
-        } else {
-            render_street(&mut builder, &aabb);
-        }
+    render_buildings(data, &mut builder, renderer, town, Audience::GameMaster);
+    render_streets_complex(
+        data,
+        renderer,
+        town,
+        Audience::GameMaster,
+        |aabb, id, index| {
+            builder.link(&link_remove_street_from_town(town.id(), index));
+
+            if id.eq(&selected) {
+                render_street_color(&mut builder, &aabb, Color::Yellow);
+            } else {
+                render_street(&mut builder, &aabb);
+            }
 
-        builder.close();
-    });
+            builder.close();
+        },
+    );
 
     let svg = builder.finish();
     RawSvg::new(svg.export())
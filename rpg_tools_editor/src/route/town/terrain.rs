@@ -8,6 +8,7 @@ use rocket::response::content::RawHtml;
 use rocket::State;
 use rpg_tools_core::model::math::point2d::Point2d;
 use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::visibility::Audience;
 use rpg_tools_core::model::world::mountain::MountainId;
 use rpg_tools_core::model::world::river::RiverId;
 use rpg_tools_core::model::world::town::terrain::Terrain;
@@ -142,7 +143,7 @@ fn render_terrain_editor_map(data: &RpgData, renderer: &TileMapRenderer, town: &
         |index, _tile| Some(link_edit_terrain(town.id(), index)),
     );
 
-    render_constructs(data, &mut builder, renderer, town);
+    render_constructs(data, &mut builder, renderer, town, Audience::GameMaster);
 
     let svg = builder.finish();
     RawSvg::new(svg.export())
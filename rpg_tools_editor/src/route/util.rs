@@ -1,7 +1,18 @@
 use crate::html::create_html;
+use crate::route::building::link_building_details;
+use crate::route::character::culture::link_culture_details;
+use crate::route::character::link_character_details;
+use crate::route::crud::link_details;
+use crate::route::event::link_event_details;
 use crate::route::link_home;
+use crate::route::town::link_town_details;
 use rocket::response::content::RawHtml;
+use rpg_tools_core::model::element::ElementRef;
 use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::organization::Organization;
+use rpg_tools_core::model::world::mountain::Mountain;
+use rpg_tools_core::model::world::river::River;
+use rpg_tools_core::model::world::street::Street;
 use rpg_tools_core::utils::storage::{Element, Id, Storage};
 use std::collections::HashSet;
 
@@ -22,6 +33,20 @@ pub fn get_elements<'a, ID: Id, ELEMENT: Element<ID>>(
     ids.iter().flat_map(|id| storage.get(*id)).collect()
 }
 
+pub fn link_element_details(element: ElementRef) -> String {
+    match element {
+        ElementRef::Building(id) => link_building_details(id),
+        ElementRef::Character(id) => link_character_details(id),
+        ElementRef::Culture(id) => link_culture_details(id),
+        ElementRef::Event(id) => link_event_details(id),
+        ElementRef::Mountain(id) => link_details::<_, Mountain>(id),
+        ElementRef::Organization(id) => link_details::<_, Organization>(id),
+        ElementRef::River(id) => link_details::<_, River>(id),
+        ElementRef::Street(id) => link_details::<_, Street>(id),
+        ElementRef::Town(id) => link_town_details(id),
+    }
+}
+
 pub fn get_all_html<ID: Id, ELEMENT: Element<ID> + WithName>(
     storage: &Storage<ID, ELEMENT>,
     title: &str,
@@ -2,3 +2,92 @@ function updateEditor() {
   console.log("Update editor");
   document.getElementById("editor").submit();
 }
+
+// Drags the element with the id "dragged" of an embedded svg & follows the link of the tile,
+// where it is dropped. The tile, where it was grabbed, is added as query parameter.
+function enableDragging(object) {
+  const svg = object.contentDocument && object.contentDocument.documentElement;
+  const dragged = svg && svg.querySelector("#dragged");
+
+  if (!dragged) {
+    return;
+  }
+
+  const toSvg = (event) => {
+    const point = svg.createSVGPoint();
+    point.x = event.clientX;
+    point.y = event.clientY;
+    return point.matrixTransform(svg.getScreenCTM().inverse());
+  };
+  const getTileLink = (event) => {
+    dragged.style.display = "none";
+    const element = object.contentDocument.elementFromPoint(event.clientX, event.clientY);
+    dragged.style.display = "";
+    return element ? element.closest("a") : null;
+  };
+  let start = null;
+  let grab = null;
+
+  dragged.addEventListener("mousedown", (event) => {
+    event.preventDefault();
+    const link = getTileLink(event);
+    start = toSvg(event);
+    grab = link ? link.getAttribute("href").split("/").pop() : null;
+  });
+  svg.addEventListener("mousemove", (event) => {
+    if (start) {
+      const point = toSvg(event);
+      dragged.setAttribute("transform", `translate(${point.x - start.x} ${point.y - start.y})`);
+    }
+  });
+  svg.addEventListener("mouseup", (event) => {
+    if (!start) {
+      return;
+    }
+
+    start = null;
+    const link = getTileLink(event);
+
+    if (link && grab !== null) {
+      window.location = `${link.getAttribute("href")}?grab=${grab}`;
+    } else {
+      dragged.removeAttribute("transform");
+    }
+  });
+}
+
+
+// Moves the element with the id "preview" of an embedded svg to the hovered tile of the group "tiles".
+// The preview is green, if the tile is a link, & red otherwise.
+function enablePreview(object) {
+  const svg = object.contentDocument && object.contentDocument.documentElement;
+  const preview = svg && svg.querySelector("#preview");
+
+  if (!preview) {
+    return;
+  }
+
+  preview.style.pointerEvents = "none";
+  preview.style.display = "none";
+
+  svg.querySelectorAll("#tiles rect").forEach((tile) => {
+    tile.addEventListener("mouseenter", () => {
+      const isValid = tile.parentElement.tagName === "a";
+      preview.setAttribute("transform", `translate(${tile.getAttribute("x")} ${tile.getAttribute("y")})`);
+      preview.querySelectorAll("rect").forEach((rect) => {
+        rect.style.stroke = isValid ? "lime" : "red";
+      });
+      preview.style.display = "";
+    });
+  });
+  svg.addEventListener("mouseleave", () => {
+    preview.style.display = "none";
+  });
+}
+
+window.addEventListener("load", () => {
+  document.querySelectorAll("object").forEach((object) => {
+    enableDragging(object);
+    enablePreview(object);
+  });
+});
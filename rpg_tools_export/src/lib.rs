@@ -0,0 +1,273 @@
+pub mod link;
+pub mod page;
+
+use crate::link::{get_details_path, link_all_from_home};
+use crate::page::Pages;
+use anyhow::{bail, Context, Result};
+use rpg_tools_core::model::visibility::Audience;
+use rpg_tools_core::model::RpgData;
+use rpg_tools_core::utils::storage::{Element, Id};
+use rpg_tools_html::HtmlBuilder;
+use std::fs;
+use std::path::Path;
+
+const STYLE: &str = r#"h1 {
+  text-align: center;
+  color: blue;
+}
+
+.error {
+  color: red;
+}
+"#;
+
+/// Exports the setting as a folder of static html pages & svg maps.
+///
+/// Only the elements, which the [`audience`](Audience) can see, are exported.
+///
+/// Fails for a non-empty directory, because old pages of a different audience could remain.
+pub fn export_site(data: &RpgData, audience: Audience, directory: &Path) -> Result<()> {
+    check_directory(directory)?;
+
+    let pages = Pages::new(data, audience);
+
+    write_file(directory, "style.css", STYLE)?;
+    write_file(directory, "index.html", &pages.get_overview())?;
+
+    for building in pages.get_buildings() {
+        let path = get_details_path("building", building.id().id());
+        write_file(directory, &path, &pages.get_building(building))?;
+    }
+
+    for character in pages.get_characters() {
+        let id = character.id().id();
+        write_file(
+            directory,
+            &get_details_path("character", id),
+            &pages.get_character(character),
+        )?;
+        write_file(
+            directory,
+            &format!("character/{}.svg", id),
+            &pages.get_portrait(character),
+        )?;
+    }
+
+    for culture in pages.get_cultures() {
+        let path = get_details_path("culture", culture.id().id());
+        write_file(directory, &path, &pages.get_culture(culture))?;
+    }
+
+    for event in pages.get_events() {
+        let path = get_details_path("event", event.id().id());
+        write_file(directory, &path, &pages.get_event(event))?;
+    }
+
+    for mountain in data.mountain_manager.get_all() {
+        let path = get_details_path("mountain", mountain.id().id());
+        write_file(directory, &path, &pages.get_mountain(mountain))?;
+    }
+
+    for organization in pages.get_organizations() {
+        let path = get_details_path("organization", organization.id().id());
+        write_file(directory, &path, &pages.get_organization(organization))?;
+    }
+
+    for river in data.river_manager.get_all() {
+        let path = get_details_path("river", river.id().id());
+        write_file(directory, &path, &pages.get_river(river))?;
+    }
+
+    for street in pages.get_streets() {
+        let path = get_details_path("street", street.id().id());
+        write_file(directory, &path, &pages.get_street(street))?;
+    }
+
+    for town in pages.get_towns() {
+        let id = town.id().id();
+        write_file(
+            directory,
+            &get_details_path("town", id),
+            &pages.get_town(town),
+        )?;
+        write_file(directory, &format!("town/{}.svg", id), &pages.get_map(town))?;
+    }
+
+    for (storage, html) in pages.get_all_lists() {
+        write_file(directory, &link_all_from_home(storage), &html)?;
+    }
+
+    Ok(())
+}
+
+fn check_directory(directory: &Path) -> Result<()> {
+    if directory.exists()
+        && fs::read_dir(directory)
+            .context(format!("Failed to read directory {:?}", directory))?
+            .next()
+            .is_some()
+    {
+        bail!("Directory {:?} is not empty!", directory);
+    }
+
+    Ok(())
+}
+
+fn write_file(directory: &Path, file: &str, content: &str) -> Result<()> {
+    let path = directory.join(file);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).context(format!("Failed to create directory {:?}", parent))?;
+    }
+
+    fs::write(&path, content).context(format!("Failed to write {:?}", path))
+}
+
+pub(crate) fn create_html(title: &str, stylesheet: &str) -> HtmlBuilder {
+    HtmlBuilder::with_stylesheet(title, stylesheet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpg_tools_core::model::character::Character;
+    use rpg_tools_core::model::element::ElementRef;
+    use rpg_tools_core::model::event::Event;
+    use rpg_tools_core::model::organization::{Member, Organization, Relation};
+    use rpg_tools_core::model::visibility::Visibility;
+    use rpg_tools_core::model::world::building::lot::BuildingLot;
+    use rpg_tools_core::model::world::town::Town;
+    use rpg_tools_core::usecase::create::building::create_building;
+    use rpg_tools_core::usecase::edit::description::update_description;
+    use rpg_tools_core::usecase::edit::name::update_name;
+    use rpg_tools_core::usecase::edit::visibility::{
+        update_construction_visibility, update_visibility,
+    };
+
+    #[test]
+    fn export_only_visible_elements() {
+        let mut data = RpgData::default();
+        data.town_manager.create(Town::new);
+        data.characters.create(Character::new);
+        let id = create_building(&mut data, BuildingLot::tile(0)).unwrap();
+        update_visibility(&mut data.building_manager, id, Visibility::GmOnly).unwrap();
+        let temp = tempfile::tempdir().unwrap();
+        let directory = temp.path();
+
+        export_site(&data, Audience::Players, directory).unwrap();
+
+        assert!(directory.join("index.html").exists());
+        assert!(directory.join("town/0.html").exists());
+        assert!(directory.join("town/0.svg").exists());
+        assert!(directory.join("character/0.html").exists());
+        assert!(directory.join("character/0.svg").exists());
+        assert!(!directory.join("building/0.html").exists());
+    }
+
+    #[test]
+    fn skip_buildings_on_invisible_tiles() {
+        let mut data = RpgData::default();
+        let town = data.town_manager.create(Town::new);
+        let id = create_building(&mut data, BuildingLot::tile(0)).unwrap();
+        update_name(&mut data.building_manager, id, "Inn").unwrap();
+        update_construction_visibility(&mut data, town, 0, Visibility::GmOnly).unwrap();
+        update_description(&mut data.town_manager, town, "Has an [[Inn]].").unwrap();
+        let pages = Pages::new(&data, Audience::Players);
+
+        let html = pages.get_town(data.town_manager.get(town).unwrap());
+
+        assert!(pages.get_buildings().is_empty());
+        assert!(!html.contains("building/0.html"));
+        assert_eq!(
+            Pages::new(&data, Audience::GameMaster)
+                .get_buildings()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn export_visible_events() {
+        let mut data = RpgData::default();
+        let character = data.characters.create(Character::new);
+        let public = data.events.create(Event::new);
+        let hidden = data.events.create(Event::new);
+        update_name(&mut data.events, public, "Fire").unwrap();
+        update_visibility(&mut data.events, hidden, Visibility::Hidden).unwrap();
+        for id in [public, hidden] {
+            data.events
+                .get_mut(id)
+                .unwrap()
+                .add_participant(ElementRef::Character(character))
+                .unwrap();
+        }
+        update_description(&mut data.characters, character, "Saw the [[Fire]].").unwrap();
+        let temp = tempfile::tempdir().unwrap();
+        let directory = temp.path();
+
+        export_site(&data, Audience::Players, directory).unwrap();
+
+        assert!(directory.join("event/index.html").exists());
+        assert!(directory.join("event/0.html").exists());
+        assert!(!directory.join("event/1.html").exists());
+
+        let html = fs::read_to_string(directory.join("character/0.html")).unwrap();
+        assert!(
+            html.contains("[Fire](../event/0.html)") || html.contains("href=\"../event/0.html\"")
+        );
+        assert!(!html.contains("../event/1.html"));
+    }
+
+    #[test]
+    fn export_visible_organizations() {
+        let mut data = RpgData::default();
+        let character = data.characters.create(Character::new);
+        let hidden_character = data.characters.create(Character::new);
+        let public = data.organizations.create(Organization::new);
+        let hidden = data.organizations.create(Organization::new);
+        update_visibility(&mut data.organizations, hidden, Visibility::Hidden).unwrap();
+        update_visibility(&mut data.characters, hidden_character, Visibility::Hidden).unwrap();
+        let organization = data.organizations.get_mut(public).unwrap();
+        organization.members = vec![
+            Member::new(character, "Leader"),
+            Member::new(hidden_character, "Spy"),
+        ];
+        organization.relations.insert(hidden, Relation::Hostile);
+        let pages = Pages::new(&data, Audience::Players);
+
+        let html = pages.get_organization(data.organizations.get(public).unwrap());
+
+        assert_eq!(pages.get_organizations().len(), 1);
+        assert!(html.contains("../character/0.html"));
+        assert!(html.contains("(Leader)"));
+        assert!(!html.contains("../character/1.html"));
+        assert!(!html.contains("../organization/1.html"));
+    }
+
+    #[test]
+    fn export_into_non_empty_directory() {
+        let data = RpgData::default();
+        let temp = tempfile::tempdir().unwrap();
+        let directory = temp.path();
+        fs::write(directory.join("old.html"), "").unwrap();
+
+        assert!(export_site(&data, Audience::Players, directory).is_err());
+        assert!(!directory.join("index.html").exists());
+    }
+
+    #[test]
+    fn export_links_only_to_visible_elements() {
+        let mut data = RpgData::default();
+        let town = data.town_manager.create(Town::new);
+        let id = create_building(&mut data, BuildingLot::tile(0)).unwrap();
+        update_name(&mut data.building_manager, id, "Inn").unwrap();
+        update_visibility(&mut data.building_manager, id, Visibility::Hidden).unwrap();
+        update_description(&mut data.town_manager, town, "Has an [[Inn]].").unwrap();
+        let pages = Pages::new(&data, Audience::Players);
+
+        let html = pages.get_town(data.town_manager.get(town).unwrap());
+
+        assert!(html.contains("Has an Inn."));
+        assert!(!html.contains("building/0.html"));
+    }
+}
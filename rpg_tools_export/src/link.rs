@@ -0,0 +1,48 @@
+//! Relative links between the pages of the exported site.
+//!
+//! The overview is at the root of the site & every other page is in a folder named after its storage.
+
+/// The link from a page inside a storage folder to the overview.
+pub fn link_home() -> String {
+    "../index.html".to_string()
+}
+
+/// The link from the overview to the list of all elements of a storage.
+pub fn link_all_from_home(storage: &str) -> String {
+    format!("{}/index.html", storage)
+}
+
+/// The link from a page inside a storage folder to the list of all elements of a storage.
+///
+/// ```
+///# use rpg_tools_export::link::link_all;
+/// assert_eq!(link_all("town"), "../town/index.html");
+/// ```
+pub fn link_all(storage: &str) -> String {
+    format!("../{}/index.html", storage)
+}
+
+/// The link from a page inside a storage folder to the details of an element.
+///
+/// ```
+///# use rpg_tools_export::link::link_details;
+/// assert_eq!(link_details("building", 3), "../building/3.html");
+/// ```
+pub fn link_details(storage: &str, id: usize) -> String {
+    format!("../{}/{}.html", storage, id)
+}
+
+/// The link from a page inside a storage folder to the map of a town.
+pub fn link_town_map(id: usize) -> String {
+    format!("../town/{}.svg", id)
+}
+
+/// The link from a page inside a storage folder to the portrait of a character.
+pub fn link_portrait(id: usize) -> String {
+    format!("../character/{}.svg", id)
+}
+
+/// The path of the details page of an element relative to the root of the site.
+pub fn get_details_path(storage: &str, id: usize) -> String {
+    format!("{}/{}.html", storage, id)
+}
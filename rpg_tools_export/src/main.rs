@@ -0,0 +1,35 @@
+use anyhow::{bail, Context, Result};
+use rpg_tools_core::model::visibility::Audience;
+use rpg_tools_core::model::RpgData;
+use rpg_tools_export::export_site;
+use std::env;
+use std::path::PathBuf;
+
+/// Exports a setting as a static website.
+///
+/// Usage: rpg_tools_export <setting> <directory> [gm]
+///
+/// Without *gm* only the elements visible to the players are exported.
+fn main() -> Result<()> {
+    let args: Vec<String> = env::args().collect();
+
+    if args.len() < 3 {
+        bail!("Usage: rpg_tools_export <setting> <directory> [gm]");
+    }
+
+    let setting = &args[1];
+    let directory = PathBuf::from(&args[2]);
+    let audience = if args.get(3).map(|a| a.eq("gm")).unwrap_or(false) {
+        Audience::GameMaster
+    } else {
+        Audience::Players
+    };
+
+    let data = RpgData::load(setting).context("Failed to load rpg data!")?;
+
+    export_site(&data, audience, &directory)?;
+
+    println!("Exported setting {} to {:?}", setting, directory);
+
+    Ok(())
+}
@@ -0,0 +1,660 @@
+use crate::create_html;
+use crate::link::{
+    link_all, link_all_from_home, link_details, link_home, link_portrait, link_town_map,
+};
+use rpg_tools_core::model::character::culture::Culture;
+use rpg_tools_core::model::character::Character;
+use rpg_tools_core::model::description::WithDescription;
+use rpg_tools_core::model::element::ElementRef;
+use rpg_tools_core::model::event::{Event, EventId};
+use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::organization::Organization;
+use rpg_tools_core::model::visibility::{Audience, WithVisibility};
+use rpg_tools_core::model::world::building::{Building, BuildingId};
+use rpg_tools_core::model::world::mountain::Mountain;
+use rpg_tools_core::model::world::river::River;
+use rpg_tools_core::model::world::street::Street;
+use rpg_tools_core::model::world::town::towns::WithTowns;
+use rpg_tools_core::model::world::town::{Town, TownId};
+use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::get::age::get_age;
+use rpg_tools_core::usecase::get::event::{get_history, get_timeline};
+use rpg_tools_core::usecase::get::link::{replace_links, resolve_link};
+use rpg_tools_core::usecase::get::name::get_character_name;
+use rpg_tools_core::usecase::get::name::get_element_name;
+use rpg_tools_core::usecase::get::organization::{get_children, get_memberships};
+use rpg_tools_core::usecase::get::town::is_building_visible;
+use rpg_tools_core::utils::storage::{Element, Id, Storage};
+use rpg_tools_core::utils::text::capitalize;
+use rpg_tools_html::escape::escape_markdown;
+use rpg_tools_html::HtmlBuilder;
+use rpg_tools_rendering::usecase::map::town::{render_town, TownMapOptions};
+use rpg_tools_rendering::usecase::map::TileMapRenderer;
+use rpg_tools_rendering::usecase::portrait::render_portrait_svg;
+
+const STYLESHEET: &str = "../style.css";
+const PORTRAIT_SIZE: u32 = 200;
+
+/// Generates the pages of the exported site.
+pub struct Pages<'a> {
+    data: &'a RpgData,
+    audience: Audience,
+    renderer: TileMapRenderer,
+}
+
+impl<'a> Pages<'a> {
+    pub fn new(data: &'a RpgData, audience: Audience) -> Self {
+        Self {
+            data,
+            audience,
+            renderer: TileMapRenderer::new(100, 1),
+        }
+    }
+
+    // Visible elements
+
+    pub fn get_buildings(&self) -> Vec<&'a Building> {
+        self.data
+            .building_manager
+            .get_all()
+            .iter()
+            .filter(|building| self.is_building_visible(building))
+            .collect()
+    }
+
+    pub fn get_characters(&self) -> Vec<&'a Character> {
+        get_visible(&self.data.characters, self.audience)
+    }
+
+    pub fn get_cultures(&self) -> Vec<&'a Culture> {
+        get_visible(&self.data.cultures, self.audience)
+    }
+
+    pub fn get_organizations(&self) -> Vec<&'a Organization> {
+        get_visible(&self.data.organizations, self.audience)
+    }
+
+    /// Returns the visible events in chronological order.
+    pub fn get_events(&self) -> Vec<&'a Event> {
+        get_timeline(self.data, self.audience)
+            .into_iter()
+            .flat_map(|id| self.data.events.get(id))
+            .collect()
+    }
+
+    pub fn get_streets(&self) -> Vec<&'a Street> {
+        get_visible(&self.data.street_manager, self.audience)
+    }
+
+    pub fn get_towns(&self) -> Vec<&'a Town> {
+        get_visible(&self.data.town_manager, self.audience)
+    }
+
+    fn is_town_visible(&self, id: TownId) -> bool {
+        self.data
+            .town_manager
+            .get(id)
+            .map(|town| town.is_visible_to(self.audience))
+            .unwrap_or(false)
+    }
+
+    /// The building, its town & all tiles of its lot must be visible.
+    fn is_building_visible(&self, building: &Building) -> bool {
+        self.data
+            .town_manager
+            .get(building.lot.town)
+            .filter(|town| town.is_visible_to(self.audience))
+            .map(|town| is_building_visible(self.data, town, building, self.audience))
+            .unwrap_or(false)
+    }
+
+    fn is_element_visible(&self, element: ElementRef) -> bool {
+        match element {
+            ElementRef::Building(id) => self
+                .data
+                .building_manager
+                .get(id)
+                .map(|building| self.is_building_visible(building))
+                .unwrap_or(false),
+            ElementRef::Character(id) => is_visible(&self.data.characters, id, self.audience),
+            ElementRef::Culture(id) => is_visible(&self.data.cultures, id, self.audience),
+            ElementRef::Event(id) => is_visible(&self.data.events, id, self.audience),
+            ElementRef::Organization(id) => is_visible(&self.data.organizations, id, self.audience),
+            ElementRef::Mountain(id) => self.data.mountain_manager.get(id).is_some(),
+            ElementRef::River(id) => self.data.river_manager.get(id).is_some(),
+            ElementRef::Street(id) => is_visible(&self.data.street_manager, id, self.audience),
+            ElementRef::Town(id) => self.is_town_visible(id),
+        }
+    }
+
+    fn get_visible_towns<T: WithTowns>(&self, element: &T) -> Vec<&'a Town> {
+        let mut towns: Vec<&Town> = element
+            .towns()
+            .iter()
+            .flat_map(|id| self.data.town_manager.get(*id))
+            .filter(|town| town.is_visible_to(self.audience))
+            .collect();
+        towns.sort_by_key(|town| town.id().id());
+        towns
+    }
+
+    /// Adds the description with links to the visible elements.
+    ///
+    /// Links to invisible elements & broken links are replaced by plain text.
+    fn add_description(&self, builder: HtmlBuilder, description: Option<&str>) -> HtmlBuilder {
+        if let Some(description) = description {
+            let markdown = replace_links(description, |link| {
+                match resolve_link(self.data, link)
+                    .ok()
+                    .filter(|element| self.is_element_visible(*element))
+                {
+                    Some(element) => format!(
+                        "[{}]({})",
+                        escape_markdown(&get_element_name(self.data, element).unwrap_or_default()),
+                        link_details(element.storage(), element.id())
+                    ),
+                    None => escape_markdown(link),
+                }
+            });
+
+            builder.h2("Description").markdown(&markdown)
+        } else {
+            builder
+        }
+    }
+
+    /// Adds the visible events of the element in chronological order.
+    fn add_history(&self, builder: HtmlBuilder, element: ElementRef) -> HtmlBuilder {
+        let history = get_history(self.data, element, self.audience);
+
+        if history.is_empty() {
+            builder
+        } else {
+            self.add_events(builder.h2("History"), &history)
+        }
+    }
+
+    /// Adds a field with a link to the element, if it is visible.
+    fn add_element_field(
+        &self,
+        builder: HtmlBuilder,
+        title: &str,
+        element: Option<ElementRef>,
+    ) -> HtmlBuilder {
+        builder.option(
+            element.filter(|element| self.is_element_visible(*element)),
+            |element, b| b.complex_field(title, |b| self.link_element(b, element)),
+        )
+    }
+
+    /// Adds a list of the visible elements with an optional text after each link.
+    fn add_elements(
+        &self,
+        builder: HtmlBuilder,
+        title: &str,
+        elements: &[(ElementRef, String)],
+    ) -> HtmlBuilder {
+        let elements: Vec<_> = elements
+            .iter()
+            .filter(|(element, _)| self.is_element_visible(*element))
+            .collect();
+
+        builder
+            .h2(title)
+            .field_usize("Count:", elements.len())
+            .list(&elements, |b, (element, text)| {
+                self.link_element(b, *element).text(text)
+            })
+    }
+
+    fn link_element(&self, builder: HtmlBuilder, element: ElementRef) -> HtmlBuilder {
+        builder.link(
+            &link_details(element.storage(), element.id()),
+            &get_element_name(self.data, element).unwrap_or_default(),
+        )
+    }
+
+    fn add_events(&self, builder: HtmlBuilder, events: &[EventId]) -> HtmlBuilder {
+        builder.list(events, |b, &id| {
+            b.option(self.data.events.get(id), |event, b| {
+                b.text(&format!(
+                    "{}: ",
+                    self.data.time.calendar.format(&event.date)
+                ))
+                .link(&link_details("event", id.id()), event.name().str())
+            })
+        })
+    }
+
+    // Pages
+
+    pub fn get_overview(&self) -> String {
+        let lists = [
+            ("Buildings:", "building", self.get_buildings().len()),
+            ("Characters:", "character", self.get_characters().len()),
+            ("Cultures:", "culture", self.get_cultures().len()),
+            ("Events:", "event", self.get_events().len()),
+            ("Mountains:", "mountain", self.data.mountain_manager.len()),
+            (
+                "Organizations:",
+                "organization",
+                self.get_organizations().len(),
+            ),
+            ("Rivers:", "river", self.data.river_manager.len()),
+            ("Streets:", "street", self.get_streets().len()),
+            ("Towns:", "town", self.get_towns().len()),
+        ];
+
+        create_html(&self.data.setting, "style.css")
+            .h1(&self.data.setting)
+            .h2("Overview")
+            .list(&lists, |b, &(title, storage, count)| {
+                b.bold(title)
+                    .complex_link(&link_all_from_home(storage), |a| a.usize(count))
+            })
+            .finish()
+    }
+
+    /// Returns the storage names & the pages listing all their visible elements.
+    pub fn get_all_lists(&self) -> Vec<(&'static str, String)> {
+        vec![
+            (
+                "building",
+                get_list_html("Buildings", "building", &self.get_buildings()),
+            ),
+            ("character", self.get_character_list()),
+            (
+                "culture",
+                get_list_html("Cultures", "culture", &self.get_cultures()),
+            ),
+            ("event", self.get_timeline()),
+            (
+                "mountain",
+                get_list_html(
+                    "Mountains",
+                    "mountain",
+                    &self
+                        .data
+                        .mountain_manager
+                        .get_all()
+                        .iter()
+                        .collect::<Vec<_>>(),
+                ),
+            ),
+            (
+                "organization",
+                get_list_html("Organizations", "organization", &self.get_organizations()),
+            ),
+            (
+                "river",
+                get_list_html(
+                    "Rivers",
+                    "river",
+                    &self.data.river_manager.get_all().iter().collect::<Vec<_>>(),
+                ),
+            ),
+            (
+                "street",
+                get_list_html("Streets", "street", &self.get_streets()),
+            ),
+            ("town", get_list_html("Towns", "town", &self.get_towns())),
+        ]
+    }
+
+    fn get_character_list(&self) -> String {
+        let characters = self.get_characters();
+
+        create_html("Characters", STYLESHEET)
+            .h1("Characters")
+            .field_usize("Count:", characters.len())
+            .list(&characters, |b, character| {
+                b.link(
+                    &link_details("character", character.id().id()),
+                    &get_character_name(self.data, character.id()).unwrap_or_default(),
+                )
+            })
+            .p(|b| b.link(&link_home(), "Back"))
+            .finish()
+    }
+
+    fn get_timeline(&self) -> String {
+        let events: Vec<EventId> = self.get_events().iter().map(|event| event.id()).collect();
+
+        self.add_events(
+            create_html("Timeline", STYLESHEET)
+                .h1("Timeline")
+                .field_usize("Count:", events.len()),
+            &events,
+        )
+        .p(|b| b.link(&link_home(), "Back"))
+        .finish()
+    }
+
+    pub fn get_building(&self, building: &Building) -> String {
+        let builder = create_html(building.name().str(), STYLESHEET)
+            .h1(&format!("Building: {}", building.name()))
+            .option(building.building_type.as_deref(), |building_type, b| {
+                b.field("Type:", building_type)
+            })
+            .option(
+                building.address.and_then(|address| {
+                    self.data
+                        .street_manager
+                        .get(address.street)
+                        .filter(|street| street.is_visible_to(self.audience))
+                        .map(|street| (address.number, street))
+                }),
+                |(number, street), b| {
+                    b.complex_field("Address:", |b| {
+                        b.text(&format!("{} ", number)).link(
+                            &link_details("street", street.id().id()),
+                            street.name().str(),
+                        )
+                    })
+                },
+            )
+            .h2("Lot")
+            .option(
+                self.data
+                    .town_manager
+                    .get(building.lot.town)
+                    .filter(|town| town.is_visible_to(self.audience)),
+                |town, b| {
+                    b.complex_field("Town:", |b| {
+                        b.link(&link_details("town", town.id().id()), town.name().str())
+                    })
+                },
+            )
+            .complex_field("Size:", |b| {
+                b.text(&format!(
+                    "{} x {}",
+                    building.lot.size.width(),
+                    building.lot.size.height()
+                ))
+            });
+
+        let builder = self.add_description(builder, building.description());
+
+        self.add_history(builder, ElementRef::Building(building.id()))
+            .p(|b| b.link(&link_all("building"), "Back"))
+            .finish()
+    }
+
+    pub fn get_character(&self, character: &Character) -> String {
+        let name = get_character_name(self.data, character.id()).unwrap_or_default();
+
+        let builder = create_html(&name, STYLESHEET)
+            .h1(&format!("Character: {}", name))
+            .center(|b| b.svg(&link_portrait(character.id().id()), "200"))
+            .h2("Name")
+            .field("First Name:", character.name.first().str())
+            .option(character.name.middle(), |middle, b| {
+                b.field("Middle Name:", middle.str())
+            })
+            .option(character.name.last().name(), |last, b| {
+                b.field(
+                    &format!("{}:", character.name.last().get_type()),
+                    last.str(),
+                )
+            })
+            .h2("Other")
+            .field("Gender:", &character.gender)
+            .option(character.birth_date, |date, b| {
+                b.field("Born:", &self.data.time.calendar.format(&date))
+            })
+            .option(character.death_date, |date, b| {
+                b.field("Died:", &self.data.time.calendar.format(&date))
+            })
+            .option(get_age(self.data, character.id()), |age, b| {
+                b.field_usize("Age:", age as usize)
+            })
+            .option(
+                self.data
+                    .cultures
+                    .get(character.culture)
+                    .filter(|culture| culture.is_visible_to(self.audience)),
+                |culture, b| {
+                    b.complex_field("Culture:", |b| {
+                        b.link(
+                            &link_details("culture", culture.id().id()),
+                            culture.name().str(),
+                        )
+                    })
+                },
+            );
+
+        let memberships: Vec<_> = get_memberships(self.data, character.id())
+            .into_iter()
+            .map(|(id, role)| (ElementRef::Organization(id), format_role(role)))
+            .collect();
+        let builder = self.add_elements(builder, "Organizations", &memberships);
+        let builder = self.add_description(builder, character.description());
+
+        self.add_history(builder, ElementRef::Character(character.id()))
+            .p(|b| b.link(&link_all("character"), "Back"))
+            .finish()
+    }
+
+    pub fn get_portrait(&self, character: &Character) -> String {
+        render_portrait_svg(&character.appearance, PORTRAIT_SIZE).export()
+    }
+
+    pub fn get_culture(&self, culture: &Culture) -> String {
+        let characters: Vec<&Character> = self
+            .get_characters()
+            .into_iter()
+            .filter(|character| character.culture.eq(&culture.id()))
+            .collect();
+
+        let builder = create_html(culture.name().str(), STYLESHEET)
+            .h1(&format!("Culture: {}", culture.name()))
+            .field_usize("Characters:", characters.len())
+            .list(&characters, |b, character| {
+                b.link(
+                    &link_details("character", character.id().id()),
+                    &get_character_name(self.data, character.id()).unwrap_or_default(),
+                )
+            });
+
+        self.add_description(builder, culture.description())
+            .p(|b| b.link(&link_all("culture"), "Back"))
+            .finish()
+    }
+
+    pub fn get_event(&self, event: &Event) -> String {
+        let participants: Vec<ElementRef> = event
+            .participants()
+            .into_iter()
+            .filter(|participant| self.is_element_visible(*participant))
+            .collect();
+
+        let builder = create_html(event.name().str(), STYLESHEET)
+            .h1(&format!("Event: {}", event.name()))
+            .field("Date:", &self.data.time.calendar.format(&event.date))
+            .h2("Participants")
+            .field_usize("Count:", participants.len())
+            .list(&participants, |b, &participant| {
+                b.text(&format!("{}: ", capitalize(participant.storage())))
+                    .link(
+                        &link_details(participant.storage(), participant.id()),
+                        &get_element_name(self.data, participant).unwrap_or_default(),
+                    )
+            });
+
+        self.add_description(builder, event.description())
+            .p(|b| b.link(&link_all("event"), "Back"))
+            .finish()
+    }
+
+    pub fn get_organization(&self, organization: &Organization) -> String {
+        let builder = create_html(organization.name().str(), STYLESHEET)
+            .h1(&format!("Organization: {}", organization.name()));
+        let builder = self.add_element_field(
+            builder,
+            "Parent:",
+            organization.parent.map(ElementRef::Organization),
+        );
+        let builder = self.add_element_field(
+            builder,
+            "Headquarters:",
+            organization.headquarters.map(ElementRef::Building),
+        );
+        let children: Vec<_> = get_children(self.data, organization.id())
+            .into_iter()
+            .map(|id| (ElementRef::Organization(id), String::new()))
+            .collect();
+        let members: Vec<_> = organization
+            .members
+            .iter()
+            .map(|member| {
+                (
+                    ElementRef::Character(member.character),
+                    format_role(&member.role),
+                )
+            })
+            .collect();
+        let buildings: Vec<_> = organization
+            .buildings
+            .iter()
+            .map(|id| (ElementRef::Building(*id), String::new()))
+            .collect();
+        let relations: Vec<_> = organization
+            .relations
+            .iter()
+            .map(|(id, relation)| (ElementRef::Organization(*id), format!(" ({})", relation)))
+            .collect();
+
+        let builder = self.add_elements(builder, "Children", &children);
+        let builder = self.add_elements(builder, "Members", &members);
+        let builder = self.add_elements(builder, "Buildings", &buildings);
+        let builder = self.add_elements(builder, "Relations", &relations);
+
+        self.add_description(builder, organization.description())
+            .p(|b| b.link(&link_all("organization"), "Back"))
+            .finish()
+    }
+
+    pub fn get_mountain(&self, mountain: &Mountain) -> String {
+        self.get_with_towns("Mountain", ElementRef::Mountain(mountain.id()), mountain)
+    }
+
+    pub fn get_river(&self, river: &River) -> String {
+        self.get_with_towns("River", ElementRef::River(river.id()), river)
+    }
+
+    pub fn get_street(&self, street: &Street) -> String {
+        self.get_with_towns("Street", ElementRef::Street(street.id()), street)
+    }
+
+    fn get_with_towns<T: WithName + WithTowns + WithDescription>(
+        &self,
+        title: &str,
+        reference: ElementRef,
+        element: &T,
+    ) -> String {
+        let towns = self.get_visible_towns(element);
+
+        let builder = create_html(element.name().str(), STYLESHEET)
+            .h1(&format!("{}: {}", title, element.name()))
+            .field_usize("Towns:", towns.len())
+            .list(&towns, |b, town| {
+                b.link(&link_details("town", town.id().id()), town.name().str())
+            });
+
+        let builder = self.add_description(builder, element.description());
+
+        self.add_history(builder, reference)
+            .p(|b| b.link(&link_all(reference.storage()), "Back"))
+            .finish()
+    }
+
+    pub fn get_town(&self, town: &Town) -> String {
+        let buildings: Vec<&Building> = self
+            .get_buildings()
+            .into_iter()
+            .filter(|building| building.lot.town.eq(&town.id()))
+            .collect();
+
+        let builder = create_html(town.name().str(), STYLESHEET)
+            .h1(&format!("Town: {}", town.name()))
+            .field_usize("Buildings:", buildings.len())
+            .list(&buildings, |b, building| {
+                b.link(
+                    &link_details("building", building.id().id()),
+                    building.name().str(),
+                )
+            });
+
+        let builder = self.add_description(builder, town.description());
+
+        self.add_history(builder, ElementRef::Town(town.id()))
+            .p(|b| b.link(&link_all("town"), "Back"))
+            .h2("Map")
+            .center(|b| b.svg(&link_town_map(town.id().id()), "800"))
+            .finish()
+    }
+
+    pub fn get_map(&self, town: &Town) -> String {
+        render_town(
+            self.data,
+            &self.renderer,
+            town,
+            self.audience,
+            &TownMapOptions::default(),
+            |id| Some(link_building(id)),
+        )
+        .export()
+    }
+}
+
+fn format_role(role: &str) -> String {
+    if role.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", role)
+    }
+}
+
+fn link_building(id: BuildingId) -> String {
+    link_details("building", id.id())
+}
+
+fn is_visible<I: Id, T: Element<I> + WithVisibility>(
+    storage: &Storage<I, T>,
+    id: I,
+    audience: Audience,
+) -> bool {
+    storage
+        .get(id)
+        .map(|element| element.is_visible_to(audience))
+        .unwrap_or(false)
+}
+
+fn get_visible<I: Id, T: Element<I> + WithVisibility>(
+    storage: &Storage<I, T>,
+    audience: Audience,
+) -> Vec<&T> {
+    storage
+        .get_all()
+        .iter()
+        .filter(|element| element.is_visible_to(audience))
+        .collect()
+}
+
+fn get_list_html<I: Id, T: Element<I> + WithName>(
+    title: &str,
+    storage: &str,
+    elements: &[&T],
+) -> String {
+    create_html(title, STYLESHEET)
+        .h1(title)
+        .field_usize("Count:", elements.len())
+        .list(elements, |b: HtmlBuilder, element| {
+            b.link(
+                &link_details(storage, element.id().id()),
+                element.name().str(),
+            )
+        })
+        .p(|b| b.link(&link_home(), "Back"))
+        .finish()
+}
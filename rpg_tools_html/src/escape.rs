@@ -0,0 +1,32 @@
+/// Escapes the characters with a special meaning in html.
+///
+/// ```
+///# use rpg_tools_html::escape::escape_html;
+/// assert_eq!(escape_html(r#"<a href="x">B & C</a>"#), "&lt;a href=&quot;x&quot;&gt;B &amp; C&lt;/a&gt;");
+/// ```
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Escapes all ASCII punctuation with a backslash, so [Markdown](https://commonmark.org/) treats it as plain text.
+///
+/// ```
+///# use rpg_tools_html::escape::escape_markdown;
+/// assert_eq!(escape_markdown("A [B](c)"), r"A \[B\]\(c\)");
+/// ```
+pub fn escape_markdown(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if c.is_ascii_punctuation() {
+            result.push('\\');
+        }
+
+        result.push(c);
+    }
+
+    result
+}
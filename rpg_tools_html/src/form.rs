@@ -1,3 +1,4 @@
+use crate::escape::escape_html;
 use crate::HtmlBuilder;
 
 pub struct FormBuilder {
@@ -23,6 +24,22 @@ impl FormBuilder {
         }
     }
 
+    pub fn text_area(self, label: &str, name: &str, value: &str) -> Self {
+        Self {
+            html: self.html.p(|b| {
+                b.open_tag_with_attribute("label", "for", name)
+                    .bold(label)
+                    .close_tag()
+                    .text("<br>")
+                    .text(&format!(
+                        r#"<textarea id="{0}" name="{0}" rows="10" cols="80">{1}</textarea>"#,
+                        name,
+                        escape_html(value)
+                    ))
+            }),
+        }
+    }
+
     pub fn number_input(
         self,
         label: &str,
@@ -44,6 +61,25 @@ impl FormBuilder {
         }
     }
 
+    pub fn hidden(self, name: &str, value: &str) -> Self {
+        Self {
+            html: self.html.text(&format!(
+                r#"<input type="hidden" id="{0}" name="{0}" value="{1}">"#,
+                name,
+                escape_html(value)
+            )),
+        }
+    }
+
+    /// Only executes *f*, if *option* is some value.
+    pub fn option<T, F: FnOnce(T, Self) -> Self>(self, option: Option<T>, f: F) -> Self {
+        if let Some(value) = option {
+            f(value, self)
+        } else {
+            self
+        }
+    }
+
     pub fn error(self, error: &str) -> Self {
         if error.is_empty() {
             self
@@ -97,6 +133,28 @@ impl FormBuilder {
         name: &str,
         values: &[(usize, &str)],
         selected: usize,
+    ) -> Self {
+        self.select_id_with_none(label, name, values, Some(selected), false)
+    }
+
+    /// A select for ids with an additional empty option, which represents no id.
+    pub fn select_optional_id(
+        self,
+        label: &str,
+        name: &str,
+        values: &[(usize, &str)],
+        selected: Option<usize>,
+    ) -> Self {
+        self.select_id_with_none(label, name, values, selected, true)
+    }
+
+    fn select_id_with_none(
+        self,
+        label: &str,
+        name: &str,
+        values: &[(usize, &str)],
+        selected: Option<usize>,
+        with_none: bool,
     ) -> Self {
         Self {
             html: self.html.p(|mut b| {
@@ -114,8 +172,16 @@ impl FormBuilder {
                         "updateEditor();",
                     );
 
+                if with_none {
+                    if selected.is_none() {
+                        b.add(r#"<option value="" selected>None</option>"#.to_string());
+                    } else {
+                        b.add(r#"<option value="">None</option>"#.to_string());
+                    }
+                }
+
                 for &(id, value) in values {
-                    if id == selected {
+                    if Some(id) == selected {
                         b.add(format!(
                             r#"<option value="{}" selected>{}</option>"#,
                             id, value
@@ -1,6 +1,9 @@
+pub mod escape;
 pub mod form;
+pub mod markdown;
 
 use crate::form::FormBuilder;
+use crate::markdown::markdown_to_html;
 
 pub struct HtmlBuilder {
     lines: Vec<String>,
@@ -9,17 +12,30 @@ pub struct HtmlBuilder {
 
 impl HtmlBuilder {
     pub fn new(title: &str) -> Self {
+        Self::start()
+            .text(r#"<link rel="stylesheet" href="/static/style.css">"#)
+            .text(r#"<script src="/static/scripts.js" charset="utf-8" defer></script>"#)
+            .inline_tag("title", title)
+            .close_tag()
+            .open_tag("body")
+    }
+
+    /// Creates a page without scripts, which uses a stylesheet relative to the page.
+    pub fn with_stylesheet(title: &str, stylesheet: &str) -> Self {
+        Self::start()
+            .text(&format!(r#"<link rel="stylesheet" href="{}">"#, stylesheet))
+            .inline_tag("title", title)
+            .close_tag()
+            .open_tag("body")
+    }
+
+    fn start() -> Self {
         Self {
             lines: vec!["<!DOCTYPE html>".to_string()],
             elements: Vec::new(),
         }
         .open_tag("html")
         .open_tag("head")
-        .text(r#"<link rel="stylesheet" href="/static/style.css">"#)
-        .text(r#"<script src="/static/scripts.js" charset="utf-8" defer></script>"#)
-        .inline_tag("title", title)
-        .close_tag()
-        .open_tag("body")
     }
 
     pub fn finish(mut self) -> String {
@@ -48,6 +64,7 @@ impl HtmlBuilder {
         ));
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn add_tag_with_3_attributes(
         &mut self,
         tag: &str,
@@ -93,6 +110,7 @@ impl HtmlBuilder {
         self
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn open_tag_with_3_attributes(
         mut self,
         tag: &str,
@@ -183,6 +201,14 @@ impl HtmlBuilder {
         self
     }
 
+    /// Adds [Markdown](https://commonmark.org/) converted to html.
+    pub fn markdown(mut self, text: &str) -> Self {
+        for line in markdown_to_html(text).lines() {
+            self.add(line.to_string());
+        }
+        self
+    }
+
     pub fn usize(self, number: usize) -> Self {
         self.text(&number.to_string())
     }
@@ -0,0 +1,27 @@
+use pulldown_cmark::{html, Event, Options, Parser};
+
+/// Converts [Markdown](https://commonmark.org/) to html.
+///
+/// Raw html is escaped & shown as text.
+///
+/// ```
+///# use rpg_tools_html::markdown::markdown_to_html;
+/// assert_eq!(markdown_to_html("A *B*"), "<p>A <em>B</em></p>\n");
+/// assert_eq!(
+///     markdown_to_html("A <script>alert(1)</script>"),
+///     "<p>A &lt;script&gt;alert(1)&lt;/script&gt;</p>\n"
+/// );
+/// ```
+pub fn markdown_to_html(text: &str) -> String {
+    let parser = Parser::new_ext(text, Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TABLES).map(
+        |event| match event {
+            Event::Html(html) => Event::Text(html),
+            event => event,
+        },
+    );
+    let mut output = String::new();
+
+    html::push_html(&mut output, parser);
+
+    output
+}
@@ -1,4 +1,5 @@
 use crate::renderer::style::RenderStyle;
+use rpg_tools_core::model::color::Color;
 use rpg_tools_core::model::math::aabb2d::AABB;
 use rpg_tools_core::model::math::point2d::Point2d;
 
@@ -14,8 +15,30 @@ pub trait Renderer {
         self.render_circle(&aabb.center(), aabb.inner_radius(), style)
     }
 
+    /// Renders an axis aligned ellipse.
+    fn render_ellipse(
+        &mut self,
+        center: &Point2d,
+        radius_x: u32,
+        radius_y: u32,
+        style: &RenderStyle,
+    );
+
+    /// Renders an ellipse in an [`axis aligned bounding box`](AABB).
+    fn render_ellipse_in_aabb(&mut self, aabb: &AABB, style: &RenderStyle) {
+        self.render_ellipse(
+            &aabb.center(),
+            aabb.size().width() as u32 / 2,
+            aabb.size().height() as u32 / 2,
+            style,
+        )
+    }
+
     /// Renders an axis aligned rectangle.
     fn render_rectangle(&mut self, aabb: &AABB, style: &RenderStyle);
+
+    /// Renders a text centered at a point.
+    fn render_text(&mut self, center: &Point2d, text: &str, size: u32, color: Color);
 }
 
 pub trait LinkRenderer: Renderer {
@@ -54,6 +54,23 @@ impl SvgBuilder {
         )
     }
 
+    fn get_ellipse_attributes(
+        &self,
+        center: &Point2d,
+        radius_x: u32,
+        radius_y: u32,
+        style: &RenderStyle,
+    ) -> String {
+        format!(
+            r#"cx="{}" cy="{}" rx="{}" ry="{}" style="{}""#,
+            center.x,
+            center.y,
+            radius_x,
+            radius_y,
+            to_style(style),
+        )
+    }
+
     fn get_rectangle_attributes(&mut self, aabb: &AABB, style: &RenderStyle) -> String {
         format!(
             r#"x="{}" y="{}" width="{}" height="{}" style="{}""#,
@@ -65,6 +82,11 @@ impl SvgBuilder {
         )
     }
 
+    /// Opens a group with an id, which is closed like a link.
+    pub fn group(&mut self, id: &str) {
+        self.open_width_attributes("g", &format!("id=\"{}\"", escape(id)));
+    }
+
     pub fn finish(mut self) -> Svg {
         while !self.elements.is_empty() {
             self.close();
@@ -89,6 +111,24 @@ impl Renderer for SvgBuilder {
         }
     }
 
+    fn render_ellipse(
+        &mut self,
+        center: &Point2d,
+        radius_x: u32,
+        radius_y: u32,
+        style: &RenderStyle,
+    ) {
+        let attributes = self.get_ellipse_attributes(center, radius_x, radius_y, style);
+
+        if self.tooltip.is_some() {
+            self.open_width_attributes("ellipse", &attributes);
+            self.add(format!("<title>{}</title>", self.tooltip.clone().unwrap()));
+            self.close();
+        } else {
+            self.add(format!("<ellipse {}/>", attributes));
+        }
+    }
+
     fn render_rectangle(&mut self, aabb: &AABB, style: &RenderStyle) {
         let attributes = self.get_rectangle_attributes(aabb, style);
 
@@ -100,6 +140,17 @@ impl Renderer for SvgBuilder {
             self.add(format!("<rect {}/>", attributes));
         }
     }
+
+    fn render_text(&mut self, center: &Point2d, text: &str, size: u32, color: Color) {
+        self.add(format!(
+            r#"<text x="{}" y="{}" font-size="{}" text-anchor="middle" dominant-baseline="central" style="{}">{}</text>"#,
+            center.x,
+            center.y,
+            size,
+            to_color(&color, "fill"),
+            escape(text),
+        ));
+    }
 }
 
 impl LinkRenderer for SvgBuilder {
@@ -148,6 +199,12 @@ fn to_stroke(color: &Color, width: u32) -> String {
     format!("{};stroke-width:{}", to_color(color, "stroke"), width)
 }
 
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
 fn to_color(color: &Color, text: &str) -> String {
     format!("{}:{}", text, color.to_string().to_lowercase())
 }
@@ -0,0 +1,25 @@
+use crate::renderer::svg::builder::SvgBuilder;
+use crate::renderer::svg::Svg;
+use crate::usecase::map::TileMapRenderer;
+use rpg_tools_core::model::math::point2d::Point2d;
+use rpg_tools_core::model::world::building::interior::{Floor, InteriorTile};
+
+/// Renders the floor plan of a [`floor`](Floor) with optional links for each tile.
+pub fn render_floor<F: Fn(usize) -> Option<String>>(
+    renderer: &TileMapRenderer,
+    floor: &Floor,
+    get_link: F,
+) -> Svg {
+    let size = renderer.calculate_map_size(&floor.map);
+    let mut builder = SvgBuilder::new(size);
+
+    renderer.render_links(
+        &mut builder,
+        &Point2d::default(),
+        &floor.map,
+        InteriorTile::get_color,
+        |index, _tile| get_link(index),
+    );
+
+    builder.finish()
+}
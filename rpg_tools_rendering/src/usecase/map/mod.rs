@@ -1,3 +1,4 @@
+pub mod interior;
 pub mod town;
 
 use crate::renderer::style::RenderStyle;
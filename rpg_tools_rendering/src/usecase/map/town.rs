@@ -1,67 +1,244 @@
 use crate::renderer::style::RenderStyle;
 use crate::renderer::svg::builder::SvgBuilder;
-use crate::renderer::Renderer;
+use crate::renderer::svg::Svg;
+use crate::renderer::{LinkRenderer, Renderer, Tooltip};
 use crate::usecase::map::TileMapRenderer;
 use rpg_tools_core::model::color::Color;
 use rpg_tools_core::model::math::aabb2d::AABB;
 use rpg_tools_core::model::math::point2d::Point2d;
-use rpg_tools_core::model::world::building::Building;
+use rpg_tools_core::model::math::size2d::Size2d;
+use rpg_tools_core::model::name::WithName;
+use rpg_tools_core::model::visibility::{Audience, WithVisibility};
+use rpg_tools_core::model::world::building::entrance::Entrance;
+use rpg_tools_core::model::world::building::lot::BuildingLot;
+use rpg_tools_core::model::world::building::types::BuildingTypeCatalog;
+use rpg_tools_core::model::world::building::{Building, BuildingId};
 use rpg_tools_core::model::world::street::StreetId;
-use rpg_tools_core::model::world::town::construction::Construction;
 use rpg_tools_core::model::world::town::construction::Construction::Street;
+use rpg_tools_core::model::world::town::terrain::Terrain;
+use rpg_tools_core::model::world::town::tile::TownTile;
 use rpg_tools_core::model::world::town::Town;
 use rpg_tools_core::model::RpgData;
+use rpg_tools_core::usecase::get::address::address;
+use rpg_tools_core::usecase::get::name::get_character_name;
+use rpg_tools_core::usecase::get::occupant::{get_residents, get_workers};
+use rpg_tools_core::usecase::get::town::{is_building_visible, is_construction_visible};
 use rpg_tools_core::utils::storage::Element;
 
+/// Renders all [`buildings`](Building) of the [`town`](Town), which the [`audience`](Audience) can see.
 pub fn render_buildings(
     data: &RpgData,
     builder: &mut SvgBuilder,
     renderer: &TileMapRenderer,
     town: &Town,
+    audience: Audience,
 ) {
+    get_visible_buildings(data, town, audience).for_each(|building| {
+        render_building(builder, renderer, &data.building_types, town, building)
+    });
+}
+
+/// Returns all [`buildings`](Building) of the [`town`](Town), which the [`audience`](Audience) can see.
+/// The tiles of their lots must be visible too.
+pub fn get_visible_buildings<'a>(
+    data: &'a RpgData,
+    town: &'a Town,
+    audience: Audience,
+) -> impl Iterator<Item = &'a Building> {
     data.building_manager
         .get_all()
         .iter()
-        .filter(|&building| building.lot.town.eq(&town.id()))
-        .for_each(|building| render_building(builder, renderer, town, building));
+        .filter(move |&building| building.lot.town.eq(&town.id()))
+        .filter(move |&building| is_building_visible(data, town, building, audience))
 }
 
+/// Renders a [`building`](Building) with the color & icon of its type.
 pub fn render_building(
     builder: &mut SvgBuilder,
     renderer: &TileMapRenderer,
+    types: &BuildingTypeCatalog,
     town: &Town,
     building: &Building,
 ) {
-    let style = RenderStyle::no_border(Color::Black);
-    let start = renderer.calculate_index_position(
-        &Point2d::default(),
-        town.map.get_size(),
-        building.lot.tile,
+    let color = types.get_color(building.building_type.as_deref());
+    let style = RenderStyle::no_border(color);
+    render_building_with_style(builder, renderer, types, town, building, &style);
+}
+
+pub fn render_building_with_style(
+    builder: &mut SvgBuilder,
+    renderer: &TileMapRenderer,
+    types: &BuildingTypeCatalog,
+    town: &Town,
+    building: &Building,
+    style: &RenderStyle,
+) {
+    let lot = &building.lot;
+    let start =
+        renderer.calculate_index_position(&Point2d::default(), town.map.get_size(), lot.tile);
+    let margin = renderer.tile_size / 4;
+
+    let icon_center = if lot.is_rectangle() {
+        let size = renderer.calculate_size(lot.size);
+        let aabb = AABB::new(start, size).shrink(margin);
+        builder.render_rectangle(&aabb, style);
+        aabb.center()
+    } else {
+        render_lot_shape(builder, renderer, &start, lot, margin, style);
+        let (x, y) = lot.get_local_positions()[0];
+        AABB::new(
+            renderer.calculate_tile_position(&start, x, y),
+            Size2d::square(renderer.tile_size),
+        )
+        .center()
+    };
+
+    for entrance in &building.entrances {
+        render_entrance(builder, renderer, &start, entrance, margin);
+    }
+
+    if let Some(icon) = building
+        .building_type
+        .as_deref()
+        .and_then(|name| types.get(name))
+        .and_then(|building_type| building_type.icon.as_deref())
+    {
+        builder.render_text(&icon_center, icon, renderer.tile_size / 2, Color::Black);
+    }
+}
+
+/// Renders an [`entrance`](Entrance) as a door on the outline of the lot.
+fn render_entrance(
+    builder: &mut SvgBuilder,
+    renderer: &TileMapRenderer,
+    start: &Point2d,
+    entrance: &Entrance,
+    margin: u32,
+) {
+    let tile = renderer.tile_size;
+    let aabb = AABB::new(
+        renderer.calculate_tile_position(start, entrance.x, entrance.y),
+        Size2d::square(tile),
     );
-    let size = renderer.calculate_size(building.lot.size);
-    let aabb = AABB::new(start, size).shrink(renderer.tile_size / 4);
+    let (x, y) = entrance.side.get_offset();
+    let distance = (tile / 2 - margin) as f32 / tile as f32;
+    let center = aabb.get_point(0.5 + x as f32 * distance, 0.5 + y as f32 * distance);
+    let size = if x == 0 {
+        Size2d::new(tile / 3, margin)
+    } else {
+        Size2d::new(margin, tile / 3)
+    };
 
-    builder.render_rectangle(&aabb, &style);
+    builder.render_rectangle(
+        &AABB::with_center(center, size),
+        &RenderStyle::no_border(Color::SaddleBrown),
+    );
+}
+
+/// Renders the merged shape of a non-rectangular [`lot`](BuildingLot),
+/// whose outline is the optional border of the style.
+fn render_lot_shape(
+    builder: &mut SvgBuilder,
+    renderer: &TileMapRenderer,
+    start: &Point2d,
+    lot: &BuildingLot,
+    margin: u32,
+    style: &RenderStyle,
+) {
+    match style {
+        RenderStyle::WithBorder {
+            fill_color,
+            border_color,
+            border_width,
+        } => {
+            let half = border_width / 2;
+            let border_style = RenderStyle::no_border(*border_color);
+            let fill_style = RenderStyle::no_border(*fill_color);
+            render_lot_pieces(renderer, start, lot, margin.saturating_sub(half), |aabb| {
+                builder.render_rectangle(&aabb, &border_style)
+            });
+            render_lot_pieces(renderer, start, lot, margin + half, |aabb| {
+                builder.render_rectangle(&aabb, &fill_style)
+            });
+        }
+        _ => render_lot_pieces(renderer, start, lot, margin, |aabb| {
+            builder.render_rectangle(&aabb, style)
+        }),
+    }
+}
+
+/// Splits the shape of the [`lot`](BuildingLot) into rectangles, which keep a margin to its outline.
+fn render_lot_pieces<F: FnMut(AABB)>(
+    renderer: &TileMapRenderer,
+    start: &Point2d,
+    lot: &BuildingLot,
+    margin: u32,
+    mut render: F,
+) {
+    let tile = renderer.tile_size;
+    let inner = tile.saturating_sub(2 * margin);
+    let margin_i32 = margin as i32;
+    let far = (tile - margin) as i32;
+
+    for (x, y) in lot.get_local_positions() {
+        let position = renderer.calculate_tile_position(start, x, y);
+        let (px, py) = (position.x, position.y);
+        let right = lot.contains(x + 1, y);
+        let down = lot.contains(x, y + 1);
+
+        render(AABB::simple(px + margin_i32, py + margin_i32, inner, inner));
+
+        if right {
+            render(AABB::simple(px + far, py + margin_i32, 2 * margin, inner));
+        }
+        if down {
+            render(AABB::simple(px + margin_i32, py + far, inner, 2 * margin));
+        }
+        if right && down && lot.contains(x + 1, y + 1) {
+            render(AABB::simple(px + far, py + far, 2 * margin, 2 * margin));
+        }
+    }
 }
 
-pub fn render_streets(builder: &mut SvgBuilder, renderer: &TileMapRenderer, town: &Town) {
-    render_streets_complex(renderer, town, |aabb, _id, _index| {
+pub fn render_streets(
+    data: &RpgData,
+    builder: &mut SvgBuilder,
+    renderer: &TileMapRenderer,
+    town: &Town,
+    audience: Audience,
+) {
+    render_streets_complex(data, renderer, town, audience, |aabb, _id, _index| {
         render_street(builder, &aabb);
     });
 }
 
+/// Renders all street tiles, which the [`audience`](Audience) can see.
 pub fn render_streets_complex<F: FnMut(AABB, StreetId, usize)>(
+    data: &RpgData,
     renderer: &TileMapRenderer,
     town: &Town,
+    audience: Audience,
     mut render: F,
 ) {
+    let is_visible_street = |x: i32, y: i32| {
+        town.map
+            .get_tile_xy(x, y)
+            .map(|tile| {
+                tile.construction.is_any_street() && is_construction_visible(data, tile, audience)
+            })
+            .unwrap_or(false)
+    };
+
     renderer.render(&Point2d::default(), &town.map, |index, x, y, aabb, tile| {
         if let Street { id } = tile.construction {
-            if town.check_construction_xy(x + 1, y, Construction::is_any_street) {
+            if !is_construction_visible(data, tile, audience) {
+                return;
+            }
+            if is_visible_street(x + 1, y) {
                 let right_aabb = aabb + Point2d::new(renderer.tile_size as i32 / 2, 0);
                 render(right_aabb, id, index);
             }
-            if town.check_construction_xy(x, y + 1, Construction::is_any_street) {
+            if is_visible_street(x, y + 1) {
                 let down_aabb = aabb + Point2d::new(0, renderer.tile_size as i32 / 2);
                 render(down_aabb, id, index);
             }
@@ -84,7 +261,141 @@ pub fn render_constructs(
     builder: &mut SvgBuilder,
     renderer: &TileMapRenderer,
     town: &Town,
+    audience: Audience,
 ) {
-    render_buildings(data, builder, renderer, town);
-    render_streets(builder, renderer, town);
+    render_buildings(data, builder, renderer, town, audience);
+    render_streets(data, builder, renderer, town, audience);
+}
+
+/// The optional features of a rendered [`town`](Town) map.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TownMapOptions {
+    /// The tooltips of buildings also list their residents & workers.
+    pub show_occupancy: bool,
+    /// These buildings get a border.
+    pub highlighted: Vec<BuildingId>,
+}
+
+/// Renders the map of a [`town`](Town) with tooltips for terrain, buildings & streets.
+///
+/// Only the elements, which the [`audience`](Audience) can see, are rendered.
+/// Buildings are links, if *get_link* returns one.
+/// Buildings are colored by their type.
+pub fn render_town<F: FnMut(BuildingId) -> Option<String>>(
+    data: &RpgData,
+    renderer: &TileMapRenderer,
+    town: &Town,
+    audience: Audience,
+    options: &TownMapOptions,
+    mut get_link: F,
+) -> Svg {
+    let size = renderer.calculate_map_size(&town.map);
+    let mut builder = SvgBuilder::new(size);
+
+    renderer.render_tooltips(
+        &mut builder,
+        &Point2d::default(),
+        &town.map,
+        TownTile::get_color,
+        |tile| match tile.terrain {
+            Terrain::Hill { id } | Terrain::Mountain { id } => {
+                data.mountain_manager.get(id).map(|m| m.name().to_string())
+            }
+            Terrain::Plain => None,
+            Terrain::River { id } => data.river_manager.get(id).map(|r| r.name().to_string()),
+        },
+    );
+
+    get_visible_buildings(data, town, audience).for_each(|building| {
+        if options.show_occupancy {
+            builder.tooltip(get_occupancy_tooltip(data, building, audience));
+        } else {
+            builder.tooltip(get_building_tooltip(data, building, audience));
+        }
+
+        let color = data
+            .building_types
+            .get_color(building.building_type.as_deref());
+        let style = if options.highlighted.contains(&building.id()) {
+            RenderStyle::with_border(color, Color::Red, renderer.tile_size / 8)
+        } else {
+            RenderStyle::no_border(color)
+        };
+
+        if let Some(link) = get_link(building.id()) {
+            builder.link(&link);
+            render_building_with_style(
+                &mut builder,
+                renderer,
+                &data.building_types,
+                town,
+                building,
+                &style,
+            );
+            builder.close();
+        } else {
+            render_building_with_style(
+                &mut builder,
+                renderer,
+                &data.building_types,
+                town,
+                building,
+                &style,
+            );
+        }
+
+        builder.clear_tooltip();
+    });
+
+    render_streets_complex(data, renderer, town, audience, |aabb, id, _index| {
+        if let Some(street) = data.street_manager.get(id) {
+            builder.tooltip(street.name().str())
+        }
+
+        render_street(&mut builder, &aabb);
+        builder.clear_tooltip();
+    });
+
+    builder.finish()
+}
+
+/// Returns the name of the [`building`](Building) & its address, if the [`audience`](Audience) can see its street.
+fn get_building_tooltip(data: &RpgData, building: &Building, audience: Audience) -> String {
+    let mut tooltip = building.name().to_string();
+
+    if building
+        .address
+        .and_then(|address| data.street_manager.get(address.street))
+        .is_some_and(|street| street.is_visible_to(audience))
+    {
+        if let Some(address) = address(data, building.id()) {
+            tooltip.push_str(&format!("\n{}", address));
+        }
+    }
+
+    tooltip
+}
+
+/// Returns the name of the [`building`](Building) & the characters living or working in it,
+/// which the [`audience`](Audience) can see.
+fn get_occupancy_tooltip(data: &RpgData, building: &Building, audience: Audience) -> String {
+    let mut tooltip = get_building_tooltip(data, building, audience);
+
+    for (title, occupants) in [
+        ("Residents", get_residents(data, building.id())),
+        ("Workers", get_workers(data, building.id())),
+    ] {
+        let names: Vec<_> = occupants
+            .into_iter()
+            .filter_map(|id| data.characters.get(id))
+            .filter(|character| character.is_visible_to(audience))
+            .filter_map(|character| get_character_name(data, character.id()))
+            .collect();
+
+        if !names.is_empty() {
+            tooltip.push_str(&format!("\n{}: {}", title, names.join(", ")));
+        }
+    }
+
+    tooltip
 }
@@ -0,0 +1,112 @@
+use crate::renderer::style::RenderStyle;
+use crate::renderer::svg::builder::SvgBuilder;
+use crate::renderer::svg::Svg;
+use crate::renderer::Renderer;
+use rpg_tools_core::model::character::appearance::{Appearance, Beard, HairStyle, HeadShape};
+use rpg_tools_core::model::color::Color;
+use rpg_tools_core::model::math::aabb2d::AABB;
+use rpg_tools_core::model::math::point2d::Point2d;
+use rpg_tools_core::model::math::size2d::Size2d;
+
+/// Renders the portrait of a character with the [`appearance`](Appearance) as svg.
+pub fn render_portrait_svg(appearance: &Appearance, size: u32) -> Svg {
+    let size = Size2d::square(size);
+    let mut builder = SvgBuilder::new(size);
+
+    render_portrait(&mut builder, &AABB::with_size(size), appearance);
+
+    builder.finish()
+}
+
+/// Renders the portrait of a character with the [`appearance`](Appearance) inside the
+/// [`axis aligned bounding box`](AABB).
+pub fn render_portrait<R: Renderer>(renderer: &mut R, aabb: &AABB, appearance: &Appearance) {
+    let head = get_head(aabb, appearance.head_shape);
+    let hair = RenderStyle::no_border(appearance.hair_color);
+
+    if appearance.hair_style == HairStyle::Long {
+        let start = head.get_point(-0.1, 0.2);
+        let end = head.get_point(1.1, 1.0);
+        renderer.render_rectangle(&AABB::new(start, to_size(start, end)), &hair);
+    }
+
+    render_head(renderer, &head, appearance);
+    render_hair(renderer, &head, appearance.hair_style, &hair);
+    render_eyes(renderer, &head, appearance.eye_color);
+    render_beard(renderer, &head, appearance.beard, &hair);
+
+    let mouth = AABB::with_center(head.get_point(0.5, 0.8), scale(&head, 0.3, 0.03));
+    renderer.render_rectangle(&mouth, &RenderStyle::no_border(Color::Maroon));
+}
+
+fn get_head(aabb: &AABB, shape: HeadShape) -> AABB {
+    let (width, height) = match shape {
+        HeadShape::Long => (0.45, 0.7),
+        HeadShape::Oval => (0.5, 0.65),
+        HeadShape::Round => (0.6, 0.6),
+        HeadShape::Square => (0.55, 0.6),
+    };
+
+    AABB::with_center(aabb.get_point(0.5, 0.55), scale(aabb, width, height))
+}
+
+fn render_head<R: Renderer>(renderer: &mut R, head: &AABB, appearance: &Appearance) {
+    let style = RenderStyle::with_border(appearance.skin_color, Color::Black, 1);
+
+    match appearance.head_shape {
+        HeadShape::Square => renderer.render_rectangle(head, &style),
+        _ => renderer.render_ellipse_in_aabb(head, &style),
+    }
+}
+
+fn render_hair<R: Renderer>(renderer: &mut R, head: &AABB, style: HairStyle, hair: &RenderStyle) {
+    if style == HairStyle::Bald {
+        return;
+    }
+
+    let cap = AABB::with_center(head.get_point(0.5, 0.12), scale(head, 1.0, 0.3));
+    renderer.render_ellipse_in_aabb(&cap, hair);
+
+    if style == HairStyle::Bun {
+        let bun = AABB::with_center(head.get_point(0.5, -0.05), scale(head, 0.35, 0.25));
+        renderer.render_ellipse_in_aabb(&bun, hair);
+    }
+}
+
+fn render_eyes<R: Renderer>(renderer: &mut R, head: &AABB, color: Color) {
+    let white = RenderStyle::with_border(Color::White, Color::Black, 1);
+    let iris = RenderStyle::no_border(color);
+    let radius = head.size().width() as u32 / 12;
+
+    for x in [0.3, 0.7] {
+        let center = head.get_point(x, 0.45);
+        renderer.render_circle(&center, radius, &white);
+        renderer.render_circle(&center, radius / 2, &iris);
+    }
+}
+
+fn render_beard<R: Renderer>(renderer: &mut R, head: &AABB, beard: Beard, hair: &RenderStyle) {
+    match beard {
+        Beard::None => {}
+        Beard::Full => {
+            let aabb = AABB::with_center(head.get_point(0.5, 0.82), scale(head, 0.8, 0.35));
+            renderer.render_ellipse_in_aabb(&aabb, hair);
+        }
+        Beard::Goatee => {
+            let aabb = AABB::with_center(head.get_point(0.5, 0.92), scale(head, 0.25, 0.15));
+            renderer.render_ellipse_in_aabb(&aabb, hair);
+        }
+        Beard::Moustache => {
+            let aabb = AABB::with_center(head.get_point(0.5, 0.72), scale(head, 0.4, 0.06));
+            renderer.render_rectangle(&aabb, hair);
+        }
+    }
+}
+
+fn scale(aabb: &AABB, width: f32, height: f32) -> Size2d {
+    aabb.size().scale(width, height)
+}
+
+fn to_size(start: Point2d, end: Point2d) -> Size2d {
+    Size2d::new((end.x - start.x) as u32, (end.y - start.y) as u32)
+}